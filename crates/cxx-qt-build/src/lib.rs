@@ -51,13 +51,10 @@ use cxx_qt_gen::{
     CxxQtItem, GeneratedCppBlocks, GeneratedOpt, GeneratedRustBlocks, Parser,
 };
 
-// TODO: we need to eventually support having multiple modules defined in a single file. This
-// is currently an issue because we are using the Rust file name to derive the cpp file name
-// and are blindly re-writing files.
-//
-// As we use struct names for the QObject files, we should actually be able to support multiple
-// QObject macros and at most one "raw CXX" macro per file already. For now this remains a TODO
-// as to keep things simpler. We also want to able to warn users about duplicate names eventually.
+// A Rust file may contain multiple CXX and CXX-Qt bridges. As the C++ file names are derived
+// from the Rust file name, the C++ generated for each CXX-Qt bridge is merged into a single
+// header and source, in the same way that CXX merges all of the bridges of a file. Any QObjects
+// with the same C++ name in different bridges of the same file are reported as an error.
 
 struct GeneratedCppFilePaths {
     plain_cpp: PathBuf,
@@ -86,7 +83,6 @@ impl GeneratedCpp {
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
 
-        let mut cxx_qt = None;
         let mut tokens = proc_macro2::TokenStream::new();
 
         // Add any attributes in the file into the tokenstream
@@ -111,31 +107,13 @@ impl GeneratedCpp {
         cxx_qt_opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
 
         // Loop through the items looking for any CXX or CXX-Qt blocks
-        let mut found_bridge = false;
+        let mut generated_cpp_blocks: Option<GeneratedCppBlocks> = None;
         for item in &file.items {
             match item {
                 CxxQtItem::Cxx(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
                     tokens.extend(m.into_token_stream());
                 }
                 CxxQtItem::CxxQt(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
                     let mut parser = Parser::from(*m.clone())
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
@@ -149,9 +127,15 @@ impl GeneratedCpp {
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
 
-                    // TODO: we'll have to extend the C++ data here rather than overwriting
-                    // assuming we share the same file
-                    cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
+                    // Merge the C++ of each CXX-Qt bridge as they share the same file
+                    if let Some(generated_cpp_blocks) = &mut generated_cpp_blocks {
+                        generated_cpp_blocks
+                            .append(generated_cpp)
+                            .map_err(GeneratedError::from)
+                            .map_err(to_diagnostic)?;
+                    } else {
+                        generated_cpp_blocks = Some(generated_cpp);
+                    }
                     let rust_tokens = write_rust(&generated_rust, Some(&include_ident));
 
                     // We need to do this and can't rely on the macro, as we need to generate the
@@ -164,6 +148,9 @@ impl GeneratedCpp {
             }
         }
 
+        let cxx_qt =
+            generated_cpp_blocks.map(|generated_cpp| write_cpp(&generated_cpp, &include_ident));
+
         let mut opt = cxx_gen::Opt::default();
         opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
         let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
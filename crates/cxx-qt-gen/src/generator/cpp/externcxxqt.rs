@@ -4,11 +4,22 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    generator::{cpp::signal::generate_cpp_signal, GeneratedOpt},
+    generator::{
+        cfg::try_eval_attributes,
+        cpp::{qobject::generate_qml_specifiers, signal::generate_cpp_signal},
+        naming::{
+            namespace::{namespace_combine_ident, NamespaceName},
+            qobject::QObjectNames,
+        },
+        structuring::Structures,
+        GeneratedOpt,
+    },
     naming::TypeNames,
-    parser::externcxxqt::ParsedExternCxxQt,
+    parser::{externcxxqt::ParsedExternCxxQt, externqobject::ParsedExternQObject, extract_cfgs},
+    writer::cpp::namespaced,
     CppFragment,
 };
+use indoc::formatdoc;
 use std::collections::BTreeSet;
 use syn::Result;
 
@@ -26,6 +37,7 @@ pub struct GeneratedCppExternCxxQtBlocks {
 
 pub fn generate(
     blocks: &[ParsedExternCxxQt],
+    structures: &Structures,
     type_names: &TypeNames,
     opt: &GeneratedOpt,
 ) -> Result<Vec<GeneratedCppExternCxxQtBlocks>> {
@@ -62,6 +74,22 @@ pub fn generate(
                 "QObject".to_owned()
             };
             generated.base_classes.push(base_class);
+
+            // Skip if the cfg attributes are not resolved to true
+            if !try_eval_attributes(
+                opt.cfg_evaluator.as_ref(),
+                &extract_cfgs(&qobject.declaration.attrs),
+            )? {
+                continue;
+            }
+
+            if qobject.qml_metadata.is_some() {
+                out.push(generate_qml_foreign(qobject, type_names)?);
+            }
+
+            if structures.extern_qobject_threading(qobject.name.rust_unqualified()) {
+                out.push(generate_threading(qobject, type_names)?);
+            }
         }
         out.push(generated);
     }
@@ -69,8 +97,90 @@ pub fn generate(
     Ok(out)
 }
 
+/// Generate the CxxQtThread of an existing QObject
+///
+/// As the existing type cannot inherit from CxxQtThreading, the thread tracks the QObject
+/// with a QPointer instead, see `rust::cxxqt1::qtThread`.
+fn generate_threading(
+    qobject: &ParsedExternQObject,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppExternCxxQtBlocks> {
+    let mut generated = GeneratedCppExternCxxQtBlocks::default();
+    let qobject_idents = QObjectNames::from_extern_qobject(qobject, type_names)?;
+    let cpp_class = qobject.name.cxx_unqualified();
+    let namespace = qobject.name.namespace().unwrap_or_default();
+    let cxx_qt_thread_ident = &qobject_idents.cxx_qt_thread_class;
+    let cxx_qt_thread_qualified = namespace_combine_ident(namespace, cxx_qt_thread_ident);
+
+    generated
+        .includes
+        .insert("#include <cxx-qt/threading.h>".to_owned());
+
+    // The CXX header refers to the thread type, so the class needs to be declared beforehand
+    generated.forward_declares.push(namespaced(
+        namespace,
+        &formatdoc! {r#"
+            class {cpp_class};
+            using {cxx_qt_thread_ident} = ::rust::cxxqt1::CxxQtThread<{cpp_class}>;
+        "#},
+    ));
+    // Ensure that the CxxQtThread<T> is of the correct size and alignment
+    // which should be two std::shared_ptr which are two size_t each
+    generated.fragments.push(CppFragment::Source(formatdoc! {
+        r#"
+        static_assert(alignof({cxx_qt_thread_qualified}) <= alignof(::std::size_t), "unexpected aligment");
+        static_assert(sizeof({cxx_qt_thread_qualified}) == sizeof(::std::size_t[2]), "unexpected size");
+        "#
+    }));
+
+    Ok(generated)
+}
+
+/// Generate a Q_GADGET which registers the existing type with QML via QML_FOREIGN
+///
+/// As the existing type cannot be modified, the QML specifiers are placed on this wrapper instead.
+fn generate_qml_foreign(
+    qobject: &ParsedExternQObject,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppExternCxxQtBlocks> {
+    let mut generated = GeneratedCppExternCxxQtBlocks::default();
+    let Some(qml_metadata) = &qobject.qml_metadata else {
+        return Ok(generated);
+    };
+
+    generated
+        .includes
+        .insert("#include <QtQml/QQmlEngine>".to_owned());
+
+    let namespace = NamespaceName::from_namespace_and_ident(
+        qobject.name.namespace().unwrap_or_default(),
+        qobject.name.rust_unqualified(),
+    )
+    .internal;
+    let qml_specifiers = generate_qml_specifiers(qml_metadata, type_names)?.join("\n  ");
+    generated.fragments.push(CppFragment::Header(formatdoc! {
+        r#"
+        namespace {namespace} {{
+        struct {ident}QmlForeign
+        {{
+          Q_GADGET
+          QML_FOREIGN({qualified})
+          {qml_specifiers}
+        }};
+        }} // namespace {namespace}
+        "#,
+        ident = qobject.name.cxx_unqualified(),
+        qualified = qobject.name.cxx_qualified(),
+    }));
+
+    Ok(generated)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
     use quote::format_ident;
     use syn::parse_quote;
 
@@ -98,9 +208,10 @@ mod tests {
 
         // Unknown types
         let opt = GeneratedOpt::default();
-        assert!(generate(&blocks, &TypeNames::default(), &opt).is_err());
+        assert!(generate(&blocks, &Structures::default(), &TypeNames::default(), &opt).is_err());
 
-        let generated = generate(&blocks, &TypeNames::mock(), &opt).unwrap();
+        let generated =
+            generate(&blocks, &Structures::default(), &TypeNames::mock(), &opt).unwrap();
         assert_eq!(generated.len(), 4);
     }
 
@@ -125,7 +236,115 @@ mod tests {
         let mut type_names = TypeNames::default();
         type_names.mock_insert("ObjRust", None, Some("ObjCpp"), Some("mynamespace"));
 
-        let generated = generate(&blocks, &type_names, &GeneratedOpt::default()).unwrap();
+        let generated = generate(
+            &blocks,
+            &Structures::default(),
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
         assert_eq!(generated.len(), 3);
     }
+
+    #[test]
+    fn test_generate_cpp_extern_qt_qml_foreign() {
+        let blocks = vec![ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[namespace = "mynamespace"]
+                    #[qobject]
+                    #[qml_element = "PushButton"]
+                    #[qml_extended = PushButtonExtension]
+                    type QPushButton;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap()];
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QPushButton", None, None, Some("mynamespace"));
+        type_names.mock_insert("PushButtonExtension", None, None, Some("ext"));
+
+        let generated = generate(
+            &blocks,
+            &Structures::default(),
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(generated.len(), 3);
+
+        let foreign = &generated[1];
+        assert!(foreign.includes.contains("#include <QtQml/QQmlEngine>"));
+        assert_eq!(foreign.fragments.len(), 1);
+        let CppFragment::Header(header) = &foreign.fragments[0] else {
+            panic!("Expected a header fragment");
+        };
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace mynamespace::cxx_qt_QPushButton {
+            struct QPushButtonQmlForeign
+            {
+              Q_GADGET
+              QML_FOREIGN(mynamespace::QPushButton)
+              QML_NAMED_ELEMENT(PushButton)
+              QML_EXTENDED(ext::PushButtonExtension)
+            };
+            } // namespace mynamespace::cxx_qt_QPushButton
+            "#}
+        );
+    }
+    #[test]
+    fn test_generate_cpp_extern_qt_threading() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++Qt" {
+                    #[namespace = "mynamespace"]
+                    #[qobject]
+                    type QTimer;
+                }
+
+                impl cxx_qt::Threading for QTimer {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let generated = generate(
+            &parser.cxx_qt_data.extern_cxxqt_blocks,
+            &structures,
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(generated.len(), 3);
+
+        let threading = &generated[1];
+        assert!(threading.includes.contains("#include <cxx-qt/threading.h>"));
+        assert_eq!(threading.forward_declares.len(), 1);
+        assert_str_eq!(
+            threading.forward_declares[0],
+            indoc! {r#"
+            namespace mynamespace {
+            class QTimer;
+            using QTimerCxxQtThread = ::rust::cxxqt1::CxxQtThread<QTimer>;
+
+            } // namespace mynamespace
+            "#}
+        );
+        assert_eq!(threading.fragments.len(), 1);
+        let CppFragment::Source(source) = &threading.fragments[0] else {
+            panic!("Expected a source fragment");
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            static_assert(alignof(mynamespace::QTimerCxxQtThread) <= alignof(::std::size_t), "unexpected aligment");
+            static_assert(sizeof(mynamespace::QTimerCxxQtThread) == sizeof(::std::size_t[2]), "unexpected size");
+            "#}
+        );
+    }
 }
@@ -10,6 +10,7 @@ use crate::{
             fragment::{CppFragment, CppNamedType},
             get_cpp_params,
             qobject::GeneratedCppQObjectBlocks,
+            utils::doc_comments,
             GeneratedOpt,
         },
     },
@@ -17,7 +18,7 @@ use crate::{
     naming::TypeNames,
     parser::method::{ParsedMethod, ParsedQInvokableSpecifiers},
 };
-use syn::Result;
+use syn::{Result, ReturnType};
 
 pub fn generate_cpp_methods(
     invokables: &Vec<&ParsedMethod>,
@@ -31,7 +32,13 @@ pub fn generate_cpp_methods(
             continue;
         }
 
-        let return_cxx_ty = syn_type_to_cpp_return_type(&invokable.method.sig.output, type_names)?;
+        // Async invokables return immediately and deliver their result with a signal
+        let return_type = if invokable.is_async {
+            &ReturnType::Default
+        } else {
+            &invokable.method.sig.output
+        };
+        let return_cxx_ty = syn_type_to_cpp_return_type(return_type, type_names)?;
 
         let parameters: Vec<CppNamedType> = get_cpp_params(&invokable.method, type_names)?;
 
@@ -75,9 +82,10 @@ pub fn generate_cpp_methods(
         // in Rust for our invokable.
         //
         // CXX generates the source and we just need the matching header.
-        let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
+        let has_noexcept = syn_return_type_to_cpp_except(return_type);
         generated.methods.push(CppFragment::Header(format!(
-            "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override}{is_pure};",
+            "{docs}{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override}{is_pure};",
+            docs = doc_comments(&invokable.docs),
             ident = invokable.name.cxx_unqualified(),
         )));
     }
@@ -220,4 +228,48 @@ mod tests {
         // private methods
         assert_eq!(generated.private_methods.len(), 0);
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_docs() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            /// Trivial invokable
+            fn trivial_invokable(self: &MyObject);
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let invokables = vec![&method];
+
+        let generated =
+            generate_cpp_methods(&invokables, &TypeNames::mock(), &GeneratedOpt::default())
+                .unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Trivial invokable\nQ_INVOKABLE void trivial_invokable() const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_async_invokables() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            async fn fetch(self: Pin<&mut MyObject>, param: i32) -> i32;
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration).make_mutable();
+        let invokables = vec![&method];
+
+        let generated =
+            generate_cpp_methods(&invokables, &TypeNames::mock(), &GeneratedOpt::default())
+                .unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void fetch(::std::int32_t param) noexcept;"
+        );
+    }
 }
@@ -9,8 +9,10 @@ pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
 pub mod method;
+pub mod model;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signal;
@@ -22,14 +24,17 @@ use std::collections::BTreeSet;
 
 use crate::generator::{cfg::try_eval_attributes, cpp::fragment::CppNamedType};
 use crate::naming::cpp::syn_type_to_cpp_type;
-use crate::naming::TypeNames;
+use crate::naming::{Name, TypeNames};
 use crate::{
     generator::{structuring, GeneratedOpt},
     parser::Parser,
 };
 use externcxxqt::GeneratedCppExternCxxQtBlocks;
+use proc_macro2::Span;
+use qgadget::GeneratedCppQGadget;
+use qnamespace::GeneratedCppQNamespace;
 use qobject::GeneratedCppQObject;
-use syn::{FnArg, ForeignItemFn, Pat, PatIdent, PatType, Result};
+use syn::{Error, FnArg, ForeignItemFn, Pat, PatIdent, PatType, Result};
 
 /// Representation of the generated C++ code for a group of QObjects
 pub struct GeneratedCppBlocks {
@@ -39,8 +44,14 @@ pub struct GeneratedCppBlocks {
     pub includes: BTreeSet<String>,
     /// Generated QObjects
     pub qobjects: Vec<GeneratedCppQObject>,
+    /// Generated Q_GADGETs
+    pub qgadgets: Vec<GeneratedCppQGadget>,
     /// Generated extern C++Qt blocks
     pub extern_cxx_qt: Vec<GeneratedCppExternCxxQtBlocks>,
+    /// Names of the qenums which are declared in the forward declarations
+    pub qenums: Vec<Name>,
+    /// Generated Q_NAMESPACE declarations
+    pub qnamespaces: Vec<GeneratedCppQNamespace>,
 }
 
 impl GeneratedCppBlocks {
@@ -50,20 +61,38 @@ impl GeneratedCppBlocks {
 
         let mut includes = BTreeSet::new();
 
-        let mut forward_declares: Vec<_> = parser
+        let qnamespaces = parser
             .cxx_qt_data
             .qnamespaces
             .iter()
-            .map(|parsed_qnamespace| qnamespace::generate(parsed_qnamespace, &mut includes))
+            .map(|parsed_qnamespace| GeneratedCppQNamespace {
+                namespace: parsed_qnamespace.namespace.clone(),
+                declaration: qnamespace::generate(parsed_qnamespace, &mut includes),
+            })
             .collect();
-        forward_declares.extend(
-            parser
-                .cxx_qt_data
-                .qenums
-                .iter()
-                .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes, opt))
-                .collect::<Result<Vec<String>>>()?,
-        );
+        let forward_declares = parser
+            .cxx_qt_data
+            .qenums
+            .iter()
+            .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes, opt))
+            .collect::<Result<Vec<String>>>()?;
+        let qenums = parser
+            .cxx_qt_data
+            .qenums
+            .iter()
+            .filter_map(|qenum| {
+                if qenum.qobject.is_some() {
+                    return None;
+                }
+
+                // Skip if the cfg attributes are not resolved to true
+                match try_eval_attributes(opt.cfg_evaluator.as_ref(), &qenum.cfgs) {
+                    Ok(true) => Some(Ok(qenum.name.clone())),
+                    Ok(false) => None,
+                    Err(err) => Some(Err(err)),
+                }
+            })
+            .collect::<Result<Vec<Name>>>()?;
         Ok(GeneratedCppBlocks {
             forward_declares,
             includes,
@@ -82,13 +111,92 @@ impl GeneratedCppBlocks {
                     }
                 })
                 .collect::<Result<Vec<GeneratedCppQObject>>>()?,
+            qgadgets: parser
+                .cxx_qt_data
+                .qgadgets()
+                .into_iter()
+                .filter_map(|qgadget| {
+                    // Skip if the cfg attributes are not resolved to true
+                    match try_eval_attributes(opt.cfg_evaluator.as_ref(), &qgadget.cfgs) {
+                        Ok(true) => Some(GeneratedCppQGadget::from(qgadget, &parser.type_names)),
+                        Ok(false) => None,
+                        Err(err) => Some(Err(err)),
+                    }
+                })
+                .collect::<Result<Vec<GeneratedCppQGadget>>>()?,
             extern_cxx_qt: externcxxqt::generate(
                 &parser.cxx_qt_data.extern_cxxqt_blocks,
+                &structures,
                 &parser.type_names,
                 opt,
             )?,
+            qenums,
+            qnamespaces,
         })
     }
+
+    /// The names of the QObjects, Q_GADGETs and qenums declared, along with the kind of each
+    fn declared_types(&self) -> impl Iterator<Item = (&'static str, &Name)> {
+        self.qobjects
+            .iter()
+            .map(|qobject| ("QObject", &qobject.name))
+            .chain(
+                self.qgadgets
+                    .iter()
+                    .map(|qgadget| ("Q_GADGET", &qgadget.name)),
+            )
+            .chain(self.qenums.iter().map(|qenum| ("QEnum", qenum)))
+    }
+
+    /// Append the [GeneratedCppBlocks] of another bridge, so that multiple bridges
+    /// from the same Rust file can be written into a single C++ header and source.
+    ///
+    /// Returns an error if both bridges generate a QObject, Q_GADGET or qenum with the same C++ name,
+    /// or declare the same Q_NAMESPACE differently. A Q_NAMESPACE which is declared the same way
+    /// by both bridges is only declared once.
+    pub fn append(&mut self, mut other: GeneratedCppBlocks) -> Result<()> {
+        for (kind, name) in other.declared_types() {
+            let cxx_qualified = name.cxx_qualified();
+            if self
+                .declared_types()
+                .any(|(_, existing)| existing.cxx_qualified() == cxx_qualified)
+            {
+                return Err(Error::new(
+                    name.rust_unqualified().span(),
+                    format!("{kind} `{cxx_qualified}` is already defined by another bridge in this file, use a different name or namespace"),
+                ));
+            }
+        }
+
+        for qnamespace in std::mem::take(&mut other.qnamespaces) {
+            match self
+                .qnamespaces
+                .iter()
+                .find(|existing| existing.namespace == qnamespace.namespace)
+            {
+                // Bridges may share a namespace, but it can only be declared once in the header
+                Some(existing) if existing.declaration == qnamespace.declaration => {}
+                Some(_) => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        format!(
+                            "qnamespace `{}` is declared differently by another bridge in this file, use the same qml_element attribute",
+                            qnamespace.namespace
+                        ),
+                    ));
+                }
+                None => self.qnamespaces.push(qnamespace),
+            }
+        }
+
+        self.forward_declares.append(&mut other.forward_declares);
+        self.includes.append(&mut other.includes);
+        self.qobjects.append(&mut other.qobjects);
+        self.qgadgets.append(&mut other.qgadgets);
+        self.extern_cxx_qt.append(&mut other.extern_cxx_qt);
+        self.qenums.append(&mut other.qenums);
+        Ok(())
+    }
 }
 
 /// Returns a vector of the names and types ([CppNamedType] of the parameters of this method, used in cpp generation step
@@ -179,4 +287,193 @@ mod tests {
         let cpp = GeneratedCppBlocks::from(&parser, &opt).unwrap();
         assert_eq!(cpp.qobjects[0].name.namespace(), Some("cxx_qt"));
     }
+
+    #[test]
+    fn test_generated_cpp_blocks_append() {
+        let opt = GeneratedOpt::default();
+        let mut cpp = GeneratedCppBlocks::from(
+            &Parser::from(parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            })
+            .unwrap(),
+            &opt,
+        )
+        .unwrap();
+        let other = GeneratedCppBlocks::from(
+            &Parser::from(parse_quote! {
+                #[cxx_qt::bridge(namespace = "other")]
+                mod other_ffi {
+                    #[qenum]
+                    enum MyEnum {
+                        A,
+                    }
+
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyOtherObjectRust;
+                    }
+                }
+            })
+            .unwrap(),
+            &opt,
+        )
+        .unwrap();
+
+        cpp.append(other).unwrap();
+        assert_eq!(cpp.qobjects.len(), 2);
+        assert_eq!(cpp.qobjects[0].name.cxx_qualified(), "MyObject");
+        assert_eq!(cpp.qobjects[1].name.cxx_qualified(), "other::MyObject");
+        assert_eq!(cpp.forward_declares.len(), 1);
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_append_collision() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let opt = GeneratedOpt::default();
+        let mut cpp =
+            GeneratedCppBlocks::from(&Parser::from(module.clone()).unwrap(), &opt).unwrap();
+        let other = GeneratedCppBlocks::from(&Parser::from(module).unwrap(), &opt).unwrap();
+
+        assert_eq!(
+            cpp.append(other).unwrap_err().to_string(),
+            "QObject `MyObject` is already defined by another bridge in this file, use a different name or namespace"
+        );
+    }
+
+    fn append_error(first: ItemMod, second: ItemMod) -> String {
+        let opt = GeneratedOpt::default();
+        let mut cpp = GeneratedCppBlocks::from(&Parser::from(first).unwrap(), &opt).unwrap();
+        let other = GeneratedCppBlocks::from(&Parser::from(second).unwrap(), &opt).unwrap();
+        cpp.append(other).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_append_collision_qenum() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "enums")]
+            mod ffi {
+                #[qenum]
+                enum MyEnum {
+                    A,
+                }
+            }
+        };
+
+        assert_eq!(
+            append_error(module.clone(), module),
+            "QEnum `enums::MyEnum` is already defined by another bridge in this file, use a different name or namespace"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_append_collision_qgadget() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qgadget]
+                    type MyGadget = super::MyGadgetRust;
+                }
+            }
+        };
+
+        assert_eq!(
+            append_error(module.clone(), module),
+            "Q_GADGET `MyGadget` is already defined by another bridge in this file, use a different name or namespace"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_append_collision_between_kinds() {
+        assert_eq!(
+            append_error(
+                parse_quote! {
+                    #[cxx_qt::bridge(namespace = "ns")]
+                    mod ffi {
+                        extern "RustQt" {
+                            #[qobject]
+                            type MyObject = super::MyObjectRust;
+                        }
+                    }
+                },
+                parse_quote! {
+                    #[cxx_qt::bridge(namespace = "ns")]
+                    mod other_ffi {
+                        #[qenum]
+                        enum MyObject {
+                            A,
+                        }
+                    }
+                },
+            ),
+            "QEnum `ns::MyObject` is already defined by another bridge in this file, use a different name or namespace"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_append_shared_qnamespace() {
+        let opt = GeneratedOpt::default();
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                qnamespace!("my_namespace");
+            }
+        };
+        let mut cpp =
+            GeneratedCppBlocks::from(&Parser::from(module.clone()).unwrap(), &opt).unwrap();
+        let other = GeneratedCppBlocks::from(
+            &Parser::from(parse_quote! {
+                #[cxx_qt::bridge]
+                mod other_ffi {
+                    qnamespace!("my_namespace");
+                    qnamespace!("other_namespace");
+                }
+            })
+            .unwrap(),
+            &opt,
+        )
+        .unwrap();
+
+        // The namespace shared by both bridges is only declared once
+        cpp.append(other).unwrap();
+        assert_eq!(cpp.qnamespaces.len(), 2);
+        assert_eq!(cpp.qnamespaces[0].namespace, "my_namespace");
+        assert_eq!(cpp.qnamespaces[1].namespace, "other_namespace");
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_append_collision_qnamespace() {
+        assert_eq!(
+            append_error(
+                parse_quote! {
+                    #[cxx_qt::bridge]
+                    mod ffi {
+                        qnamespace!("my_namespace");
+                    }
+                },
+                parse_quote! {
+                    #[cxx_qt::bridge]
+                    mod other_ffi {
+                        #[qml_element]
+                        qnamespace!("my_namespace");
+                    }
+                },
+            ),
+            "qnamespace `my_namespace` is declared differently by another bridge in this file, use the same qml_element attribute"
+        );
+    }
 }
@@ -0,0 +1,594 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectNames,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+/// Helpers which are called from Rust to notify views of changes to the list
+///
+/// Each entry is the name of the helper, its parameters, and the statement calling the model
+const LIST_MODEL_HELPERS: [(&str, &str, &str); 9] = [
+    (
+        "BeginInsertRows",
+        "::std::int32_t first, ::std::int32_t last",
+        "beginInsertRows(QModelIndex(), first, last);",
+    ),
+    ("EndInsertRows", "", "endInsertRows();"),
+    (
+        "BeginRemoveRows",
+        "::std::int32_t first, ::std::int32_t last",
+        "beginRemoveRows(QModelIndex(), first, last);",
+    ),
+    ("EndRemoveRows", "", "endRemoveRows();"),
+    (
+        "BeginMoveRows",
+        "::std::int32_t first, ::std::int32_t last, ::std::int32_t destination",
+        "return beginMoveRows(QModelIndex(), first, last, QModelIndex(), destination);",
+    ),
+    ("EndMoveRows", "", "endMoveRows();"),
+    ("BeginResetModel", "", "beginResetModel();"),
+    ("EndResetModel", "", "endResetModel();"),
+    (
+        "RowsChanged",
+        "::std::int32_t first, ::std::int32_t last",
+        "Q_EMIT dataChanged(index(first, 0), index(last, 0));",
+    ),
+];
+
+/// Generate the overrides of QAbstractListModel and the helpers used by the Rust list model
+pub fn generate_list_model(
+    qobject_idents: &QObjectNames,
+    base_class: &str,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let class_name = qobject_idents.name.cxx_unqualified();
+
+    generated.includes.extend([
+        "#include <QtCore/QByteArray>".to_owned(),
+        "#include <QtCore/QHash>".to_owned(),
+        "#include <QtCore/QModelIndex>".to_owned(),
+        "#include <QtCore/QVariant>".to_owned(),
+    ]);
+
+    generated.methods.extend([
+        CppFragment::Pair {
+            header: "int rowCount(const QModelIndex& parent = QModelIndex()) const override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                int
+                {class_name}::rowCount(const QModelIndex& parent) const
+                {{
+                  if (parent.isValid()) {{
+                    return 0;
+                  }}
+                  return cxxQtListModelRowCount();
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "QVariant data(const QModelIndex& index, int role = Qt::DisplayRole) const override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                QVariant
+                {class_name}::data(const QModelIndex& index, int role) const
+                {{
+                  if (!index.isValid()) {{
+                    return QVariant();
+                  }}
+                  return cxxQtListModelData(index.row(), role);
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "bool setData(const QModelIndex& index, const QVariant& value, int role = Qt::EditRole) override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                bool
+                {class_name}::setData(const QModelIndex& index, const QVariant& value, int role)
+                {{
+                  if (!index.isValid() || !cxxQtListModelSetData(index.row(), role, value)) {{
+                    return false;
+                  }}
+                  Q_EMIT dataChanged(index, index);
+                  return true;
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "QHash<int, QByteArray> roleNames() const override;".to_owned(),
+            source: formatdoc! {
+                r#"
+                QHash<int, QByteArray>
+                {class_name}::roleNames() const
+                {{
+                  auto roles = {base_class}::roleNames();
+                  const auto names = cxxQtListModelRoleNames();
+                  for (::std::size_t i = 0; i < names.size(); ++i) {{
+                    roles.insert(Qt::UserRole + static_cast<int>(i), QByteArray(names[i].data(), static_cast<int>(names[i].size())));
+                  }}
+                  return roles;
+                }}
+                "#
+            },
+        },
+    ]);
+
+    for (name, parameters, statement) in LIST_MODEL_HELPERS {
+        let return_type = if name == "BeginMoveRows" {
+            "bool"
+        } else {
+            "void"
+        };
+        generated.methods.push(CppFragment::Pair {
+            header: format!("{return_type} cxxQtListModel{name}({parameters});"),
+            source: formatdoc! {
+                r#"
+                {return_type}
+                {class_name}::cxxQtListModel{name}({parameters})
+                {{
+                  {statement}
+                }}
+                "#
+            },
+        });
+    }
+
+    // These are implemented in Rust by CXX
+    generated.private_methods.extend([
+        CppFragment::Header("::std::int32_t cxxQtListModelRowCount() const noexcept;".to_owned()),
+        CppFragment::Header(
+            "QVariant cxxQtListModelData(::std::int32_t row, ::std::int32_t role) const noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "bool cxxQtListModelSetData(::std::int32_t row, ::std::int32_t role, QVariant const& value) noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "::rust::Vec<::rust::String> cxxQtListModelRoleNames() const noexcept;".to_owned(),
+        ),
+    ]);
+
+    Ok(generated)
+}
+
+/// Helpers which are called from Rust to notify views of changes to the tree
+///
+/// Each entry is the name of the helper, its parameters, and the statement calling the model
+const TREE_MODEL_HELPERS: [(&str, &str, &str); 9] = [
+    (
+        "BeginInsertRows",
+        "::std::size_t parent, ::std::int32_t first, ::std::int32_t last",
+        "beginInsertRows(cxxQtTreeModelIndex(parent, 0), first, last);",
+    ),
+    ("EndInsertRows", "", "endInsertRows();"),
+    (
+        "BeginRemoveRows",
+        "::std::size_t parent, ::std::int32_t first, ::std::int32_t last",
+        "beginRemoveRows(cxxQtTreeModelIndex(parent, 0), first, last);",
+    ),
+    ("EndRemoveRows", "", "endRemoveRows();"),
+    (
+        "BeginMoveRows",
+        "::std::size_t sourceParent, ::std::int32_t first, ::std::int32_t last, ::std::size_t destinationParent, ::std::int32_t destination",
+        "return beginMoveRows(cxxQtTreeModelIndex(sourceParent, 0), first, last, cxxQtTreeModelIndex(destinationParent, 0), destination);",
+    ),
+    ("EndMoveRows", "", "endMoveRows();"),
+    ("BeginResetModel", "", "beginResetModel();"),
+    ("EndResetModel", "", "endResetModel();"),
+    (
+        "NodeChanged",
+        "::std::size_t id",
+        "Q_EMIT dataChanged(cxxQtTreeModelIndex(id, 0), cxxQtTreeModelIndex(id, columnCount() - 1));",
+    ),
+];
+
+/// Generate the overrides of QAbstractItemModel and the helpers used by the Rust tree model
+///
+/// The internal id of each QModelIndex is the id of the node in the Rust tree, where zero is the root.
+pub fn generate_tree_model(
+    qobject_idents: &QObjectNames,
+    base_class: &str,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let class_name = qobject_idents.name.cxx_unqualified();
+
+    generated.includes.extend([
+        "#include <QtCore/QByteArray>".to_owned(),
+        "#include <QtCore/QHash>".to_owned(),
+        "#include <QtCore/QModelIndex>".to_owned(),
+        "#include <QtCore/QVariant>".to_owned(),
+    ]);
+
+    generated.methods.extend([
+        // Overriding parent(const QModelIndex&) would otherwise hide QObject::parent()
+        CppFragment::Header("using QObject::parent;".to_owned()),
+        CppFragment::Pair {
+            header: "QModelIndex index(int row, int column, const QModelIndex& parent = QModelIndex()) const override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                QModelIndex
+                {class_name}::index(int row, int column, const QModelIndex& parent) const
+                {{
+                  if (!hasIndex(row, column, parent)) {{
+                    return QModelIndex();
+                  }}
+                  const auto id = cxxQtTreeModelChild(static_cast<::std::size_t>(parent.internalId()), row);
+                  if (id == 0) {{
+                    return QModelIndex();
+                  }}
+                  return createIndex(row, column, static_cast<quintptr>(id));
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "QModelIndex parent(const QModelIndex& index) const override;".to_owned(),
+            source: formatdoc! {
+                r#"
+                QModelIndex
+                {class_name}::parent(const QModelIndex& index) const
+                {{
+                  if (!index.isValid()) {{
+                    return QModelIndex();
+                  }}
+                  return cxxQtTreeModelIndex(cxxQtTreeModelParent(static_cast<::std::size_t>(index.internalId())), 0);
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "int rowCount(const QModelIndex& parent = QModelIndex()) const override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                int
+                {class_name}::rowCount(const QModelIndex& parent) const
+                {{
+                  if (parent.column() > 0) {{
+                    return 0;
+                  }}
+                  return cxxQtTreeModelRowCount(static_cast<::std::size_t>(parent.internalId()));
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "int columnCount(const QModelIndex& parent = QModelIndex()) const override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                int
+                {class_name}::columnCount(const QModelIndex& parent) const
+                {{
+                  Q_UNUSED(parent);
+                  return cxxQtTreeModelColumnCount();
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "QVariant data(const QModelIndex& index, int role = Qt::DisplayRole) const override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                QVariant
+                {class_name}::data(const QModelIndex& index, int role) const
+                {{
+                  if (!index.isValid()) {{
+                    return QVariant();
+                  }}
+                  return cxxQtTreeModelData(static_cast<::std::size_t>(index.internalId()), index.column(), role);
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "bool setData(const QModelIndex& index, const QVariant& value, int role = Qt::EditRole) override;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                bool
+                {class_name}::setData(const QModelIndex& index, const QVariant& value, int role)
+                {{
+                  if (!index.isValid() || !cxxQtTreeModelSetData(static_cast<::std::size_t>(index.internalId()), index.column(), role, value)) {{
+                    return false;
+                  }}
+                  Q_EMIT dataChanged(index, index);
+                  return true;
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "Qt::ItemFlags flags(const QModelIndex& index) const override;".to_owned(),
+            source: formatdoc! {
+                r#"
+                Qt::ItemFlags
+                {class_name}::flags(const QModelIndex& index) const
+                {{
+                  if (!index.isValid()) {{
+                    return {base_class}::flags(index);
+                  }}
+                  return Qt::ItemFlags(QFlag(cxxQtTreeModelFlags(static_cast<::std::size_t>(index.internalId()), index.column())));
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "QHash<int, QByteArray> roleNames() const override;".to_owned(),
+            source: formatdoc! {
+                r#"
+                QHash<int, QByteArray>
+                {class_name}::roleNames() const
+                {{
+                  auto roles = {base_class}::roleNames();
+                  const auto names = cxxQtTreeModelRoleNames();
+                  for (::std::size_t i = 0; i < names.size(); ++i) {{
+                    roles.insert(Qt::UserRole + static_cast<int>(i), QByteArray(names[i].data(), static_cast<int>(names[i].size())));
+                  }}
+                  return roles;
+                }}
+                "#
+            },
+        },
+        CppFragment::Pair {
+            header: "QModelIndex cxxQtTreeModelIndex(::std::size_t id, ::std::int32_t column) const;"
+                .to_owned(),
+            source: formatdoc! {
+                r#"
+                QModelIndex
+                {class_name}::cxxQtTreeModelIndex(::std::size_t id, ::std::int32_t column) const
+                {{
+                  if (id == 0) {{
+                    return QModelIndex();
+                  }}
+                  return createIndex(cxxQtTreeModelRow(id), column, static_cast<quintptr>(id));
+                }}
+                "#
+            },
+        },
+    ]);
+
+    for (name, parameters, statement) in TREE_MODEL_HELPERS {
+        let return_type = if name == "BeginMoveRows" {
+            "bool"
+        } else {
+            "void"
+        };
+        generated.methods.push(CppFragment::Pair {
+            header: format!("{return_type} cxxQtTreeModel{name}({parameters});"),
+            source: formatdoc! {
+                r#"
+                {return_type}
+                {class_name}::cxxQtTreeModel{name}({parameters})
+                {{
+                  {statement}
+                }}
+                "#
+            },
+        });
+    }
+
+    // These are implemented in Rust by CXX
+    generated.private_methods.extend([
+        CppFragment::Header(
+            "::std::size_t cxxQtTreeModelChild(::std::size_t parent, ::std::int32_t row) const noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "::std::size_t cxxQtTreeModelParent(::std::size_t id) const noexcept;".to_owned(),
+        ),
+        CppFragment::Header(
+            "::std::int32_t cxxQtTreeModelRow(::std::size_t id) const noexcept;".to_owned(),
+        ),
+        CppFragment::Header(
+            "::std::int32_t cxxQtTreeModelRowCount(::std::size_t parent) const noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "::std::int32_t cxxQtTreeModelColumnCount() const noexcept;".to_owned(),
+        ),
+        CppFragment::Header(
+            "QVariant cxxQtTreeModelData(::std::size_t id, ::std::int32_t column, ::std::int32_t role) const noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "bool cxxQtTreeModelSetData(::std::size_t id, ::std::int32_t column, ::std::int32_t role, QVariant const& value) noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "::std::int32_t cxxQtTreeModelFlags(::std::size_t id, ::std::int32_t column) const noexcept;"
+                .to_owned(),
+        ),
+        CppFragment::Header(
+            "::rust::Vec<::rust::String> cxxQtTreeModelRoleNames() const noexcept;".to_owned(),
+        ),
+    ]);
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_list_model() {
+        let qobject_idents =
+            QObjectNames::from_idents(format_ident!("MyObject"), format_ident!("MyObjectRust"));
+        let generated = generate_list_model(&qobject_idents, "QAbstractListModel").unwrap();
+
+        assert_eq!(generated.includes.len(), 4);
+        assert_eq!(generated.methods.len(), 13);
+        assert_eq!(generated.private_methods.len(), 4);
+
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "int rowCount(const QModelIndex& parent = QModelIndex()) const override;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            int
+            MyObject::rowCount(const QModelIndex& parent) const
+            {
+              if (parent.isValid()) {
+                return 0;
+              }
+              return cxxQtListModelRowCount();
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[3]).unwrap();
+        assert_str_eq!(header, "QHash<int, QByteArray> roleNames() const override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QHash<int, QByteArray>
+            MyObject::roleNames() const
+            {
+              auto roles = QAbstractListModel::roleNames();
+              const auto names = cxxQtListModelRoleNames();
+              for (::std::size_t i = 0; i < names.size(); ++i) {
+                roles.insert(Qt::UserRole + static_cast<int>(i), QByteArray(names[i].data(), static_cast<int>(names[i].size())));
+              }
+              return roles;
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[8]).unwrap();
+        assert_str_eq!(
+            header,
+            "bool cxxQtListModelBeginMoveRows(::std::int32_t first, ::std::int32_t last, ::std::int32_t destination);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            bool
+            MyObject::cxxQtListModelBeginMoveRows(::std::int32_t first, ::std::int32_t last, ::std::int32_t destination)
+            {
+              return beginMoveRows(QModelIndex(), first, last, QModelIndex(), destination);
+            }
+            "#}
+        );
+
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "::std::int32_t cxxQtListModelRowCount() const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_tree_model() {
+        let qobject_idents =
+            QObjectNames::from_idents(format_ident!("MyObject"), format_ident!("MyObjectRust"));
+        let generated = generate_tree_model(&qobject_idents, "QAbstractItemModel").unwrap();
+
+        assert_eq!(generated.includes.len(), 4);
+        assert_eq!(generated.methods.len(), 19);
+        assert_eq!(generated.private_methods.len(), 9);
+
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(header, "using QObject::parent;");
+
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "QModelIndex index(int row, int column, const QModelIndex& parent = QModelIndex()) const override;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QModelIndex
+            MyObject::index(int row, int column, const QModelIndex& parent) const
+            {
+              if (!hasIndex(row, column, parent)) {
+                return QModelIndex();
+              }
+              const auto id = cxxQtTreeModelChild(static_cast<::std::size_t>(parent.internalId()), row);
+              if (id == 0) {
+                return QModelIndex();
+              }
+              return createIndex(row, column, static_cast<quintptr>(id));
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[7]).unwrap();
+        assert_str_eq!(
+            header,
+            "Qt::ItemFlags flags(const QModelIndex& index) const override;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            Qt::ItemFlags
+            MyObject::flags(const QModelIndex& index) const
+            {
+              if (!index.isValid()) {
+                return QAbstractItemModel::flags(index);
+              }
+              return Qt::ItemFlags(QFlag(cxxQtTreeModelFlags(static_cast<::std::size_t>(index.internalId()), index.column())));
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[14]).unwrap();
+        assert_str_eq!(
+            header,
+            "bool cxxQtTreeModelBeginMoveRows(::std::size_t sourceParent, ::std::int32_t first, ::std::int32_t last, ::std::size_t destinationParent, ::std::int32_t destination);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            bool
+            MyObject::cxxQtTreeModelBeginMoveRows(::std::size_t sourceParent, ::std::int32_t first, ::std::int32_t last, ::std::size_t destinationParent, ::std::int32_t destination)
+            {
+              return beginMoveRows(cxxQtTreeModelIndex(sourceParent, 0), first, last, cxxQtTreeModelIndex(destinationParent, 0), destination);
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[18]).unwrap();
+        assert_str_eq!(header, "void cxxQtTreeModelNodeChanged(::std::size_t id);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::cxxQtTreeModelNodeChanged(::std::size_t id)
+            {
+              Q_EMIT dataChanged(cxxQtTreeModelIndex(id, 0), cxxQtTreeModelIndex(id, columnCount() - 1));
+            }
+            "#}
+        );
+
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "::std::size_t cxxQtTreeModelChild(::std::size_t parent, ::std::int32_t row) const noexcept;"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::property::{NameState, QPropertyNames},
+};
+use crate::naming::Name;
+use indoc::formatdoc;
+
+/// The name of the QObjectBindableProperty member which stores the value of the property
+pub fn storage_name(idents: &QPropertyNames) -> String {
+    format!("m_{}", idents.name.cxx_unqualified())
+}
+
+/// Generate the getter, setter, QBindable accessor and storage for a BINDABLE property
+///
+/// Unlike other properties the value is stored on the C++ side in a QObjectBindableProperty,
+/// so that Qt can track dependencies of bindings, the methods are therefore implemented in C++.
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_name: &Name,
+    cxx_ty: &str,
+) -> Option<GeneratedCppQObjectBlocks> {
+    let (Some(bindable), Some(binding)) = (&idents.bindable, &idents.binding) else {
+        return None;
+    };
+
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let class_name = qobject_name.cxx_unqualified();
+    let storage = storage_name(idents);
+    let ident = idents.name.cxx_unqualified();
+
+    generated
+        .includes
+        .insert("#include <QtCore/QProperty>".to_owned());
+
+    let getter = idents.getter.cxx_unqualified();
+    generated.methods.push(CppFragment::Pair {
+        header: format!(
+            "/// Getter for the Q_PROPERTY {ident}\n{cxx_ty} {getter}() const noexcept;"
+        ),
+        source: formatdoc! {
+            r#"
+            {cxx_ty}
+            {class_name}::{getter}() const noexcept
+            {{
+              return {storage}.value();
+            }}
+            "#,
+        },
+    });
+
+    if let Some(setter) = &idents.setter {
+        let setter = setter.cxx_unqualified();
+        generated.methods.push(CppFragment::Pair {
+            header: format!(
+                "/// Setter for the Q_PROPERTY {ident}\nQ_SLOT void {setter}({cxx_ty} value) noexcept;"
+            ),
+            source: formatdoc! {
+                r#"
+                void
+                {class_name}::{setter}({cxx_ty} value) noexcept
+                {{
+                  {storage}.setValue(::std::move(value));
+                }}
+                "#,
+            },
+        });
+    }
+
+    // As the value is not stored in the Rust struct, the reset value is the Default of the
+    // Rust type, which is provided by a function implemented in Rust
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset = reset.cxx_unqualified();
+        generated.private_methods.push(CppFragment::Header(format!(
+            "{cxx_ty} {reset}Default() const noexcept;"
+        )));
+        generated.methods.push(CppFragment::Pair {
+            header: format!(
+                "/// Reset for the Q_PROPERTY {ident}\nQ_SLOT void {reset}() noexcept;"
+            ),
+            source: formatdoc! {
+                r#"
+                void
+                {class_name}::{reset}() noexcept
+                {{
+                  {storage}.setValue({reset}Default());
+                }}
+                "#,
+            },
+        });
+    }
+
+    let bindable = bindable.cxx_unqualified();
+    generated.methods.push(CppFragment::Pair {
+        header: format!(
+            "/// QBindable for the Q_PROPERTY {ident}\nQBindable<{cxx_ty}> {bindable}() noexcept;"
+        ),
+        source: formatdoc! {
+            r#"
+            QBindable<{cxx_ty}>
+            {class_name}::{bindable}() noexcept
+            {{
+              return QBindable<{cxx_ty}>(&{storage});
+            }}
+            "#,
+        },
+    });
+
+    // Bindings are evaluated lazily by Qt, any bindable properties read by the
+    // function are automatically tracked as dependencies
+    let binding = binding.cxx_unqualified();
+    generated.methods.push(CppFragment::Pair {
+        header: format!(
+            "/// Set a binding on the Q_PROPERTY {ident}\nvoid {binding}(::rust::Fn<{cxx_ty}({class_name} const&)> binding) noexcept;"
+        ),
+        source: formatdoc! {
+            r#"
+            void
+            {class_name}::{binding}(::rust::Fn<{cxx_ty}({class_name} const&)> binding) noexcept
+            {{
+              {storage}.setBinding([this, binding]() {{ return binding(*this); }});
+            }}
+            "#,
+        },
+    });
+
+    // The notify signal is declared in the public block, so it is available to the storage
+    let notify = idents
+        .notify
+        .as_ref()
+        .map(|notify| format!(", &{class_name}::{}", notify.cxx_unqualified()))
+        .unwrap_or_default();
+    generated.private_methods.push(CppFragment::Header(format!(
+        "Q_OBJECT_BINDABLE_PROPERTY({class_name}, {cxx_ty}, {storage}{notify})"
+    )));
+
+    Some(generated)
+}
@@ -11,7 +11,8 @@ use crate::generator::{
 pub fn generate(idents: &QPropertyNames, return_cxx_ty: &str) -> Option<CppFragment> {
     if let NameState::Auto(name) = &idents.getter {
         Some(CppFragment::Header(format!(
-            "{return_cxx_ty} const& {ident_getter}() const noexcept;",
+            "/// Getter for the Q_PROPERTY {ident}\n{return_cxx_ty} const& {ident_getter}() const noexcept;",
+            ident = idents.name.cxx_unqualified(),
             ident_getter = name.cxx_unqualified()
         )))
     } else {
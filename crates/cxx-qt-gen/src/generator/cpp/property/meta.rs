@@ -25,6 +25,10 @@ pub fn generate(idents: &QPropertyNames, flags: &QPropertyFlags, cxx_ty: &str) -
         parts.push(format!("RESET {}", reset.cxx_unqualified()));
     }
 
+    if let Some(bindable) = &idents.bindable {
+        parts.push(format!("BINDABLE {}", bindable.cxx_unqualified()));
+    }
+
     if flags.constant {
         parts.push(String::from("CONSTANT"))
     }
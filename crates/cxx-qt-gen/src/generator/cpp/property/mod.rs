@@ -14,8 +14,11 @@ use crate::{
 };
 use syn::Result;
 
+mod bindable;
 mod getter;
 mod meta;
+mod qpointer;
+mod reset;
 mod setter;
 mod signal;
 
@@ -32,18 +35,43 @@ pub fn generate_cpp_properties(
     for property in properties {
         // Cache the idents as they are used in multiple places
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
-        let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
 
-        generated
-            .metaobjects
-            .push(meta::generate(&idents, &property.flags, &cxx_ty));
+        // A QPointer or QBox is exposed to the meta-object system as a pointer to the QObject
+        if let Some(inner_ty) = property.qobject_pointer() {
+            let cxx_ty = syn_type_to_cpp_type(inner_ty, type_names)?;
+            generated.metaobjects.push(meta::generate(
+                &idents,
+                &property.flags,
+                &format!("{cxx_ty}*"),
+            ));
+            generated.append(&mut qpointer::generate(
+                &idents,
+                &qobject_idents.name,
+                &cxx_ty,
+                property.owns_qobject(),
+            ));
+        } else {
+            let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
+
+            generated
+                .metaobjects
+                .push(meta::generate(&idents, &property.flags, &cxx_ty));
+
+            if let Some(mut bindable) = bindable::generate(&idents, &qobject_idents.name, &cxx_ty) {
+                generated.append(&mut bindable);
+            } else {
+                if let Some(getter) = getter::generate(&idents, &cxx_ty) {
+                    generated.methods.push(getter);
+                }
 
-        if let Some(getter) = getter::generate(&idents, &cxx_ty) {
-            generated.methods.push(getter);
-        }
+                if let Some(setter) = setter::generate(&idents, &cxx_ty) {
+                    generated.methods.push(setter)
+                }
 
-        if let Some(setter) = setter::generate(&idents, &cxx_ty) {
-            generated.methods.push(setter)
+                if let Some(reset) = reset::generate(&idents) {
+                    generated.methods.push(reset)
+                }
+            }
         }
 
         if let Some(notify) = signal::generate(&idents, &qobject_idents.name) {
@@ -232,7 +260,10 @@ pub mod tests {
         assert_eq!(generated.methods.len(), 1);
         let header = require_header(&generated.methods[0]).unwrap();
 
-        assert_str_eq!(header, "::std::int32_t const& getNum() const noexcept;");
+        assert_str_eq!(
+            header,
+            "/// Getter for the Q_PROPERTY num\n::std::int32_t const& getNum() const noexcept;"
+        );
     }
 
     #[test]
@@ -271,6 +302,65 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_reset_auto() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum WRITE setNum NOTIFY numChanged RESET resetNum)"
+        );
+
+        // getter, setter, reset and notify
+        assert_eq!(generated.methods.len(), 4);
+        let header = require_header(&generated.methods[2]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Reset for the Q_PROPERTY num\nQ_SLOT void resetNum() noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_bindable_reset_auto() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, WRITE, NOTIFY, RESET, BINDABLE)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum WRITE setNum NOTIFY numChanged RESET resetNum BINDABLE bindableNum)"
+        );
+
+        // getter, setter, reset, bindable, binding and notify
+        assert_eq!(generated.methods.len(), 6);
+        let (header, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Reset for the Q_PROPERTY num\nQ_SLOT void resetNum() noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::resetNum() noexcept
+            {
+              m_num.setValue(resetNumDefault());
+            }
+            "#}
+        );
+
+        // the default value is provided by Rust, followed by the storage
+        assert_eq!(generated.private_methods.len(), 2);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(header, "::std::int32_t resetNumDefault() const noexcept;");
+    }
+
     #[test]
     fn test_constant_and_required() {
         let mut input: ItemStruct = parse_quote! {
@@ -285,6 +375,319 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_bindable() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, WRITE, NOTIFY, BINDABLE)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum WRITE setNum NOTIFY numChanged BINDABLE bindableNum)"
+        );
+        assert!(generated.includes.contains("#include <QtCore/QProperty>"));
+
+        // methods
+        assert_eq!(generated.methods.len(), 5);
+
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Getter for the Q_PROPERTY num\n::std::int32_t getNum() const noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::getNum() const noexcept
+            {
+              return m_num.value();
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Setter for the Q_PROPERTY num\nQ_SLOT void setNum(::std::int32_t value) noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setNum(::std::int32_t value) noexcept
+            {
+              m_num.setValue(::std::move(value));
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(header, "/// QBindable for the Q_PROPERTY num\nQBindable<::std::int32_t> bindableNum() noexcept;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QBindable<::std::int32_t>
+            MyObject::bindableNum() noexcept
+            {
+              return QBindable<::std::int32_t>(&m_num);
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[3]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Set a binding on the Q_PROPERTY num\nvoid bindNum(::rust::Fn<::std::int32_t(MyObject const&)> binding) noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::bindNum(::rust::Fn<::std::int32_t(MyObject const&)> binding) noexcept
+            {
+              m_num.setBinding([this, binding]() { return binding(*this); });
+            }
+            "#}
+        );
+
+        let header = require_header(&generated.methods[4]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY num\nQ_SIGNAL void numChanged();"
+        );
+
+        // private methods
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_OBJECT_BINDABLE_PROPERTY(MyObject, ::std::int32_t, m_num, &MyObject::numChanged)"
+        );
+    }
+
+    #[test]
+    fn test_bindable_without_notify() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, BINDABLE)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum BINDABLE bindableNum)"
+        );
+
+        // getter, bindable and binding
+        assert_eq!(generated.methods.len(), 3);
+
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_OBJECT_BINDABLE_PROPERTY(MyObject, ::std::int32_t, m_num)"
+        );
+    }
+
+    #[test]
+    fn test_qpointer() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QPointer<MyObject>, object, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(MyObject* object READ getObject WRITE setObject NOTIFY objectChanged RESET resetObject)"
+        );
+        assert!(generated.includes.contains("#include <QtCore/QPointer>"));
+
+        // getter, setter, reset and notify
+        assert_eq!(generated.methods.len(), 4);
+
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Getter for the Q_PROPERTY object\nMyObject* getObject() const noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            MyObject*
+            MyObject::getObject() const noexcept
+            {
+              return m_object.data();
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Setter for the Q_PROPERTY object\nQ_SLOT void setObject(MyObject* value) noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setObject(MyObject* value) noexcept
+            {
+              if (m_object == value) {
+                return;
+              }
+
+              QObject::disconnect(m_objectDestroyedConnection);
+              m_object = value;
+              if (!m_object.isNull()) {
+                m_objectDestroyedConnection = QObject::connect(
+                  m_object.data(), &QObject::destroyed, this, [this]() { Q_EMIT objectChanged(); });
+              }
+              Q_EMIT objectChanged();
+            }
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Reset for the Q_PROPERTY object\nQ_SLOT void resetObject() noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::resetObject() noexcept
+            {
+              if (m_object.isNull()) {
+                return;
+              }
+
+              QObject::disconnect(m_objectDestroyedConnection);
+              m_object.clear();
+              Q_EMIT objectChanged();
+            }
+            "#}
+        );
+
+        // private members
+        assert_eq!(generated.private_methods.len(), 2);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "::QMetaObject::Connection m_objectDestroyedConnection;"
+        );
+        let header = require_header(&generated.private_methods[1]).unwrap();
+        assert_str_eq!(header, "QPointer<MyObject> m_object;");
+    }
+
+    #[test]
+    fn test_qpointer_without_notify() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QPointer<MyObject>, object, READ, WRITE)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(MyObject* object READ getObject WRITE setObject)"
+        );
+
+        // getter and setter
+        assert_eq!(generated.methods.len(), 2);
+        let (_, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setObject(MyObject* value) noexcept
+            {
+              if (m_object == value) {
+                return;
+              }
+
+              m_object = value;
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(header, "QPointer<MyObject> m_object;");
+    }
+
+    #[test]
+    fn test_qbox() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QBox<MyObject>, object, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(MyObject* object READ getObject WRITE setObject NOTIFY objectChanged RESET resetObject)"
+        );
+
+        // getter, setter, reset and notify
+        assert_eq!(generated.methods.len(), 4);
+
+        let (_, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setObject(MyObject* value) noexcept
+            {
+              if (m_object == value) {
+                return;
+              }
+
+              QObject::disconnect(m_objectDestroyedConnection);
+              if (!m_object.isNull() && m_object->parent() == this) {
+                m_object->deleteLater();
+              }
+              if (value != nullptr) {
+                value->setParent(this);
+              }
+              m_object = value;
+              if (!m_object.isNull()) {
+                m_objectDestroyedConnection = QObject::connect(
+                  m_object.data(), &QObject::destroyed, this, [this]() { Q_EMIT objectChanged(); });
+              }
+              Q_EMIT objectChanged();
+            }
+            "#}
+        );
+
+        let (_, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::resetObject() noexcept
+            {
+              if (m_object.isNull()) {
+                return;
+              }
+
+              QObject::disconnect(m_objectDestroyedConnection);
+              if (!m_object.isNull() && m_object->parent() == this) {
+                m_object->deleteLater();
+              }
+              m_object.clear();
+              Q_EMIT objectChanged();
+            }
+            "#}
+        );
+
+        let header = require_header(&generated.private_methods[1]).unwrap();
+        assert_str_eq!(header, "QPointer<MyObject> m_object;");
+    }
+
     #[test]
     fn test_generate_cpp_properties() {
         let input1: ItemStruct = parse_quote! {
@@ -327,33 +730,39 @@ pub mod tests {
         let header = require_header(&generated.methods[0]).unwrap();
         assert_str_eq!(
             header,
-            "::std::int32_t const& getTrivialProperty() const noexcept;"
+            "/// Getter for the Q_PROPERTY trivialProperty\n::std::int32_t const& getTrivialProperty() const noexcept;"
         );
 
         let header = require_header(&generated.methods[1]).unwrap();
         assert_str_eq!(
             header,
-            "Q_SLOT void setTrivialProperty(::std::int32_t value) noexcept;"
+            "/// Setter for the Q_PROPERTY trivialProperty\nQ_SLOT void setTrivialProperty(::std::int32_t value) noexcept;"
         );
 
         let header = require_header(&generated.methods[2]).unwrap();
 
         assert_str_eq!(
             header,
-            "::std::unique_ptr<QColor> const& getOpaqueProperty() const noexcept;"
+            "/// Getter for the Q_PROPERTY opaqueProperty\n::std::unique_ptr<QColor> const& getOpaqueProperty() const noexcept;"
         );
 
         let header = require_header(&generated.methods[3]).unwrap();
         assert_str_eq!(
             header,
-            "Q_SLOT void setOpaqueProperty(::std::unique_ptr<QColor> value) noexcept;"
+            "/// Setter for the Q_PROPERTY opaqueProperty\nQ_SLOT void setOpaqueProperty(::std::unique_ptr<QColor> value) noexcept;"
         );
 
         let header = require_header(&generated.methods[4]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void trivialPropertyChanged();");
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY trivialProperty\nQ_SIGNAL void trivialPropertyChanged();"
+        );
 
         let header = require_header(&generated.methods[5]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void opaquePropertyChanged();");
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY opaqueProperty\nQ_SIGNAL void opaquePropertyChanged();"
+        );
 
         assert_eq!(generated.fragments.len(), 2);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
@@ -401,7 +810,7 @@ pub mod tests {
             ::QMetaObject::Connection
             MyObject_trivialPropertyChangedConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlertrivialPropertyChanged closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::trivialPropertyChanged,
                     &self,
@@ -457,7 +866,7 @@ pub mod tests {
             ::QMetaObject::Connection
             MyObject_opaquePropertyChangedConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandleropaquePropertyChanged closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::opaquePropertyChanged,
                     &self,
@@ -507,14 +916,17 @@ pub mod tests {
         assert_eq!(generated.methods.len(), 3);
 
         let header = require_header(&generated.methods[0]).unwrap();
-        assert_str_eq!(header, "A1 const& getMappedProperty() const noexcept;");
+        assert_str_eq!(header, "/// Getter for the Q_PROPERTY mappedProperty\nA1 const& getMappedProperty() const noexcept;");
 
         let header = require_header(&generated.methods[1]).unwrap();
 
-        assert_str_eq!(header, "Q_SLOT void setMappedProperty(A1 value) noexcept;");
+        assert_str_eq!(header, "/// Setter for the Q_PROPERTY mappedProperty\nQ_SLOT void setMappedProperty(A1 value) noexcept;");
 
         let header = require_header(&generated.methods[2]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void mappedPropertyChanged();");
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY mappedProperty\nQ_SIGNAL void mappedPropertyChanged();"
+        );
 
         assert_eq!(generated.fragments.len(), 1);
 
@@ -560,7 +972,7 @@ pub mod tests {
             ::QMetaObject::Connection
             MyObject_mappedPropertyChangedConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlermappedPropertyChanged closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::mappedPropertyChanged,
                     &self,
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::property::{NameState, QPropertyNames},
+};
+use crate::naming::Name;
+use indoc::formatdoc;
+
+/// The name of the QPointer member which stores the value of the property
+pub fn storage_name(idents: &QPropertyNames) -> String {
+    format!("m_{}", idents.name.cxx_unqualified())
+}
+
+/// The name of the member which stores the connection to the destroyed signal of the object
+fn destroyed_connection_name(idents: &QPropertyNames) -> String {
+    format!("m_{}DestroyedConnection", idents.name.cxx_unqualified())
+}
+
+/// Generate the getter, setter and storage for a property of a `QPointer<T>` or `QBox<T>`
+///
+/// The object is stored on the C++ side in a QPointer, so that the property becomes null when
+/// the object is destroyed, the methods are therefore implemented in C++.
+///
+/// When `owned` is true the property is a `QBox<T>`, so the object is parented to the QObject
+/// when it is set, and the previous object is deleted if the QObject is still its parent.
+///
+/// `cxx_ty` is the C++ type of the QObject, which is exposed as a pointer to the meta-object system.
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_name: &Name,
+    cxx_ty: &str,
+    owned: bool,
+) -> GeneratedCppQObjectBlocks {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let class_name = qobject_name.cxx_unqualified();
+    let storage = storage_name(idents);
+    let ident = idents.name.cxx_unqualified();
+
+    generated
+        .includes
+        .insert("#include <QtCore/QPointer>".to_owned());
+
+    let getter = idents.getter.cxx_unqualified();
+    generated.methods.push(CppFragment::Pair {
+        header: format!(
+            "/// Getter for the Q_PROPERTY {ident}\n{cxx_ty}* {getter}() const noexcept;"
+        ),
+        source: formatdoc! {
+            r#"
+            {cxx_ty}*
+            {class_name}::{getter}() const noexcept
+            {{
+              return {storage}.data();
+            }}
+            "#,
+        },
+    });
+
+    // An owned object is deleted when it is replaced, unless it has been reparented elsewhere,
+    // and the new object is parented to the QObject so that it is deleted with the QObject
+    let (release, adopt) = if owned {
+        (
+            formatdoc! {
+                r#"
+                if (!{storage}.isNull() && {storage}->parent() == this) {{
+                    {storage}->deleteLater();
+                  }}
+                  "#
+            },
+            formatdoc! {
+                r#"
+                if (value != nullptr) {{
+                    value->setParent(this);
+                  }}
+                  "#
+            },
+        )
+    } else {
+        (String::new(), String::new())
+    };
+
+    // When the object is destroyed the QPointer becomes null, so the notify signal is emitted
+    //
+    // The connection is stored so that it can be disconnected when the value changes
+    let (assign, clear) = if let Some(notify) = &idents.notify {
+        let notify = notify.cxx_unqualified();
+        let connection = destroyed_connection_name(idents);
+        generated.private_methods.push(CppFragment::Header(format!(
+            "::QMetaObject::Connection {connection};"
+        )));
+        (
+            formatdoc! {
+                r#"
+                QObject::disconnect({connection});
+                  {release}{adopt}{storage} = value;
+                  if (!{storage}.isNull()) {{
+                    {connection} = QObject::connect(
+                      {storage}.data(), &QObject::destroyed, this, [this]() {{ Q_EMIT {notify}(); }});
+                  }}
+                  Q_EMIT {notify}();"#
+            },
+            formatdoc! {
+                r#"
+                QObject::disconnect({connection});
+                  {release}{storage}.clear();
+                  Q_EMIT {notify}();"#
+            },
+        )
+    } else {
+        (
+            format!("{release}{adopt}{storage} = value;"),
+            format!("{release}{storage}.clear();"),
+        )
+    };
+
+    if let Some(setter) = &idents.setter {
+        let setter = setter.cxx_unqualified();
+        generated.methods.push(CppFragment::Pair {
+            header: format!(
+                "/// Setter for the Q_PROPERTY {ident}\nQ_SLOT void {setter}({cxx_ty}* value) noexcept;"
+            ),
+            source: formatdoc! {
+                r#"
+                void
+                {class_name}::{setter}({cxx_ty}* value) noexcept
+                {{
+                  if ({storage} == value) {{
+                    return;
+                  }}
+
+                  {assign}
+                }}
+                "#,
+            },
+        });
+    }
+
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset = reset.cxx_unqualified();
+        generated.methods.push(CppFragment::Pair {
+            header: format!(
+                "/// Reset for the Q_PROPERTY {ident}\nQ_SLOT void {reset}() noexcept;"
+            ),
+            source: formatdoc! {
+                r#"
+                void
+                {class_name}::{reset}() noexcept
+                {{
+                  if ({storage}.isNull()) {{
+                    return;
+                  }}
+
+                  {clear}
+                }}
+                "#,
+            },
+        });
+    }
+
+    generated.private_methods.push(CppFragment::Header(format!(
+        "QPointer<{cxx_ty}> {storage};"
+    )));
+
+    generated
+}
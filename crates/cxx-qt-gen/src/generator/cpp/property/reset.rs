@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::fragment::CppFragment,
+    naming::property::{NameState, QPropertyNames},
+};
+
+pub fn generate(idents: &QPropertyNames) -> Option<CppFragment> {
+    // Only generates reset code if the state provided is Auto (not custom provided by user)
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        Some(CppFragment::Header(format!(
+            "/// Reset for the Q_PROPERTY {ident}\nQ_SLOT void {ident_reset}() noexcept;",
+            ident = idents.name.cxx_unqualified(),
+            ident_reset = reset.cxx_unqualified(),
+        )))
+    } else {
+        None
+    }
+}
@@ -12,7 +12,8 @@ pub fn generate(idents: &QPropertyNames, cxx_ty: &str) -> Option<CppFragment> {
     // Only generates setter code if the state provided is Auto (not custom provided by user)
     if let Some(NameState::Auto(setter)) = &idents.setter {
         Some(CppFragment::Header(format!(
-            "Q_SLOT void {ident_setter}({cxx_ty} value) noexcept;",
+            "/// Setter for the Q_PROPERTY {ident}\nQ_SLOT void {ident_setter}({cxx_ty} value) noexcept;",
+            ident = idents.name.cxx_unqualified(),
             ident_setter = setter.cxx_unqualified(),
         )))
     } else {
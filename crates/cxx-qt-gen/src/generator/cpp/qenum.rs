@@ -16,24 +16,57 @@ use crate::{
     CppFragment,
 };
 
-use super::{qobject::GeneratedCppQObjectBlocks, utils::Indent};
+use super::{
+    qobject::GeneratedCppQObjectBlocks,
+    utils::{doc_comments, Indent},
+};
+
+/// The fixed width C++ integer type matching the repr of the QEnum
+fn cpp_repr(qenum: &ParsedQEnum) -> String {
+    let repr = qenum.repr.to_string();
+    let (unsigned, bits) = repr.split_at(1);
+    if unsigned == "u" {
+        format!("::std::uint{bits}_t")
+    } else {
+        format!("::std::int{bits}_t")
+    }
+}
+
+/// A C++ integer literal for the value of a QEnum variant
+fn cpp_value(value: i128) -> String {
+    if value == i64::MIN.into() {
+        // The literal for the magnitude of i64::MIN does not fit into a signed 64-bit integer
+        format!("{} - 1", value + 1)
+    } else if value > i64::MAX.into() {
+        format!("{value}ULL")
+    } else {
+        value.to_string()
+    }
+}
 
 fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_name = &qenum.name.cxx_unqualified();
+    let enum_repr = cpp_repr(qenum);
 
     let enum_values = qenum
         .variants
         .iter()
-        .enumerate()
-        .map(|(index, variant)| format!("{variant} = {index}"))
+        .map(|variant| {
+            format!(
+                "{docs}{ident} = {value}",
+                docs = doc_comments(&variant.docs),
+                ident = variant.ident,
+                value = cpp_value(variant.value)
+            )
+        })
         .collect::<Vec<_>>()
         .join(",\n");
 
     formatdoc! { r#"
-        enum class {enum_name} : ::std::int32_t {{
+        {docs}enum class {enum_name} : {enum_repr} {{
         {enum_values}
         }};
-        "#, enum_values = enum_values.indented(2) }
+        "#, docs = doc_comments(&qenum.docs), enum_values = enum_values.indented(2) }
 }
 
 pub fn generate_declaration(
@@ -52,7 +85,26 @@ pub fn generate_declaration(
 
     let enum_definition = generate_definition(qenum).indented(2);
     let enum_name = &qenum.name.cxx_unqualified();
-    Ok(namespaced(
+    let (registration, operators) = if let Some(flags) = &qenum.flags {
+        includes.insert("#include <QtCore/QFlags>".to_owned());
+
+        let flags_name = flags.cxx_unqualified();
+        (
+            formatdoc! {r#"
+                Q_DECLARE_FLAGS({flags_name}, {enum_name})
+                Q_FLAG_NS({flags_name})"#
+            },
+            // The operators must be declared outside of the namespace
+            format!(
+                "\nQ_DECLARE_OPERATORS_FOR_FLAGS({})\n",
+                flags.cxx_qualified()
+            ),
+        )
+    } else {
+        (format!("Q_ENUM_NS({enum_name})"), String::new())
+    };
+
+    let declaration = namespaced(
         qenum.name.namespace().unwrap_or_default(),
         // The declaration must still include Q_NAMESPACE, as otherwise moc will complain.
         // This is redundant with `qnamespace!`, which is now only required if you want to specify
@@ -60,9 +112,10 @@ pub fn generate_declaration(
         &formatdoc! {r#"
                 Q_NAMESPACE
                 {enum_definition}
-                Q_ENUM_NS({enum_name}) "#
+                {registration} "#
         },
-    ))
+    );
+    Ok(format!("{declaration}{operators}"))
 }
 
 pub fn generate_on_qobject<'a>(
@@ -89,9 +142,15 @@ pub fn generate_on_qobject<'a>(
 
         generated.includes.insert("#include <cstdint>".to_owned());
         let enum_definition = generate_definition(qenum);
+        let registration = if let Some(flags) = &qenum.flags {
+            let flags_name = flags.cxx_unqualified();
+            format!("Q_DECLARE_FLAGS({flags_name}, {enum_name})\n  Q_FLAG({flags_name})")
+        } else {
+            format!("Q_ENUM({enum_name})")
+        };
         generated.metaobjects.push(formatdoc! {r#"
             {enum_definition}
-              Q_ENUM({enum_name})"#, enum_definition = enum_definition.indented(2)});
+              {registration}"#, enum_definition = enum_definition.indented(2)});
 
         generated
             .post_fragments
@@ -99,6 +158,23 @@ pub fn generate_on_qobject<'a>(
                 qenum.name.namespace().unwrap_or_default(),
                 &format!("using {enum_name} = {qobject_name}::{enum_name};",),
             )));
+
+        if let Some(flags) = &qenum.flags {
+            let flags_name = flags.cxx_unqualified();
+            generated
+                .includes
+                .insert("#include <QtCore/QFlags>".to_owned());
+            generated
+                .post_fragments
+                .push(CppFragment::Header(namespaced(
+                    flags.namespace().unwrap_or_default(),
+                    &format!("using {flags_name} = {qobject_name}::{flags_name};",),
+                )));
+            // The operators must be declared outside of the class and any namespace
+            generated.post_fragments.push(CppFragment::Header(format!(
+                "Q_DECLARE_OPERATORS_FOR_FLAGS({qobject_name}::{flags_name})"
+            )));
+        }
     }
 
     Ok(generated)
@@ -178,4 +254,150 @@ mod tests {
         );
         assert_eq!(generated.forward_declares.len(), 0);
     }
+
+    #[test]
+    fn generates_discriminants_and_repr() {
+        let qenums = [ParsedQEnum::parse(
+            parse_quote! {
+                #[repr(u8)]
+                enum MyEnum {
+                    A = 0x10, B = 0x20, C
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate_on_qobject(
+            qenums.iter(),
+            &Name::mock("MyObject"),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_str_eq!(
+            indoc! {r#"
+              enum class MyEnum : ::std::uint8_t {
+                A = 16,
+                B = 32,
+                C = 33
+              };
+              Q_ENUM(MyEnum)
+            "#}
+            .indented(2),
+            generated.metaobjects[0],
+        );
+    }
+
+    #[test]
+    fn generates_declaration_with_limits() {
+        let qenum = ParsedQEnum::parse(
+            parse_quote! {
+                #[namespace = "my_namespace"]
+                #[repr(i64)]
+                enum MyEnum {
+                    Min = -9223372036854775808,
+                    Negative = -1,
+                }
+            },
+            None,
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        let mut includes = BTreeSet::new();
+        let generated =
+            generate_declaration(&qenum, &mut includes, &GeneratedOpt::default()).unwrap();
+        assert!(generated.contains("enum class MyEnum : ::std::int64_t {"));
+        assert!(generated.contains("Min = -9223372036854775807 - 1,"));
+        assert!(generated.contains("Negative = -1"));
+
+        assert_eq!(cpp_value(u64::MAX.into()), "18446744073709551615ULL");
+    }
+
+    #[test]
+    fn generates_flags() {
+        let qenums = [ParsedQEnum::parse(
+            parse_quote! {
+                #[qflags(MyFlags)]
+                enum MyFlag {
+                    A = 1, B = 2
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate_on_qobject(
+            qenums.iter(),
+            &Name::mock("MyObject"),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(generated.includes.len(), 2);
+        assert!(generated.includes.contains("#include <QtCore/QFlags>"));
+        assert_str_eq!(
+            indoc! {r#"
+              enum class MyFlag : ::std::int32_t {
+                A = 1,
+                B = 2
+              };
+              Q_DECLARE_FLAGS(MyFlags, MyFlag)
+              Q_FLAG(MyFlags)
+            "#}
+            .indented(2),
+            generated.metaobjects[0],
+        );
+        assert_eq!(generated.post_fragments.len(), 3);
+        assert_eq!(
+            CppFragment::Header("using MyFlags = ::MyObject::MyFlags;".to_owned()),
+            generated.post_fragments[1]
+        );
+        assert_eq!(
+            CppFragment::Header("Q_DECLARE_OPERATORS_FOR_FLAGS(::MyObject::MyFlags)".to_owned()),
+            generated.post_fragments[2]
+        );
+    }
+
+    #[test]
+    fn generates_namespaced_flags() {
+        let qenum = ParsedQEnum::parse(
+            parse_quote! {
+                #[namespace = "my_namespace"]
+                #[qflags(MyFlags)]
+                enum MyFlag {
+                    A = 1, B = 2
+                }
+            },
+            None,
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        let mut includes = BTreeSet::new();
+        let generated =
+            generate_declaration(&qenum, &mut includes, &GeneratedOpt::default()).unwrap();
+        assert!(includes.contains("#include <QtCore/QFlags>"));
+        assert_str_eq!(
+            generated,
+            indoc! {r#"
+                namespace my_namespace {
+                Q_NAMESPACE
+                  enum class MyFlag : ::std::int32_t {
+                    A = 1,
+                    B = 2
+                  };
+                Q_DECLARE_FLAGS(MyFlags, MyFlag)
+                Q_FLAG_NS(MyFlags) 
+                } // namespace my_namespace
+
+                Q_DECLARE_OPERATORS_FOR_FLAGS(my_namespace::MyFlags)
+            "#}
+        );
+    }
 }
@@ -0,0 +1,323 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, utils::doc_comments},
+        naming::qgadget::{QGadgetNames, QGadgetPropertyNames},
+    },
+    naming::{cpp::syn_type_to_cpp_type, Name, TypeNames},
+    parser::qgadget::ParsedQGadget,
+    writer::cpp::namespaced,
+};
+use indoc::formatdoc;
+use std::collections::BTreeSet;
+use syn::Result;
+
+pub struct GeneratedCppQGadget {
+    /// Name of the Q_GADGET, with associated namespace, cxx_name, etc.
+    pub name: Name,
+    /// List of includes
+    pub includes: BTreeSet<String>,
+    /// The class definition and its helpers, these are after the include of the generated CXX header
+    pub fragments: Vec<CppFragment>,
+}
+
+impl GeneratedCppQGadget {
+    pub fn from(qgadget: &ParsedQGadget, type_names: &TypeNames) -> Result<Self> {
+        let names = QGadgetNames::from_qgadget(qgadget, type_names)?;
+        let ident = names.name.cxx_unqualified();
+        let qualified = names.name.cxx_qualified();
+        let namespace = names.name.namespace().unwrap_or_default();
+        let namespace_internals = &names.namespace_internals;
+        let rust_struct = names.rust_struct.cxx_qualified();
+
+        let mut includes = BTreeSet::from([
+            "#include <QtCore/QObject>".to_owned(),
+            "#include <QtCore/QVariant>".to_owned(),
+        ]);
+
+        let mut metaobjects = vec![];
+        if let Some(qml_name) = &qgadget.qml_name {
+            includes.insert("#include <QtQml/QQmlEngine>".to_owned());
+            metaobjects.push(format!("QML_VALUE_TYPE({qml_name})"));
+        }
+
+        let mut methods = vec![];
+        for property in &qgadget.properties {
+            let property_names = QGadgetPropertyNames::from(property);
+            let property_ident = property_names.name.cxx_unqualified();
+            let getter = property_names.getter.cxx_unqualified();
+            let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
+
+            let mut meta_parts = vec![format!("READ {getter}")];
+            methods.push(format!(
+                "/// Getter for the Q_PROPERTY {property_ident}\n{cxx_ty} const& {getter}() const noexcept;"
+            ));
+
+            if let Some(setter) = &property_names.setter {
+                let setter = setter.cxx_unqualified();
+                meta_parts.push(format!("WRITE {setter}"));
+                methods.push(format!(
+                    "/// Setter for the Q_PROPERTY {property_ident}\nvoid {setter}({cxx_ty} value) noexcept;"
+                ));
+            }
+
+            if property.flags.constant {
+                meta_parts.push("CONSTANT".to_owned());
+            }
+
+            if property.flags.is_final {
+                meta_parts.push("FINAL".to_owned());
+            }
+
+            metaobjects.push(format!(
+                "Q_PROPERTY({cxx_ty} {property_ident} {meta_parts})",
+                meta_parts = meta_parts.join(" ")
+            ));
+        }
+
+        let class_definition = namespaced(
+            namespace,
+            &formatdoc! {r#"
+                {docs}class {ident}
+                {{
+                  Q_GADGET
+                  {metaobjects}
+
+                public:
+                  {ident}();
+                  {ident}(const {ident}& other);
+                  {ident}({ident}&& other) noexcept = default;
+                  ~{ident}() = default;
+                  {ident}& operator=(const {ident}& other);
+                  {ident}& operator=({ident}&& other) noexcept = default;
+
+                  {methods}
+
+                private:
+                  ::rust::Box<{rust_struct}> m_rustObj;
+                }};
+                "#,
+                docs = doc_comments(&qgadget.docs),
+                metaobjects = metaobjects.join("\n  "),
+                methods = methods.join("\n  "),
+            },
+        );
+        let class_methods = namespaced(
+            namespace,
+            &formatdoc! {r#"
+                {ident}::{ident}()
+                  : m_rustObj(::{namespace_internals}::createRs())
+                {{
+                }}
+
+                {ident}::{ident}(const {ident}& other)
+                  : m_rustObj(::{namespace_internals}::cloneRs(*other.m_rustObj))
+                {{
+                }}
+
+                {ident}&
+                {ident}::operator=(const {ident}& other)
+                {{
+                  m_rustObj = ::{namespace_internals}::cloneRs(*other.m_rustObj);
+                  return *this;
+                }}
+                "#
+            },
+        );
+
+        let fragments = vec![
+            CppFragment::Pair {
+                // The inner Rust struct is boxed, so the Q_GADGET can be moved by CXX
+                header: formatdoc! {r#"
+                    {class_definition}
+                    namespace rust {{
+                    template<>
+                    struct IsRelocatable<::{qualified}> : ::std::true_type
+                    {{}};
+                    }} // namespace rust
+
+                    Q_DECLARE_METATYPE({qualified})
+                    "#
+                },
+                source: class_methods,
+            },
+            CppFragment::Pair {
+                header: namespaced(
+                    namespace_internals,
+                    "bool\nqvariantCanConvert(const QVariant& variant);\n",
+                ),
+                source: namespaced(
+                    namespace_internals,
+                    &formatdoc! {r#"
+                        bool
+                        qvariantCanConvert(const QVariant& variant)
+                        {{
+                          return variant.canConvert<::{qualified}>();
+                        }}
+                        "#
+                    },
+                ),
+            },
+        ];
+
+        Ok(Self {
+            name: names.name,
+            includes,
+            fragments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_pair;
+    use crate::parser::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::{parse_quote, ItemMod};
+
+    fn generate(module: ItemMod) -> GeneratedCppQGadget {
+        let parser = Parser::from(module).unwrap();
+        GeneratedCppQGadget::from(parser.cxx_qt_data.qgadgets()[0], &parser.type_names).unwrap()
+    }
+
+    #[test]
+    fn test_generate_cpp_qgadget() {
+        let generated = generate(parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    /// A point
+                    #[qgadget]
+                    #[qml_element]
+                    #[qproperty(i32, x)]
+                    #[qproperty(i32, y, READ, CONSTANT)]
+                    type Point = super::PointRust;
+                }
+            }
+        });
+
+        assert_eq!(generated.name.cxx_qualified(), "cxx_qt::Point");
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+        assert_eq!(generated.fragments.len(), 2);
+
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace cxx_qt {
+            /// A point
+            class Point
+            {
+              Q_GADGET
+              QML_VALUE_TYPE(point)
+              Q_PROPERTY(::std::int32_t x READ getX WRITE setX)
+              Q_PROPERTY(::std::int32_t y READ getY CONSTANT)
+
+            public:
+              Point();
+              Point(const Point& other);
+              Point(Point&& other) noexcept = default;
+              ~Point() = default;
+              Point& operator=(const Point& other);
+              Point& operator=(Point&& other) noexcept = default;
+
+              /// Getter for the Q_PROPERTY x
+            ::std::int32_t const& getX() const noexcept;
+              /// Setter for the Q_PROPERTY x
+            void setX(::std::int32_t value) noexcept;
+              /// Getter for the Q_PROPERTY y
+            ::std::int32_t const& getY() const noexcept;
+
+            private:
+              ::rust::Box<PointRust> m_rustObj;
+            };
+
+            } // namespace cxx_qt
+
+            namespace rust {
+            template<>
+            struct IsRelocatable<::cxx_qt::Point> : ::std::true_type
+            {};
+            } // namespace rust
+
+            Q_DECLARE_METATYPE(cxx_qt::Point)
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace cxx_qt {
+            Point::Point()
+              : m_rustObj(::cxx_qt::cxx_qt_Point::createRs())
+            {
+            }
+
+            Point::Point(const Point& other)
+              : m_rustObj(::cxx_qt::cxx_qt_Point::cloneRs(*other.m_rustObj))
+            {
+            }
+
+            Point&
+            Point::operator=(const Point& other)
+            {
+              m_rustObj = ::cxx_qt::cxx_qt_Point::cloneRs(*other.m_rustObj);
+              return *this;
+            }
+
+            } // namespace cxx_qt
+            "#}
+        );
+
+        let (header, source) = require_pair(&generated.fragments[1]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace cxx_qt::cxx_qt_Point {
+            bool
+            qvariantCanConvert(const QVariant& variant);
+
+            } // namespace cxx_qt::cxx_qt_Point
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace cxx_qt::cxx_qt_Point {
+            bool
+            qvariantCanConvert(const QVariant& variant)
+            {
+              return variant.canConvert<::cxx_qt::Point>();
+            }
+
+            } // namespace cxx_qt::cxx_qt_Point
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_qgadget_no_qml() {
+        let generated = generate(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qgadget]
+                    type Point = super::PointRust;
+                }
+            }
+        });
+
+        assert!(!generated.includes.contains("#include <QtQml/QQmlEngine>"));
+        let (header, _) = require_pair(&generated.fragments[0]).unwrap();
+        assert!(!header.contains("QML_VALUE_TYPE"));
+        assert!(header.contains("struct IsRelocatable<::Point>"));
+        let (_, source) = require_pair(&generated.fragments[1]).unwrap();
+        assert!(source.contains("namespace cxx_qt_Point {"));
+    }
+}
@@ -9,6 +9,14 @@ use indoc::formatdoc;
 
 use crate::{parser::qnamespace::ParsedQNamespace, writer::cpp::namespaced};
 
+/// The generated declaration of a Q_NAMESPACE
+pub struct GeneratedCppQNamespace {
+    /// The name of the namespace
+    pub namespace: String,
+    /// The declaration of the namespace, including the Q_NAMESPACE macro
+    pub declaration: String,
+}
+
 /// Generate the declaration of the namespace, including the Q_NAMESPACE macro.
 pub fn generate(qnamespace: &ParsedQNamespace, includes: &mut BTreeSet<String>) -> String {
     includes.insert("#include <QtCore/QObject>".to_owned());
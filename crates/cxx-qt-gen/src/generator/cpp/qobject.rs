@@ -6,8 +6,16 @@
 use crate::{
     generator::{
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, method::generate_cpp_methods,
-            property::generate_cpp_properties, qenum, signal::generate_cpp_signals, threading,
+            constructor, cxxqttype,
+            fragment::CppFragment,
+            inherit,
+            method::generate_cpp_methods,
+            model::{generate_list_model, generate_tree_model},
+            property::generate_cpp_properties,
+            qenum,
+            signal::generate_cpp_signals,
+            threading,
+            utils::doc_comments,
         },
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         structuring::StructuredQObject,
@@ -15,7 +23,11 @@ use crate::{
     naming::Name,
     GeneratedOpt,
 };
-use crate::{naming::TypeNames, parser::qobject::ParsedQObject};
+use crate::{
+    naming::TypeNames,
+    parser::qobject::{ParsedQObject, QmlElementMetadata},
+};
+use indoc::formatdoc;
 use std::collections::BTreeSet;
 use syn::Result;
 
@@ -60,28 +72,67 @@ impl GeneratedCppQObjectBlocks {
         self.private_methods.append(&mut other.private_methods);
     }
 
-    pub fn from(qobject: &ParsedQObject) -> GeneratedCppQObjectBlocks {
-        let mut qml_specifiers = Vec::new();
+    pub fn from(
+        qobject: &ParsedQObject,
+        type_names: &TypeNames,
+    ) -> Result<GeneratedCppQObjectBlocks> {
+        let mut generated = GeneratedCppQObjectBlocks::default();
         if let Some(qml_metadata) = &qobject.qml_metadata {
             // Note ensure that the header moc processes has QtQml/QQmlEngine
             // this is done via generator/rust/qobject
+            generated
+                .metaobjects
+                .append(&mut generate_qml_specifiers(qml_metadata, type_names)?);
+
+            // The attached object is created with the object it is attached to as the parent
+            if let Some(attached) = &qml_metadata.attached {
+                let attached = type_names.cxx_qualified(attached)?;
+                let class_name = qobject.name.cxx_unqualified();
+                generated
+                    .metaobjects
+                    .push(format!("QML_ATTACHED({attached})"));
+                generated.methods.push(CppFragment::Pair {
+                    header: format!("static {attached}* qmlAttachedProperties(QObject* object);"),
+                    source: formatdoc! {
+                        r#"
+                        {attached}*
+                        {class_name}::qmlAttachedProperties(QObject* object)
+                        {{
+                          return new {attached}(object);
+                        }}
+                        "#,
+                    },
+                });
+            }
+        }
+        Ok(generated)
+    }
+}
 
-            qml_specifiers.push(format!("QML_NAMED_ELEMENT({})", qml_metadata.name));
+/// Generate the QML specifiers (eg QML_NAMED_ELEMENT) for registering a type with QML
+pub fn generate_qml_specifiers(
+    qml_metadata: &QmlElementMetadata,
+    type_names: &TypeNames,
+) -> Result<Vec<String>> {
+    let mut qml_specifiers = vec![format!("QML_NAMED_ELEMENT({})", qml_metadata.name)];
 
-            if qml_metadata.uncreatable {
-                qml_specifiers
-                    .push("QML_UNCREATABLE(\"Type cannot be created in QML.\")".to_owned());
-            }
+    if qml_metadata.uncreatable {
+        qml_specifiers.push("QML_UNCREATABLE(\"Type cannot be created in QML.\")".to_owned());
+    }
 
-            if qml_metadata.singleton {
-                qml_specifiers.push("QML_SINGLETON".to_owned());
-            }
-        }
-        GeneratedCppQObjectBlocks {
-            metaobjects: qml_specifiers,
-            ..Default::default()
-        }
+    if qml_metadata.singleton {
+        qml_specifiers.push("QML_SINGLETON".to_owned());
+    }
+
+    // The extension object is created by QML with the extended object as the parent
+    if let Some(extended) = &qml_metadata.extended {
+        qml_specifiers.push(format!(
+            "QML_EXTENDED({})",
+            type_names.cxx_qualified(extended)?
+        ));
     }
+
+    Ok(qml_specifiers)
 }
 
 pub struct GeneratedCppQObject {
@@ -95,6 +146,8 @@ pub struct GeneratedCppQObject {
     pub blocks: GeneratedCppQObjectBlocks,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// Doc comments for the class of the QObject
+    pub docs: String,
 }
 
 impl GeneratedCppQObject {
@@ -112,8 +165,9 @@ impl GeneratedCppQObject {
             name: qobject.name.clone(),
             rust_struct: type_names.lookup(&qobject.rust_type)?.clone(),
             namespace_internals: namespace_idents.internal,
-            blocks: GeneratedCppQObjectBlocks::from(qobject),
+            blocks: GeneratedCppQObjectBlocks::from(qobject, type_names)?,
             has_qobject_macro: qobject.has_qobject_macro,
+            docs: doc_comments(&qobject.docs),
         };
 
         let base_class = if let Some(ident) = &qobject.base_class {
@@ -162,6 +216,16 @@ impl GeneratedCppQObject {
             type_names,
             opt,
         )?);
+        if qobject.list_model.is_some() {
+            generated
+                .blocks
+                .append(&mut generate_list_model(&qobject_idents, &base_class)?);
+        }
+        if qobject.tree_model.is_some() {
+            generated
+                .blocks
+                .append(&mut generate_tree_model(&qobject_idents, &base_class)?);
+        }
         generated.blocks.append(&mut qenum::generate_on_qobject(
             structured_qobject.qenums.iter().cloned(),
             &generated.name,
@@ -200,8 +264,11 @@ impl GeneratedCppQObject {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generator::cpp::property::tests::require_pair;
     use crate::generator::mock_qml_singleton;
     use crate::{generator::structuring::Structures, parser::Parser};
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
     use quote::format_ident;
     use syn::{parse_quote, ItemMod};
 
@@ -354,4 +421,86 @@ mod tests {
             "QML_UNCREATABLE(\"Type cannot be created in QML.\")"
         );
     }
+
+    #[test]
+    fn test_generated_cpp_qobject_attached() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_attached = MyAttached]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type MyAttached = super::MyAttachedRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(cpp.blocks.metaobjects[0], "QML_NAMED_ELEMENT(MyObject)");
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "QML_ATTACHED(cxx_qt::MyAttached)"
+        );
+
+        let (header, source) = require_pair(&cpp.blocks.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "static cxx_qt::MyAttached* qmlAttachedProperties(QObject* object);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            cxx_qt::MyAttached*
+            MyObject::qmlAttachedProperties(QObject* object)
+            {
+              return new cxx_qt::MyAttached(object);
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_extended() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_extended = MyExtension]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type MyExtension = super::MyExtensionRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &parser.type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(cpp.blocks.metaobjects[0], "QML_NAMED_ELEMENT(MyObject)");
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "QML_EXTENDED(cxx_qt::MyExtension)"
+        );
+    }
 }
@@ -6,7 +6,7 @@
 use crate::{
     generator::{
         cfg::try_eval_attributes,
-        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, utils::doc_comments},
         naming::{
             qobject::QObjectNames,
             signals::{QSignalHelperNames, QSignalNames},
@@ -95,7 +95,10 @@ pub fn generate_cpp_signal(
         return Ok(generated);
     }
 
-    // Add the include we need
+    // Add the includes we need
+    generated
+        .includes
+        .insert("#include <cxx-qt/connection.h>".to_owned());
     generated
         .includes
         .insert("#include <cxx-qt/signalhandler.h>".to_owned());
@@ -140,7 +143,8 @@ pub fn generate_cpp_signal(
     // Generate the Q_SIGNAL if this is not an existing signal
     if !signal.inherit {
         generated.methods.push(CppFragment::Header(format!(
-            "Q_SIGNAL void {signal_ident}({parameters_named_types}){is_const};"
+            "{docs}Q_SIGNAL void {signal_ident}({parameters_named_types}){is_const};",
+            docs = doc_comments(&signal.docs),
         )));
     }
 
@@ -184,7 +188,7 @@ pub fn generate_cpp_signal(
             ::QMetaObject::Connection
             {free_connect_ident_cpp}({qobject_ident_namespaced}{reference_type} self, {signal_handler_alias_namespaced} closure, ::Qt::ConnectionType type)
             {{
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &{qobject_ident_namespaced}::{signal_ident},
                     &self,
@@ -326,7 +330,7 @@ mod tests {
             ::QMetaObject::Connection
             MyObject_dataChangedConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::dataChanged,
                     &self,
@@ -340,6 +344,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signals_docs() {
+        let method: ForeignItemFn = parse_quote! {
+            /// Emitted when the data changes
+            #[cxx_name = "dataChanged"]
+            fn data_changed(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+        let generated = generate_cpp_signals(
+            &signals,
+            &qobject_idents,
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "/// Emitted when the data changes\nQ_SIGNAL void dataChanged();"
+        );
+    }
+
     #[test]
     fn test_generate_cpp_signals_mapped_cxx_name() {
         let method: ForeignItemFn = parse_quote! {
@@ -409,7 +439,7 @@ mod tests {
             ::QMetaObject::Connection
             MyObject_dataChangedConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::dataChanged,
                     &self,
@@ -489,7 +519,7 @@ mod tests {
             ::QMetaObject::Connection
             MyObject_baseNameConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerbaseName closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::baseName,
                     &self,
@@ -568,7 +598,7 @@ mod tests {
             ::QMetaObject::Connection
             MyObject_signalRustNameConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlersignalRustName closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &MyObject::signalRustName,
                     &self,
@@ -647,7 +677,7 @@ mod tests {
             ::QMetaObject::Connection
             ObjCpp_signalCxxNameConnect(mynamespace::ObjCpp& self, ::mynamespace::rust::cxxqtgen1::MyObjectCxxQtSignalHandlersignalCxxName closure, ::Qt::ConnectionType type)
             {
-                return ::QObject::connect(
+                return ::rust::cxxqt1::qobjectConnect(
                     &self,
                     &mynamespace::ObjCpp::signalCxxName,
                     &self,
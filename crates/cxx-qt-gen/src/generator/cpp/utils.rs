@@ -3,6 +3,38 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::syntax::expr::expr_to_string;
+use syn::Attribute;
+
+/// Convert the given `#[doc = "..."]` attributes into Doxygen style `///` comments
+///
+/// Each line of the comment is terminated with a newline, so the result can be placed directly
+/// before the item that is being documented.
+pub(crate) fn doc_comments(docs: &[Attribute]) -> String {
+    docs.iter()
+        .filter_map(|attr| attr.meta.require_name_value().ok())
+        .filter_map(|name_value| expr_to_string(&name_value.value).ok())
+        .flat_map(|doc| doc.split('\n').map(str::to_owned).collect::<Vec<_>>())
+        .map(|line| {
+            // Doc comments in Rust usually start with a space after the slashes
+            //
+            // A trailing backslash would continue the comment onto the next line of C++,
+            // so these are removed
+            let line = line
+                .strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .trim_end_matches('\\')
+                .trim_end();
+            if line.is_empty() {
+                "///\n".to_owned()
+            } else {
+                format!("/// {line}\n")
+            }
+        })
+        .collect()
+}
+
 /// A trait to allow indenting multi-line string
 /// This is specifically useful when using formatdoc! with a multi-line string argument.
 /// As the formatdoc! formatting doesn't support indenting multi-line arguments, we can indent
@@ -26,6 +58,45 @@ mod tests {
 
     use indoc::{formatdoc, indoc};
     use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn doc_comments_from_attributes() {
+        let docs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = " A doc comment"] },
+            parse_quote! { #[doc = ""] },
+            parse_quote! { #[doc = "Without a space"] },
+            parse_quote! { #[doc = " Multiple\n lines"] },
+        ];
+
+        assert_str_eq!(
+            doc_comments(&docs),
+            indoc! { r#"
+            /// A doc comment
+            ///
+            /// Without a space
+            /// Multiple
+            /// lines
+        "#}
+        );
+        assert_str_eq!(doc_comments(&[]), "");
+    }
+
+    #[test]
+    fn doc_comments_trailing_backslash() {
+        let docs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = r" A path C:\\"] },
+            parse_quote! { #[doc = r" Continued \ "] },
+        ];
+
+        assert_str_eq!(
+            doc_comments(&docs),
+            indoc! { r#"
+            /// A path C:
+            /// Continued
+        "#}
+        );
+    }
 
     #[test]
     fn indent_string() {
@@ -4,5 +4,6 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 pub mod namespace;
 pub mod property;
+pub mod qgadget;
 pub mod qobject;
 pub mod signals;
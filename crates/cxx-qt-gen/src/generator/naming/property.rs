@@ -54,7 +54,9 @@ pub struct QPropertyNames {
     pub getter: NameState,
     pub setter: Option<NameState>,
     pub notify: Option<NameState>,
-    pub reset: Option<Name>,
+    pub reset: Option<NameState>,
+    pub bindable: Option<Name>,
+    pub binding: Option<Name>,
 }
 
 impl QPropertyNames {
@@ -102,15 +104,33 @@ impl QPropertyNames {
 
         let reset = flags
             .reset
-            .as_ref()
-            .map(|ident| structured_qobject.method_lookup(ident))
+            .clone()
+            .map(|reset| {
+                NameState::from_flag_with_auto_fn(
+                    &reset,
+                    || reset_name_from_property(property_name),
+                    structured_qobject,
+                    false,
+                )
+            })
             .transpose()?;
 
+        let (bindable, binding) = if flags.bindable {
+            (
+                Some(bindable_name_from_property(property_name)),
+                Some(binding_name_from_property(property_name)),
+            )
+        } else {
+            (None, None)
+        };
+
         Ok(Self {
             getter,
             setter,
             notify,
             reset,
+            bindable,
+            binding,
             name: property_name.clone(),
         })
     }
@@ -145,6 +165,30 @@ pub fn notify_name_from_property(name: &Name) -> Name {
         .with_cxx_name(format!("{}Changed", name.cxx_unqualified()))
 }
 
+/// For a given property name generate the reset name
+pub fn reset_name_from_property(name: &Name) -> Name {
+    name.clone()
+        .with_rust_name(format_ident!("reset_{}", name.rust_unqualified()))
+        .with_cxx_name(format!("reset{}", capitalise_first(name.cxx_unqualified())))
+}
+
+/// For a given property name generate the name of the QBindable accessor
+pub fn bindable_name_from_property(name: &Name) -> Name {
+    name.clone()
+        .with_rust_name(format_ident!("bindable_{}", name.rust_unqualified()))
+        .with_cxx_name(format!(
+            "bindable{}",
+            capitalise_first(name.cxx_unqualified())
+        ))
+}
+
+/// For a given property name generate the name of the method which sets a binding
+pub fn binding_name_from_property(name: &Name) -> Name {
+    name.clone()
+        .with_rust_name(format_ident!("bind_{}", name.rust_unqualified()))
+        .with_cxx_name(format!("bind{}", capitalise_first(name.cxx_unqualified())))
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -207,6 +251,54 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_reset_property() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, my_property, cxx_name = "myProperty", READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(&input.attrs[0], CaseConversion::none()).unwrap();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let names = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+        let reset = names.reset.as_ref().unwrap();
+        assert!(matches!(reset, NameState::Auto(_)));
+        assert_eq!(reset.cxx_unqualified(), "resetMyProperty");
+        assert_eq!(reset.rust_unqualified(), "reset_my_property");
+    }
+
+    #[test]
+    fn test_bindable_property() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, my_property, cxx_name = "myProperty", READ, WRITE, NOTIFY, BINDABLE)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(&input.attrs[0], CaseConversion::none()).unwrap();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let names = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+        assert_eq!(
+            names.bindable.as_ref().unwrap().cxx_unqualified(),
+            "bindableMyProperty"
+        );
+        assert_eq!(
+            names.bindable.as_ref().unwrap().rust_unqualified(),
+            "bindable_my_property"
+        );
+        assert_eq!(
+            names.binding.as_ref().unwrap().cxx_unqualified(),
+            "bindMyProperty"
+        );
+        assert_eq!(
+            names.binding.as_ref().unwrap().rust_unqualified(),
+            "bind_my_property"
+        );
+    }
+
     #[test]
     fn test_capitalise_first() {
         assert_eq!(capitalise_first("abc".to_owned()), "Abc".to_owned());
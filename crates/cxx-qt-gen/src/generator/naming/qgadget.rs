@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::{
+    generator::naming::{
+        namespace::NamespaceName,
+        property::{getter_name_from_property, setter_name_from_property},
+    },
+    naming::{Name, TypeNames},
+    parser::{property::ParsedQProperty, qgadget::ParsedQGadget},
+};
+use quote::format_ident;
+use syn::{Ident, Result};
+
+/// Names for parts of a Q_GADGET
+pub struct QGadgetNames {
+    /// The name of the Q_GADGET itself
+    pub name: Name,
+    /// The name of the inner Rust struct
+    pub rust_struct: Name,
+    /// The ident of the Rust type which wraps the boxed inner Rust struct
+    pub rust_wrapper: Ident,
+    /// The namespace for CXX-Qt internals of the Q_GADGET
+    pub namespace_internals: String,
+}
+
+impl QGadgetNames {
+    /// For a given Q_GADGET, create the names associated with it for generation.
+    pub fn from_qgadget(qgadget: &ParsedQGadget, type_names: &TypeNames) -> Result<Self> {
+        Ok(Self {
+            name: qgadget.name.clone(),
+            rust_struct: type_names.lookup(&qgadget.rust_type)?.clone(),
+            rust_wrapper: format_ident!("{}CxxQtGadget", qgadget.name.rust_unqualified()),
+            namespace_internals: NamespaceName::from_namespace_and_ident(
+                qgadget.name.namespace().unwrap_or_default(),
+                qgadget.name.rust_unqualified(),
+            )
+            .internal,
+        })
+    }
+
+    /// For a given C++ function in the given namespace, generate a free function name specific
+    /// to this Q_GADGET.
+    pub fn cxx_qt_ffi_method(&self, cxx_name: &str, namespace: &str) -> Name {
+        let ident = format_ident!(
+            "cxx_qt_ffi_{ident}_{cxx_name}",
+            ident = self.name.cxx_unqualified(),
+        );
+        let mut name = Name::new(ident);
+        if let Some(module) = self.name.module() {
+            name = name.with_module(module.clone());
+        }
+        name.with_namespace(namespace.to_owned())
+            .with_cxx_name(cxx_name.to_owned())
+    }
+}
+
+/// Names for the getter and setter of a Q_PROPERTY on a Q_GADGET
+pub struct QGadgetPropertyNames {
+    pub name: Name,
+    pub getter: Name,
+    pub setter: Option<Name>,
+}
+
+impl From<&ParsedQProperty> for QGadgetPropertyNames {
+    fn from(property: &ParsedQProperty) -> Self {
+        Self {
+            name: property.name.clone(),
+            getter: getter_name_from_property(&property.name),
+            setter: property
+                .flags
+                .write
+                .as_ref()
+                .map(|_| setter_name_from_property(&property.name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::CaseConversion;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_qgadget_names() {
+        let qgadget = ParsedQGadget::parse(
+            parse_quote! {
+                #[qgadget]
+                #[qproperty(i32, x)]
+                #[qproperty(i32, y, READ)]
+                type Point = super::PointRust;
+            },
+            Some("cxx_qt"),
+            &format_ident!("qobject"),
+            CaseConversion::none(),
+        )
+        .unwrap();
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("PointRust", None, None, None);
+
+        let names = QGadgetNames::from_qgadget(&qgadget, &type_names).unwrap();
+        assert_eq!(names.name.cxx_qualified(), "cxx_qt::Point");
+        assert_eq!(names.rust_struct.rust_unqualified(), "PointRust");
+        assert_eq!(names.rust_wrapper, "PointCxxQtGadget");
+        assert_eq!(names.namespace_internals, "cxx_qt::cxx_qt_Point");
+        assert_eq!(
+            names
+                .cxx_qt_ffi_method("qvariantCanConvert", &names.namespace_internals)
+                .into_cxx_parts(),
+            (
+                format_ident!("cxx_qt_ffi_Point_qvariantCanConvert"),
+                vec![
+                    parse_quote! { #[cxx_name="qvariantCanConvert"] },
+                    parse_quote! { #[namespace="cxx_qt::cxx_qt_Point"] },
+                ],
+                parse_quote! { qobject::cxx_qt_ffi_Point_qvariantCanConvert }
+            )
+        );
+
+        let x = QGadgetPropertyNames::from(&qgadget.properties[0]);
+        assert_eq!(x.getter.cxx_unqualified(), "getX");
+        assert_eq!(x.getter.rust_unqualified(), "x");
+        assert_eq!(x.setter.unwrap().cxx_unqualified(), "setX");
+
+        let y = QGadgetPropertyNames::from(&qgadget.properties[1]);
+        assert!(y.setter.is_none());
+    }
+}
@@ -11,6 +11,8 @@ pub struct QSignalNames {
     pub name: Name,
     pub connect_name: Name,
     pub on_name: Ident,
+    pub stream_name: Ident,
+    pub once_name: Ident,
 }
 
 impl From<&ParsedSignal> for QSignalNames {
@@ -19,6 +21,8 @@ impl From<&ParsedSignal> for QSignalNames {
             name: signal.name.clone(),
             connect_name: connect_name_from_signal(&signal.name),
             on_name: on_from_signal(signal.name.rust_unqualified()),
+            stream_name: format_ident!("{}_stream", signal.name.rust_unqualified()),
+            once_name: format_ident!("{}_once", signal.name.rust_unqualified()),
         }
     }
 }
@@ -124,6 +128,8 @@ mod tests {
             &format_ident!("connect_data_changed")
         );
         assert_eq!(names.on_name, format_ident!("on_data_changed"));
+        assert_eq!(names.stream_name, format_ident!("data_changed_stream"));
+        assert_eq!(names.once_name, format_ident!("data_changed_once"));
     }
 
     #[test]
@@ -2,11 +2,14 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::generator::naming::qobject::QObjectNames;
+use crate::generator::naming::{namespace::NamespaceName, qobject::QObjectNames};
 use crate::{
-    generator::rust::{fragment::GeneratedRustFragment, signals::generate_rust_signal},
+    generator::{
+        rust::{fragment::GeneratedRustFragment, signals::generate_rust_signal, threading},
+        structuring::Structures,
+    },
     naming::TypeNames,
-    parser::externcxxqt::ParsedExternCxxQt,
+    parser::{externcxxqt::ParsedExternCxxQt, extract_cfgs},
     syntax::path::path_compare_str,
 };
 use quote::quote;
@@ -15,6 +18,7 @@ use syn::{parse_quote, Attribute, Result};
 impl GeneratedRustFragment {
     pub fn from_extern_cxx_qt(
         extern_cxxqt_block: &ParsedExternCxxQt,
+        structures: &Structures,
         type_names: &TypeNames,
     ) -> Result<Self> {
         let extern_block_namespace = if let Some(namespace) = &extern_cxxqt_block.namespace {
@@ -79,6 +83,20 @@ impl GeneratedRustFragment {
                         #vis type #ident;
                     }
                 }));
+
+                // If this type has threading enabled then add generation
+                if structures.extern_qobject_threading(rust_name) {
+                    let namespace_idents = NamespaceName::from_namespace_and_ident(
+                        ty.name.namespace().unwrap_or_default(),
+                        rust_name,
+                    );
+                    generated.push(threading::generate(
+                        &qobject_names,
+                        &namespace_idents,
+                        type_names,
+                        &extract_cfgs(&ty.declaration.attrs),
+                    )?);
+                }
                 Ok(GeneratedRustFragment::flatten(generated))
             })
             .collect::<Result<Vec<_>>>()?;
@@ -12,8 +12,8 @@ use crate::{
     generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
     parser::method::ParsedMethod,
 };
-use quote::quote;
-use syn::{parse_quote_spanned, spanned::Spanned, Item, Result, ReturnType};
+use quote::{format_ident, quote};
+use syn::{parse_quote_spanned, spanned::Spanned, Ident, Item, Result, ReturnType};
 
 pub fn generate_rust_methods(
     invokables: &[&ParsedMethod],
@@ -36,7 +36,17 @@ pub fn generate_rust_methods(
                 cpp_class_name_rust,
             );
 
-            let return_type = &invokable.method.sig.output;
+            // Async invokables return immediately and deliver their result with a signal
+            let return_type = if invokable.is_async {
+                &ReturnType::Default
+            } else {
+                &invokable.method.sig.output
+            };
+            let invokable_ident_rust = if invokable.is_async {
+                async_wrapper_ident(invokable)
+            } else {
+                invokable_ident_rust.clone()
+            };
 
             let cfgs = &invokable.cfgs;
 
@@ -62,6 +72,12 @@ pub fn generate_rust_methods(
 
             let wrapper_fn = if invokable.wrap {
                 vec![generate_auto_wrap_fn(type_names, qobject_names, invokable)?]
+            } else if invokable.is_async {
+                vec![generate_async_wrapper_fn(
+                    type_names,
+                    qobject_names,
+                    invokable,
+                )?]
             } else {
                 vec![]
             };
@@ -93,6 +109,59 @@ pub fn generate_rust_methods(
     Ok(GeneratedRustFragment::flatten(generated))
 }
 
+/// The name of the Rust method which is called from C++ to start an async invokable
+fn async_wrapper_ident(invokable: &ParsedMethod) -> Ident {
+    format_ident!("cxx_qt_async_{}", invokable.name.rust_unqualified())
+}
+
+/// Generate the method which starts the future of an async invokable
+/// and emits the finished signal with its output on the thread of the QObject
+fn generate_async_wrapper_fn(
+    type_names: &TypeNames,
+    qobject_names: &QObjectNames,
+    invokable: &ParsedMethod,
+) -> Result<Item> {
+    let cfgs = &invokable.cfgs;
+
+    let qualified_impl = type_names.rust_qualified(&invokable.qobject_ident)?;
+
+    let invokable_ident_rust = invokable.name.rust_unqualified();
+    let wrapper_ident_rust = async_wrapper_ident(invokable);
+    let finished_ident_rust = invokable
+        .async_finished_signal()
+        .name
+        .rust_unqualified()
+        .clone();
+
+    let parameter_signatures_qualified = get_params_tokens_qualified(
+        invokable.mutable,
+        &invokable.parameters,
+        &qobject_names.name.rust_qualified(),
+        type_names,
+    )?;
+
+    let call_parameters = get_call_params_tokens(&invokable.parameters);
+
+    let finished = if matches!(invokable.method.sig.output, ReturnType::Default) {
+        quote! { |qobject, ()| qobject.#finished_ident_rust() }
+    } else {
+        quote! { |qobject, result| qobject.#finished_ident_rust(result) }
+    };
+
+    Ok(parse_quote_spanned! {
+        invokable.method.span() =>
+        #(#cfgs)*
+        impl #qualified_impl {
+            #[doc(hidden)]
+            pub fn #wrapper_ident_rust(#parameter_signatures_qualified) {
+                let cxx_qt_thread = ::cxx_qt::Threading::qt_thread(&*self);
+                let cxx_qt_future = self.#invokable_ident_rust(#call_parameters);
+                cxx_qt_thread.spawn_invokable(cxx_qt_future, #finished);
+            }
+        }
+    })
+}
+
 pub fn generate_auto_wrap_fn(
     type_names: &TypeNames,
     qobject_names: &QObjectNames,
@@ -274,4 +343,88 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_async_invokables() {
+        let method1: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            #[cxx_name = "fetch"]
+            async fn fetch(self: Pin<&mut MyObject>, param: &QColor) -> i32;
+        };
+        let method2: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            #[cxx_name = "doWork"]
+            async fn do_work(self: &MyObject);
+        };
+        let invokables = [
+            ParsedMethod::mock_qinvokable(&method1).make_mutable(),
+            ParsedMethod::mock_qinvokable(&method2),
+        ];
+        let qobject_names = create_qobjectname_with_qcolor();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", Some(format_ident!("qobject")), None, None);
+
+        let generated = generate_rust_methods(
+            &invokables.iter().collect::<Vec<_>>(),
+            &qobject_names,
+            &type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // fetch
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "fetch"]
+                    #[doc(hidden)]
+                    fn cxx_qt_async_fetch(self: Pin<&mut MyObject>, param: &QColor);
+                }
+            },
+        );
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_async_fetch(self: Pin<&mut qobject::MyObject>, param: &qobject::QColor) {
+                        let cxx_qt_thread = ::cxx_qt::Threading::qt_thread(&*self);
+                        let cxx_qt_future = self.fetch(param);
+                        cxx_qt_thread.spawn_invokable(cxx_qt_future, |qobject, result| qobject.fetch_finished(result));
+                    }
+                }
+            },
+        );
+
+        // do_work
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "doWork"]
+                    #[doc(hidden)]
+                    fn cxx_qt_async_do_work(self: &MyObject);
+                }
+            },
+        );
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_async_do_work(self: &qobject::MyObject) {
+                        let cxx_qt_thread = ::cxx_qt::Threading::qt_thread(&*self);
+                        let cxx_qt_future = self.do_work();
+                        cxx_qt_thread.spawn_invokable(cxx_qt_future, |qobject, ()| qobject.do_work_finished());
+                    }
+                }
+            },
+        );
+    }
 }
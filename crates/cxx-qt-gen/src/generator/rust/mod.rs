@@ -9,8 +9,10 @@ pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
 pub mod method;
+pub mod model;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
 pub mod qobject;
 pub mod signals;
 pub mod threading;
@@ -21,7 +23,7 @@ use crate::naming::TypeNames;
 use crate::parser::cxxqtdata::ParsedCxxQtData;
 use crate::parser::{parameter::ParsedFunctionParameter, Parser};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_quote, ItemMod, Path, Result};
 
 /// Representation of the generated Rust code for a QObject
@@ -47,13 +49,25 @@ impl GeneratedRustBlocks {
                 .map(|qobject| GeneratedRustFragment::from_qobject(qobject, &parser.type_names))
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
+        fragments.extend(
+            parser
+                .cxx_qt_data
+                .qgadgets()
+                .into_iter()
+                .map(|qgadget| GeneratedRustFragment::from_qgadget(qgadget, &parser.type_names))
+                .collect::<Result<Vec<GeneratedRustFragment>>>()?,
+        );
         fragments.extend(
             parser
                 .cxx_qt_data
                 .extern_cxxqt_blocks
                 .iter()
                 .map(|extern_cxx_block| {
-                    GeneratedRustFragment::from_extern_cxx_qt(extern_cxx_block, &parser.type_names)
+                    GeneratedRustFragment::from_extern_cxx_qt(
+                        extern_cxx_block,
+                        &structures,
+                        &parser.type_names,
+                    )
                 })
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
@@ -65,6 +79,37 @@ impl GeneratedRustBlocks {
             fragments.push(qobject_import);
         }
 
+        // Q_GADGETs and models need QVariant, reuse it if it is already declared
+        let needs_qvariant = !parser.cxx_qt_data.qgadgets().is_empty()
+            || parser
+                .cxx_qt_data
+                .qobjects()
+                .iter()
+                .any(|qobject| qobject.list_model.is_some() || qobject.tree_model.is_some());
+        if needs_qvariant
+            && parser
+                .type_names
+                .lookup(&format_ident!("QVariant"))
+                .is_err()
+        {
+            fragments.push(GeneratedRustFragment::qvariant_import());
+        }
+
+        // Tree models create a QModelIndex for a node, reuse it if it is already declared
+        let needs_qmodelindex = parser
+            .cxx_qt_data
+            .qobjects()
+            .iter()
+            .any(|qobject| qobject.tree_model.is_some());
+        if needs_qmodelindex
+            && parser
+                .type_names
+                .lookup(&format_ident!("QModelIndex"))
+                .is_err()
+        {
+            fragments.push(GeneratedRustFragment::qmodelindex_import());
+        }
+
         let vis = &passthrough_mod.vis;
         let ident = &passthrough_mod.module_ident;
         let docs = &passthrough_mod.docs;
@@ -0,0 +1,562 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
+    naming::TypeNames,
+    parser::model::{ParsedListModel, ParsedTreeModel},
+};
+use syn::{parse_quote, Attribute, Result};
+
+/// Generate the CXX bridge for the list model methods and the implementation of `cxx_qt_lib::ListModel`
+pub fn generate_list_model(
+    list_model: &ParsedListModel,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+    cfgs: &[Attribute],
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let cxx_namespace = qobject_names.namespace_tokens();
+    let item = &list_model.item;
+    let field = &list_model.field;
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![
+            parse_quote! {
+                #(#cfgs)*
+                extern "Rust" {
+                    #[cxx_name = "cxxQtListModelRowCount"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_row_count(self: &#cpp_class_name_rust) -> i32;
+
+                    #[cxx_name = "cxxQtListModelData"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_data(self: &#cpp_class_name_rust, row: i32, role: i32) -> QVariant;
+
+                    #[cxx_name = "cxxQtListModelSetData"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_set_data(self: Pin<&mut #cpp_class_name_rust>, row: i32, role: i32, value: &QVariant) -> bool;
+
+                    #[cxx_name = "cxxQtListModelRoleNames"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_role_names(self: &#cpp_class_name_rust) -> Vec<String>;
+                }
+            },
+            parse_quote! {
+                #(#cfgs)*
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelBeginInsertRows"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_begin_insert_rows(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelEndInsertRows"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_end_insert_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelBeginRemoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_begin_remove_rows(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelEndRemoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_end_remove_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelBeginMoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_begin_move_rows(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32, destination: i32) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelEndMoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_end_move_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelBeginResetModel"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_begin_reset_model(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelEndResetModel"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_end_reset_model(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtListModelRowsChanged"]
+                    #cxx_namespace
+                    fn cxx_qt_list_model_rows_changed(self: Pin<&mut #cpp_class_name_rust>, first: i32, last: i32);
+                }
+            },
+        ],
+        cxx_qt_mod_contents: vec![
+            parse_quote! {
+                #(#cfgs)*
+                impl #qualified_impl {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_row_count(&self) -> i32 {
+                        ::cxx_qt_lib::ListModel::cxx_qt_row_count(self)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_data(&self, row: i32, role: i32) -> ::cxx_qt_lib::QVariant {
+                        ::cxx_qt_lib::ListModel::cxx_qt_data(self, row, role)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_set_data(self: core::pin::Pin<&mut Self>, row: i32, role: i32, value: &::cxx_qt_lib::QVariant) -> bool {
+                        ::cxx_qt_lib::ListModel::cxx_qt_set_data(self, row, role, value)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_role_names(&self) -> ::std::vec::Vec<::std::string::String> {
+                        <Self as ::cxx_qt_lib::ListModel>::cxx_qt_role_names()
+                    }
+                }
+            },
+            parse_quote! {
+                #(#cfgs)*
+                impl ::cxx_qt_lib::ListModel for #qualified_impl {
+                    type Item = #item;
+
+                    fn cxx_qt_items(&self) -> &::std::vec::Vec<Self::Item> {
+                        &self.#field
+                    }
+
+                    fn cxx_qt_items_mut(self: core::pin::Pin<&mut Self>) -> &mut ::std::vec::Vec<Self::Item> {
+                        use ::cxx_qt::CxxQtType;
+                        &mut self.rust_mut().get_mut().#field
+                    }
+
+                    fn cxx_qt_begin_insert_rows(self: core::pin::Pin<&mut Self>, first: i32, last: i32) {
+                        self.cxx_qt_list_model_begin_insert_rows(first, last);
+                    }
+
+                    fn cxx_qt_end_insert_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_list_model_end_insert_rows();
+                    }
+
+                    fn cxx_qt_begin_remove_rows(self: core::pin::Pin<&mut Self>, first: i32, last: i32) {
+                        self.cxx_qt_list_model_begin_remove_rows(first, last);
+                    }
+
+                    fn cxx_qt_end_remove_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_list_model_end_remove_rows();
+                    }
+
+                    fn cxx_qt_begin_move_rows(self: core::pin::Pin<&mut Self>, first: i32, last: i32, destination: i32) -> bool {
+                        self.cxx_qt_list_model_begin_move_rows(first, last, destination)
+                    }
+
+                    fn cxx_qt_end_move_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_list_model_end_move_rows();
+                    }
+
+                    fn cxx_qt_begin_reset_model(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_list_model_begin_reset_model();
+                    }
+
+                    fn cxx_qt_end_reset_model(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_list_model_end_reset_model();
+                    }
+
+                    fn cxx_qt_rows_changed(self: core::pin::Pin<&mut Self>, first: i32, last: i32) {
+                        self.cxx_qt_list_model_rows_changed(first, last);
+                    }
+                }
+            },
+        ],
+    })
+}
+
+/// Generate the CXX bridge for the tree model methods and the implementation of `cxx_qt_lib::TreeModel`
+pub fn generate_tree_model(
+    tree_model: &ParsedTreeModel,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+    cfgs: &[Attribute],
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let cxx_namespace = qobject_names.namespace_tokens();
+    let item = &tree_model.item;
+    let field = &tree_model.field;
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![
+            parse_quote! {
+                #(#cfgs)*
+                extern "Rust" {
+                    #[cxx_name = "cxxQtTreeModelChild"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_child(self: &#cpp_class_name_rust, parent: usize, row: i32) -> usize;
+
+                    #[cxx_name = "cxxQtTreeModelParent"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_parent(self: &#cpp_class_name_rust, id: usize) -> usize;
+
+                    #[cxx_name = "cxxQtTreeModelRow"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_row(self: &#cpp_class_name_rust, id: usize) -> i32;
+
+                    #[cxx_name = "cxxQtTreeModelRowCount"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_row_count(self: &#cpp_class_name_rust, parent: usize) -> i32;
+
+                    #[cxx_name = "cxxQtTreeModelColumnCount"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_column_count(self: &#cpp_class_name_rust) -> i32;
+
+                    #[cxx_name = "cxxQtTreeModelData"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_data(self: &#cpp_class_name_rust, id: usize, column: i32, role: i32) -> QVariant;
+
+                    #[cxx_name = "cxxQtTreeModelSetData"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_set_data(self: Pin<&mut #cpp_class_name_rust>, id: usize, column: i32, role: i32, value: &QVariant) -> bool;
+
+                    #[cxx_name = "cxxQtTreeModelFlags"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_flags(self: &#cpp_class_name_rust, id: usize, column: i32) -> i32;
+
+                    #[cxx_name = "cxxQtTreeModelRoleNames"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_role_names(self: &#cpp_class_name_rust) -> Vec<String>;
+                }
+            },
+            parse_quote! {
+                #(#cfgs)*
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelIndex"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_index(self: &#cpp_class_name_rust, id: usize, column: i32) -> QModelIndex;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelBeginInsertRows"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_begin_insert_rows(self: Pin<&mut #cpp_class_name_rust>, parent: usize, first: i32, last: i32);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelEndInsertRows"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_end_insert_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelBeginRemoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_begin_remove_rows(self: Pin<&mut #cpp_class_name_rust>, parent: usize, first: i32, last: i32);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelEndRemoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_end_remove_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelBeginMoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_begin_move_rows(self: Pin<&mut #cpp_class_name_rust>, source_parent: usize, first: i32, last: i32, destination_parent: usize, destination: i32) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelEndMoveRows"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_end_move_rows(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelBeginResetModel"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_begin_reset_model(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelEndResetModel"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_end_reset_model(self: Pin<&mut #cpp_class_name_rust>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtTreeModelNodeChanged"]
+                    #cxx_namespace
+                    fn cxx_qt_tree_model_node_changed(self: Pin<&mut #cpp_class_name_rust>, id: usize);
+                }
+            },
+        ],
+        cxx_qt_mod_contents: vec![
+            parse_quote! {
+                #(#cfgs)*
+                impl #qualified_impl {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_child(&self, parent: usize, row: i32) -> usize {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_child(self, parent, row)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_parent(&self, id: usize) -> usize {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_parent(self, id)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_row(&self, id: usize) -> i32 {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_row(self, id)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_row_count(&self, parent: usize) -> i32 {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_row_count(self, parent)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_column_count(&self) -> i32 {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_column_count(self)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_data(&self, id: usize, column: i32, role: i32) -> ::cxx_qt_lib::QVariant {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_data(self, id, column, role)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_set_data(self: core::pin::Pin<&mut Self>, id: usize, column: i32, role: i32, value: &::cxx_qt_lib::QVariant) -> bool {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_set_data(self, id, column, role, value)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_flags(&self, id: usize, column: i32) -> i32 {
+                        ::cxx_qt_lib::TreeModel::cxx_qt_flags(self, id, column)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_tree_model_role_names(&self) -> ::std::vec::Vec<::std::string::String> {
+                        <Self as ::cxx_qt_lib::TreeModel>::cxx_qt_role_names()
+                    }
+                }
+            },
+            parse_quote! {
+                #(#cfgs)*
+                impl ::cxx_qt_lib::TreeModel for #qualified_impl {
+                    type Item = #item;
+
+                    fn cxx_qt_tree(&self) -> &::cxx_qt_lib::Tree<Self::Item> {
+                        &self.#field
+                    }
+
+                    fn cxx_qt_tree_mut(self: core::pin::Pin<&mut Self>) -> &mut ::cxx_qt_lib::Tree<Self::Item> {
+                        use ::cxx_qt::CxxQtType;
+                        &mut self.rust_mut().get_mut().#field
+                    }
+
+                    fn cxx_qt_create_index(&self, id: usize, column: i32) -> ::cxx_qt_lib::QModelIndex {
+                        self.cxx_qt_tree_model_index(id, column)
+                    }
+
+                    fn cxx_qt_begin_insert_rows(self: core::pin::Pin<&mut Self>, parent: usize, first: i32, last: i32) {
+                        self.cxx_qt_tree_model_begin_insert_rows(parent, first, last);
+                    }
+
+                    fn cxx_qt_end_insert_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_insert_rows();
+                    }
+
+                    fn cxx_qt_begin_remove_rows(self: core::pin::Pin<&mut Self>, parent: usize, first: i32, last: i32) {
+                        self.cxx_qt_tree_model_begin_remove_rows(parent, first, last);
+                    }
+
+                    fn cxx_qt_end_remove_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_remove_rows();
+                    }
+
+                    fn cxx_qt_begin_move_rows(self: core::pin::Pin<&mut Self>, source_parent: usize, first: i32, last: i32, destination_parent: usize, destination: i32) -> bool {
+                        self.cxx_qt_tree_model_begin_move_rows(source_parent, first, last, destination_parent, destination)
+                    }
+
+                    fn cxx_qt_end_move_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_move_rows();
+                    }
+
+                    fn cxx_qt_begin_reset_model(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_begin_reset_model();
+                    }
+
+                    fn cxx_qt_end_reset_model(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_reset_model();
+                    }
+
+                    fn cxx_qt_node_changed(self: core::pin::Pin<&mut Self>, id: usize) {
+                        self.cxx_qt_tree_model_node_changed(id);
+                    }
+                }
+            },
+        ],
+    })
+}
+
+impl GeneratedRustFragment {
+    /// The declaration of QModelIndex in the CXX bridge, for when it has not been declared by the user
+    pub fn qmodelindex_import() -> Self {
+        Self {
+            cxx_mod_contents: vec![parse_quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qmodelindex.h");
+                    #[doc(hidden)]
+                    #[namespace = ""]
+                    type QModelIndex = ::cxx_qt_lib::QModelIndex;
+                }
+            }],
+            cxx_qt_mod_contents: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use quote::{format_ident, quote};
+
+    #[test]
+    fn test_generate_rust_list_model() {
+        let list_model = ParsedListModel {
+            item: parse_quote! { TodoItem },
+            field: format_ident!("todos"),
+        };
+        let qobject_names =
+            QObjectNames::from_idents(format_ident!("MyObject"), format_ident!("MyObjectRust"));
+
+        let generated = generate_list_model(
+            &list_model,
+            &qobject_names,
+            &TypeNames::mock(),
+            &[parse_quote! { #[cfg(enabled)] }],
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[cfg(enabled)]
+                extern "Rust" {
+                    #[cxx_name = "cxxQtListModelRowCount"]
+                    fn cxx_qt_list_model_row_count(self: &MyObject) -> i32;
+
+                    #[cxx_name = "cxxQtListModelData"]
+                    fn cxx_qt_list_model_data(self: &MyObject, row: i32, role: i32) -> QVariant;
+
+                    #[cxx_name = "cxxQtListModelSetData"]
+                    fn cxx_qt_list_model_set_data(self: Pin<&mut MyObject>, row: i32, role: i32, value: &QVariant) -> bool;
+
+                    #[cxx_name = "cxxQtListModelRoleNames"]
+                    fn cxx_qt_list_model_role_names(self: &MyObject) -> Vec<String>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[cfg(enabled)]
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_row_count(&self) -> i32 {
+                        ::cxx_qt_lib::ListModel::cxx_qt_row_count(self)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_data(&self, row: i32, role: i32) -> ::cxx_qt_lib::QVariant {
+                        ::cxx_qt_lib::ListModel::cxx_qt_data(self, row, role)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_set_data(self: core::pin::Pin<&mut Self>, row: i32, role: i32, value: &::cxx_qt_lib::QVariant) -> bool {
+                        ::cxx_qt_lib::ListModel::cxx_qt_set_data(self, row, role, value)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_list_model_role_names(&self) -> ::std::vec::Vec<::std::string::String> {
+                        <Self as ::cxx_qt_lib::ListModel>::cxx_qt_role_names()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_tree_model() {
+        let tree_model = ParsedTreeModel {
+            item: parse_quote! { FileItem },
+            field: format_ident!("files"),
+        };
+        let qobject_names =
+            QObjectNames::from_idents(format_ident!("MyObject"), format_ident!("MyObjectRust"));
+
+        let generated =
+            generate_tree_model(&tree_model, &qobject_names, &TypeNames::mock(), &[]).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl ::cxx_qt_lib::TreeModel for qobject::MyObject {
+                    type Item = FileItem;
+
+                    fn cxx_qt_tree(&self) -> &::cxx_qt_lib::Tree<Self::Item> {
+                        &self.files
+                    }
+
+                    fn cxx_qt_tree_mut(self: core::pin::Pin<&mut Self>) -> &mut ::cxx_qt_lib::Tree<Self::Item> {
+                        use ::cxx_qt::CxxQtType;
+                        &mut self.rust_mut().get_mut().files
+                    }
+
+                    fn cxx_qt_create_index(&self, id: usize, column: i32) -> ::cxx_qt_lib::QModelIndex {
+                        self.cxx_qt_tree_model_index(id, column)
+                    }
+
+                    fn cxx_qt_begin_insert_rows(self: core::pin::Pin<&mut Self>, parent: usize, first: i32, last: i32) {
+                        self.cxx_qt_tree_model_begin_insert_rows(parent, first, last);
+                    }
+
+                    fn cxx_qt_end_insert_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_insert_rows();
+                    }
+
+                    fn cxx_qt_begin_remove_rows(self: core::pin::Pin<&mut Self>, parent: usize, first: i32, last: i32) {
+                        self.cxx_qt_tree_model_begin_remove_rows(parent, first, last);
+                    }
+
+                    fn cxx_qt_end_remove_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_remove_rows();
+                    }
+
+                    fn cxx_qt_begin_move_rows(self: core::pin::Pin<&mut Self>, source_parent: usize, first: i32, last: i32, destination_parent: usize, destination: i32) -> bool {
+                        self.cxx_qt_tree_model_begin_move_rows(source_parent, first, last, destination_parent, destination)
+                    }
+
+                    fn cxx_qt_end_move_rows(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_move_rows();
+                    }
+
+                    fn cxx_qt_begin_reset_model(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_begin_reset_model();
+                    }
+
+                    fn cxx_qt_end_reset_model(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_tree_model_end_reset_model();
+                    }
+
+                    fn cxx_qt_node_changed(self: core::pin::Pin<&mut Self>, id: usize) {
+                        self.cxx_qt_tree_model_node_changed(id);
+                    }
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::rust::fragment::GeneratedRustFragment;
+use crate::{
+    generator::naming::{
+        property::{NameState, QPropertyNames},
+        qobject::QObjectNames,
+    },
+    naming::rust::syn_type_is_cxx_bridge_unsafe,
+};
+use quote::quote;
+use syn::{parse_quote, Type};
+
+/// Generate the Rust accessors for a BINDABLE property
+///
+/// The value of a bindable property is stored in C++, so rather than implementing the getter
+/// and setter in Rust we bind to the C++ implementations.
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    cxx_ty: &Type,
+) -> Option<GeneratedRustFragment> {
+    let binding = idents.binding.as_ref()?;
+
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let ident_str = idents.name.rust_unqualified().to_string();
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    // Determine if unsafe is required due to an unsafe type
+    let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
+        quote! { unsafe }
+    } else {
+        quote! {}
+    };
+
+    let getter_cpp = idents.getter.cxx_unqualified();
+    let getter_rust = idents.getter.rust_unqualified();
+
+    let mut cxx_mod_contents = vec![parse_quote! {
+        unsafe extern "C++" {
+            #[doc = "Getter for the Q_PROPERTY "]
+            #[doc = #ident_str]
+            #[cxx_name = #getter_cpp]
+            #cxx_namespace
+            fn #getter_rust(self: &#cpp_class_name_rust) -> #cxx_ty;
+        }
+    }];
+
+    if let Some(setter) = &idents.setter {
+        let setter_cpp = setter.cxx_unqualified();
+        let setter_rust = setter.rust_unqualified();
+
+        cxx_mod_contents.push(parse_quote! {
+            unsafe extern "C++" {
+                #[doc = "Setter for the Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[doc = ""]
+                #[doc = "This removes any binding which is currently set on the property."]
+                #[cxx_name = #setter_cpp]
+                #cxx_namespace
+                #has_unsafe fn #setter_rust(self: Pin<&mut #cpp_class_name_rust>, value: #cxx_ty);
+            }
+        });
+    }
+
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset_cpp = reset.cxx_unqualified();
+        let reset_rust = reset.rust_unqualified();
+
+        cxx_mod_contents.push(parse_quote! {
+            unsafe extern "C++" {
+                #[doc = "Reset for the Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[cxx_name = #reset_cpp]
+                #cxx_namespace
+                fn #reset_rust(self: Pin<&mut #cpp_class_name_rust>);
+            }
+        });
+    }
+
+    let binding_cpp = binding.cxx_unqualified();
+    let binding_rust = binding.rust_unqualified();
+    cxx_mod_contents.push(parse_quote! {
+        unsafe extern "C++" {
+            #[doc = "Set a binding on the Q_PROPERTY "]
+            #[doc = #ident_str]
+            #[doc = ""]
+            #[doc = "The function is evaluated lazily, any bindable properties it reads are tracked as dependencies."]
+            #[cxx_name = #binding_cpp]
+            #cxx_namespace
+            fn #binding_rust(self: Pin<&mut #cpp_class_name_rust>, binding: fn(&#cpp_class_name_rust) -> #cxx_ty);
+        }
+    });
+
+    Some(GeneratedRustFragment {
+        cxx_mod_contents,
+        cxx_qt_mod_contents: vec![],
+    })
+}
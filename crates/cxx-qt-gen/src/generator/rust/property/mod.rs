@@ -3,7 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod bindable;
 pub mod getter;
+pub mod qpointer;
+pub mod reset;
 pub mod setter;
 pub mod signal;
 
@@ -31,12 +34,38 @@ pub fn generate_rust_properties(
     for property in properties {
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
 
-        if let Some(getter) = getter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated.append(getter);
-        };
+        if let Some(inner_ty) = property.qobject_pointer() {
+            generated.append(qpointer::generate(
+                &idents,
+                qobject_names,
+                inner_ty,
+                type_names,
+                property.owns_qobject(),
+            )?);
+        } else if let Some(bindable) = bindable::generate(&idents, qobject_names, &property.ty) {
+            generated.append(bindable);
 
-        if let Some(setter) = setter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated.append(setter);
+            if let Some(reset) =
+                reset::generate_bindable_default(&idents, qobject_names, &property.ty, type_names)?
+            {
+                generated.append(reset);
+            }
+        } else {
+            if let Some(getter) =
+                getter::generate(&idents, qobject_names, &property.ty, type_names)?
+            {
+                generated.append(getter);
+            };
+
+            if let Some(setter) =
+                setter::generate(&idents, qobject_names, &property.ty, type_names)?
+            {
+                generated.append(setter);
+            }
+
+            if let Some(reset) = reset::generate(&idents, qobject_names, type_names)? {
+                generated.append(reset);
+            }
         }
 
         if let Some(notify) = signal::generate(&idents, qobject_names) {
@@ -57,10 +86,353 @@ pub fn generate_rust_properties(
 mod tests {
     use super::*;
     use crate::naming::Name;
-    use crate::parser::property::QPropertyFlags;
+    use crate::parser::property::{FlagState, QPropertyFlags};
     use crate::parser::qobject::ParsedQObject;
     use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
     use syn::parse_quote;
+    #[test]
+    fn test_generate_rust_properties_bindable() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("bindable_property", "bindableProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                bindable: true,
+                ..QPropertyFlags::default()
+            },
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter, setter and binding followed by the notify signal
+        assert_eq!(generated.cxx_mod_contents.len(), 6);
+        // Only the notify signal has Rust implementations
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "bindable_property"]
+                    #[cxx_name = "getBindableProperty"]
+                    fn bindable_property(self: &MyObject) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "bindable_property"]
+                    #[doc = ""]
+                    #[doc = "This removes any binding which is currently set on the property."]
+                    #[cxx_name = "setBindableProperty"]
+                    fn set_bindable_property(self: Pin<&mut MyObject>, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Set a binding on the Q_PROPERTY "]
+                    #[doc = "bindable_property"]
+                    #[doc = ""]
+                    #[doc = "The function is evaluated lazily, any bindable properties it reads are tracked as dependencies."]
+                    #[cxx_name = "bindBindableProperty"]
+                    fn bind_bindable_property(self: Pin<&mut MyObject>, binding: fn(&MyObject) -> i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[3],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "bindablePropertyChanged"]
+                    #[doc = "Notify for the Q_PROPERTY bindableProperty"]
+                    fn bindable_property_changed(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_bindable_reset() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("bindable_property", "bindableProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                bindable: true,
+                reset: Some(FlagState::Auto),
+                ..QPropertyFlags::default()
+            },
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter, setter, reset, binding and default followed by the notify signal
+        assert_eq!(generated.cxx_mod_contents.len(), 8);
+        // The default and the notify signal have Rust implementations
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[4],
+            parse_quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "resetBindablePropertyDefault"]
+                    fn cxx_qt_ffi_reset_bindable_property_default(self: &MyObject) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn cxx_qt_ffi_reset_bindable_property_default(&self) -> i32 {
+                        <i32 as Default>::default()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_qpointer() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("object_property", "objectProperty"),
+            ty: parse_quote! { QPointer<MyObject> },
+            flags: QPropertyFlags {
+                reset: Some(FlagState::Auto),
+                ..QPropertyFlags::default()
+            },
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter, setter and reset followed by the notify signal
+        assert_eq!(generated.cxx_mod_contents.len(), 6);
+        // Getter and setter followed by the notify signal
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 10);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "getObjectProperty"]
+                    fn cxx_qt_ffi_object_property(self: &MyObject) -> *mut MyObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "setObjectProperty"]
+                    unsafe fn cxx_qt_ffi_set_object_property(self: Pin<&mut MyObject>, value: *mut MyObject);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = "object_property"]
+                    #[cxx_name = "resetObjectProperty"]
+                    fn reset_object_property(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "object_property"]
+                    #[doc = ""]
+                    #[doc = "The pointer becomes null when the object is destroyed."]
+                    pub fn object_property(&self) -> cxx_qt::QPointer<qobject::MyObject> {
+                        // SAFETY: the object is tracked with a QPointer in C++, so is either null or valid
+                        unsafe { cxx_qt::QPointer::from_raw(self.cxx_qt_ffi_object_property()) }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "object_property"]
+                    pub fn set_object_property(self: core::pin::Pin<&mut Self>, value: &cxx_qt::QPointer<qobject::MyObject>) {
+                        // SAFETY: a QPointer is either null or points to a valid object
+                        unsafe { self.cxx_qt_ffi_set_object_property(value.as_ptr()) }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_qbox() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("object_property", "objectProperty"),
+            ty: parse_quote! { QBox<MyObject> },
+            flags: QPropertyFlags::default(),
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter and setter followed by the notify signal
+        assert_eq!(generated.cxx_mod_contents.len(), 5);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 10);
+
+        // The getter does not own the object, so it returns a QPointer
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "object_property"]
+                    #[doc = ""]
+                    #[doc = "The pointer becomes null when the object is destroyed."]
+                    pub fn object_property(&self) -> cxx_qt::QPointer<qobject::MyObject> {
+                        // SAFETY: the object is tracked with a QPointer in C++, so is either null or valid
+                        unsafe { cxx_qt::QPointer::from_raw(self.cxx_qt_ffi_object_property()) }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "object_property"]
+                    #[doc = ""]
+                    #[doc = "The object is parented to this QObject, which deletes the previous object if it is still its parent."]
+                    pub fn set_object_property(self: core::pin::Pin<&mut Self>, value: cxx_qt::QBox<qobject::MyObject>) {
+                        // SAFETY: a QBox is either null or points to a valid object, which is now owned by the QObject
+                        unsafe { self.cxx_qt_ffi_set_object_property(value.into_raw()) }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_reset() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                reset: Some(FlagState::Auto),
+                ..QPropertyFlags::default()
+            },
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter, setter and reset followed by the notify signal
+        assert_eq!(generated.cxx_mod_contents.len(), 6);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 11);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "resetTrivialProperty"]
+                    fn reset_trivial_property(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    #[doc = ""]
+                    #[doc = "This sets the property back to the value it has in the Default implementation of the Rust struct."]
+                    pub fn reset_trivial_property(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value = <MyObjectRust as Default>::default().trivial_property;
+                        if self.trivial_property == value {
+                            return;
+                        }
+                        self.as_mut().rust_mut().trivial_property = value;
+                        self.as_mut().trivial_property_changed();
+                    }
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generate_rust_properties() {
         let properties = vec![
@@ -269,7 +641,7 @@ mod tests {
             parse_quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "trivialPropertyChanged"]
-                    #[doc = "Notify for the Q_PROPERTY"]
+                    #[doc = "Notify for the Q_PROPERTY trivialProperty"]
                     fn trivial_property_changed(self: Pin<&mut MyObject>);
                 }
             },
@@ -313,10 +685,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_trivial_property_changed<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + 'static + Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_trivial_property_changed(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosuretrivialPropertyChanged>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosuretrivialPropertyChanged, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosuretrivialPropertyChanged>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, | (connection_closure.get_mut())(self_value, )
+                                ));
+                                qobject::MyObject_connect_trivial_property_changed(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -396,7 +775,7 @@ mod tests {
             parse_quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "opaquePropertyChanged"]
-                    #[doc = "Notify for the Q_PROPERTY"]
+                    #[doc = "Notify for the Q_PROPERTY opaqueProperty"]
                     fn opaque_property_changed(self: Pin<&mut MyObject>);
                 }
             },
@@ -440,10 +819,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_opaque_property_changed<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + 'static + Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_opaque_property_changed(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureopaquePropertyChanged>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosureopaquePropertyChanged, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureopaquePropertyChanged>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, | (connection_closure.get_mut())(self_value, )
+                                ));
+                                qobject::MyObject_connect_opaque_property_changed(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -523,7 +909,7 @@ mod tests {
             parse_quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "unsafePropertyChanged"]
-                    #[doc = "Notify for the Q_PROPERTY"]
+                    #[doc = "Notify for the Q_PROPERTY unsafeProperty"]
                     fn unsafe_property_changed(self: Pin<&mut MyObject>);
                 }
             },
@@ -567,10 +953,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_unsafe_property_changed<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + 'static + Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_unsafe_property_changed(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureunsafePropertyChanged>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosureunsafePropertyChanged, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureunsafePropertyChanged>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, | (connection_closure.get_mut())(self_value, )
+                                ));
+                                qobject::MyObject_connect_unsafe_property_changed(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::rust::fragment::GeneratedRustFragment;
+use crate::{
+    generator::naming::{
+        property::{NameState, QPropertyNames},
+        qobject::QObjectNames,
+    },
+    naming::rust::syn_type_cxx_bridge_to_qualified,
+    naming::TypeNames,
+};
+use quote::format_ident;
+use syn::{parse_quote, Result, Type};
+
+/// Generate the Rust accessors for a property of a `QPointer<T>` or `QBox<T>`
+///
+/// The pointer is stored in C++ in a QPointer, so rather than implementing the getter and setter
+/// in Rust we bind to the C++ implementations, which use raw pointers.
+/// These are then wrapped so that a [cxx_qt::QPointer] is used without any unsafe code.
+///
+/// When `owned` is true the setter takes a [cxx_qt::QBox], whose ownership is passed to the QObject.
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    inner_ty: &Type,
+    type_names: &TypeNames,
+    owned: bool,
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let qualified_inner_ty = syn_type_cxx_bridge_to_qualified(inner_ty, type_names)?;
+    let ident_str = idents.name.rust_unqualified().to_string();
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    let getter_cpp = idents.getter.cxx_unqualified();
+    let getter_rust = idents.getter.rust_unqualified();
+    let getter_ffi = format_ident!("cxx_qt_ffi_{getter_rust}");
+
+    let mut generated = GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #[cxx_name = #getter_cpp]
+                #cxx_namespace
+                fn #getter_ffi(self: &#cpp_class_name_rust) -> *mut #inner_ty;
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            impl #qualified_impl {
+                #[doc = "Getter for the Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[doc = ""]
+                #[doc = "The pointer becomes null when the object is destroyed."]
+                pub fn #getter_rust(&self) -> cxx_qt::QPointer<#qualified_inner_ty> {
+                    // SAFETY: the object is tracked with a QPointer in C++, so is either null or valid
+                    unsafe { cxx_qt::QPointer::from_raw(self.#getter_ffi()) }
+                }
+            }
+        }],
+    };
+
+    if let Some(setter) = &idents.setter {
+        let setter_cpp = setter.cxx_unqualified();
+        let setter_rust = setter.rust_unqualified();
+        let setter_ffi = format_ident!("cxx_qt_ffi_{setter_rust}");
+
+        generated.cxx_mod_contents.push(parse_quote! {
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #[cxx_name = #setter_cpp]
+                #cxx_namespace
+                unsafe fn #setter_ffi(self: Pin<&mut #cpp_class_name_rust>, value: *mut #inner_ty);
+            }
+        });
+        generated.cxx_qt_mod_contents.push(if owned {
+            parse_quote! {
+                impl #qualified_impl {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    #[doc = ""]
+                    #[doc = "The object is parented to this QObject, which deletes the previous object if it is still its parent."]
+                    pub fn #setter_rust(self: core::pin::Pin<&mut Self>, value: cxx_qt::QBox<#qualified_inner_ty>) {
+                        // SAFETY: a QBox is either null or points to a valid object, which is now owned by the QObject
+                        unsafe { self.#setter_ffi(value.into_raw()) }
+                    }
+                }
+            }
+        } else {
+            parse_quote! {
+                impl #qualified_impl {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    pub fn #setter_rust(self: core::pin::Pin<&mut Self>, value: &cxx_qt::QPointer<#qualified_inner_ty>) {
+                        // SAFETY: a QPointer is either null or points to a valid object
+                        unsafe { self.#setter_ffi(value.as_ptr()) }
+                    }
+                }
+            }
+        });
+    }
+
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset_cpp = reset.cxx_unqualified();
+        let reset_rust = reset.rust_unqualified();
+
+        generated.cxx_mod_contents.push(parse_quote! {
+            unsafe extern "C++" {
+                #[doc = "Reset for the Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[cxx_name = #reset_cpp]
+                #cxx_namespace
+                fn #reset_rust(self: Pin<&mut #cpp_class_name_rust>);
+            }
+        });
+    }
+
+    Ok(generated)
+}
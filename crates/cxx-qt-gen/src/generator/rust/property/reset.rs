@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::rust::fragment::GeneratedRustFragment;
+use crate::{
+    generator::naming::{
+        property::{NameState, QPropertyNames},
+        qobject::QObjectNames,
+    },
+    naming::rust::syn_type_cxx_bridge_to_qualified,
+    naming::TypeNames,
+};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Result, Type};
+
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<Option<GeneratedRustFragment>> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset_cpp = reset.cxx_unqualified();
+        let reset_rust = reset.rust_unqualified();
+        let ident = &idents.name.rust_unqualified();
+        let ident_str = ident.to_string();
+        let rust_struct = qobject_names.rust_struct.rust_unqualified();
+
+        // Generate a notify name if it was provided, otherwise return empty
+        let notify_binding = match &idents.notify {
+            Some(notify) => {
+                let notify_ident = notify.rust_unqualified();
+                quote! {self.as_mut().#notify_ident();}
+            }
+            None => quote! {},
+        };
+
+        let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+        let cxx_namespace = qobject_names.namespace_tokens();
+
+        Ok(Some(GeneratedRustFragment {
+            cxx_mod_contents: vec![parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = #reset_cpp]
+                    // Needed for QObjects to have a namespace on their type or extern block
+                    //
+                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                    // but to apply it to only certain types, it is needed here too
+                    #cxx_namespace
+                    fn #reset_rust(self: Pin<&mut #cpp_class_name_rust>);
+                }
+            }],
+            cxx_qt_mod_contents: vec![parse_quote! {
+                impl #qualified_impl {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    #[doc = ""]
+                    #[doc = "This sets the property back to the value it has in the Default implementation of the Rust struct."]
+                    pub fn #reset_rust(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value = <#rust_struct as Default>::default().#ident;
+                        if self.#ident == value {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().#ident = value;
+                        #notify_binding
+                    }
+                }
+            }],
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Generate the Rust function which provides the value for the reset of a BINDABLE property
+///
+/// The value of a bindable property is stored in C++ rather than in the Rust struct,
+/// so the reset is implemented in C++ and uses the Default value of the Rust type.
+pub fn generate_bindable_default(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    cxx_ty: &Type,
+    type_names: &TypeNames,
+) -> Result<Option<GeneratedRustFragment>> {
+    let Some(NameState::Auto(reset)) = &idents.reset else {
+        return Ok(None);
+    };
+
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let default_cpp = format!("{}Default", reset.cxx_unqualified());
+    let default_rust = format_ident!("cxx_qt_ffi_{}_default", reset.rust_unqualified());
+    let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    Ok(Some(GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+            extern "Rust" {
+                #[doc(hidden)]
+                #[cxx_name = #default_cpp]
+                #cxx_namespace
+                fn #default_rust(self: &#cpp_class_name_rust) -> #cxx_ty;
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            impl #qualified_impl {
+                #[doc(hidden)]
+                pub fn #default_rust(&self) -> #qualified_ty {
+                    <#qualified_ty as Default>::default()
+                }
+            }
+        }],
+    }))
+}
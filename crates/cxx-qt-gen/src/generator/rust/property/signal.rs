@@ -22,8 +22,13 @@ pub fn generate(idents: &QPropertyNames, qobject_names: &QObjectNames) -> Option
         let notify_rust = notify.rust_unqualified();
         let notify_cpp_str = notify.cxx_unqualified();
 
+        let notify_doc = format!(
+            "Notify for the Q_PROPERTY {}",
+            idents.name.cxx_unqualified()
+        );
+
         let method: ForeignItemFn = syn::parse_quote! {
-            #[doc = "Notify for the Q_PROPERTY"]
+            #[doc = #notify_doc]
             #[cxx_name = #notify_cpp_str]
             fn #notify_rust(self: Pin<&mut #cpp_class_rust>);
         };
@@ -3,9 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::parser::qenum::ParsedQEnum;
+use crate::{naming::Name, parser::qenum::ParsedQEnum};
 use quote::{format_ident, quote_spanned};
-use syn::{parse_quote_spanned, spanned::Spanned};
+use syn::{parse_quote_spanned, spanned::Spanned, LitInt};
 
 use super::fragment::GeneratedRustFragment;
 
@@ -13,15 +13,67 @@ pub fn generate(qenums: &[ParsedQEnum]) -> Vec<GeneratedRustFragment> {
     qenums
         .iter()
         .map(|qenum| {
-            if qenum.qobject.is_none() {
+            let mut generated = if qenum.qobject.is_none() {
                 generate_standalone_qenum(qenum)
             } else {
                 generate_member_qenum(qenum)
+            };
+
+            if let Some(flags) = &qenum.flags {
+                generated.append(generate_qflags(qenum, flags));
             }
+
+            generated
         })
         .collect()
 }
 
+// The QFlags type is an alias of cxx_qt_lib::QFlags, which already implements ExternType using the
+// type id from the QFlag implementation of the enum.
+//
+// cxx-qt-lib depends on cxx-qt, so it can not be re-exported from cxx_qt and the crate using
+// #[qflags] has to depend on cxx-qt-lib itself. The path is spanned to the name of the flags type,
+// so that if the dependency is missing the compiler points at the #[qflags] attribute.
+//
+// The alias is placed in a private module so that it does not collide with any items in the
+// surrounding module, and is then imported into the CXX bridge.
+fn generate_qflags(qenum: &ParsedQEnum, flags: &Name) -> GeneratedRustFragment {
+    let item = &qenum.item;
+    let cfgs = &qenum.cfgs;
+    let repr = &qenum.repr;
+    let qenum_qualified = qenum.name.rust_qualified();
+    let flags_cxx_qualified = flags.cxx_qualified();
+    let (flags_ident, flags_attrs, _qualified) = flags.clone().into_cxx_parts();
+    let module_name = format_ident!("cxx_qt_private_qflags_{flags_ident}");
+    let cxx_qt_lib = quote_spanned! { flags.rust_unqualified().span() => ::cxx_qt_lib };
+    let doc = format!(
+        "[`QFlags`](cxx_qt_lib::QFlags) of [`{}`]",
+        qenum.name.rust_unqualified()
+    );
+
+    GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote_spanned! {
+            item.span() =>
+            #(#cfgs)*
+            extern "C++" {
+                #[doc = #doc]
+                #(#flags_attrs)*
+                type #flags_ident = super::#module_name::#flags_ident;
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote_spanned! {
+            item.span() =>
+            #(#cfgs)*
+            mod #module_name {
+                #[doc = #doc]
+                pub type #flags_ident = #cxx_qt_lib::QFlags<super::#qenum_qualified>;
+
+                #cxx_qt_lib::unsafe_impl_qflag!(super::#qenum_qualified, #flags_cxx_qualified, #repr);
+            }
+        }],
+    }
+}
+
 // Previously we used CXX to generate ourselves a C++ enum that we then imported into the QObject
 // class.
 //
@@ -45,24 +97,31 @@ fn generate_member_qenum(qenum: &ParsedQEnum) -> GeneratedRustFragment {
     let (qenum_ident, qenum_attrs, _qualified) = qenum.name.clone().into_cxx_parts();
     let qenum_ident_str = qenum_ident.to_string();
 
+    let repr = &qenum.repr;
     let variants = qenum
         .variants
         .iter()
-        .enumerate()
-        .map(|(index, variant)| {
-            // Note: The types here must match with the `repr` inside the struct, as quote will
-            // emit the type that exactly matches the input type.
-            let index = index as i32;
+        .map(|variant| {
+            // Note: The suffix here must match with the `repr` inside the struct
+            let ident = &variant.ident;
+            let value = LitInt::new(
+                &format!("{}{repr}", variant.value.unsigned_abs()),
+                ident.span(),
+            );
+            let value = if variant.value < 0 {
+                quote_spanned! { ident.span() => -#value }
+            } else {
+                quote_spanned! { ident.span() => #value }
+            };
             quote_spanned! {
-                variant.span() =>
-                pub const #variant: #qenum_ident = #qenum_ident { repr: #index };
+                ident.span() =>
+                pub const #ident: #qenum_ident = #qenum_ident { repr: #value };
             }
         })
         .collect::<Vec<_>>();
 
     let module_name = format_ident!("cxx_qt_private_qenum_{qenum_ident}");
 
-    // TODO: Support different repr types
     GeneratedRustFragment {
         cxx_mod_contents: vec![parse_quote_spanned! {
             item.span() =>
@@ -83,7 +142,7 @@ fn generate_member_qenum(qenum: &ParsedQEnum) -> GeneratedRustFragment {
                 #[repr(transparent)]
                 pub struct #qenum_ident {
                     #[allow(missing_docs)]
-                    pub repr: i32
+                    pub repr: #repr
                 }
 
                 #[allow(non_upper_case_globals)]
@@ -108,12 +167,13 @@ fn generate_standalone_qenum(qenum: &ParsedQEnum) -> GeneratedRustFragment {
     let variants = &item.variants;
     let docs = &qenum.docs;
     let cfgs = &qenum.cfgs;
+    let repr = &qenum.repr;
 
     GeneratedRustFragment {
         cxx_mod_contents: vec![
             parse_quote_spanned! {
                 item.span() =>
-                    #[repr(i32)]
+                    #[repr(#repr)]
                     #(#cfgs)*
                     #(#docs)*
                     #(#qenum_attrs)*
@@ -250,4 +310,125 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn generates_namespaced_with_discriminants() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                #[namespace="my_namespace"]
+                #[repr(u16)]
+                enum MyEnum {
+                    Variant1 = 0x10,
+                    Variant2 = 0x20,
+                }
+            },
+            None,
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate(&qenums);
+        assert_eq!(generated.len(), 1);
+        assert_tokens_eq(
+            &generated[0].cxx_mod_contents[0],
+            quote! {
+                #[repr(u16)]
+                #[namespace = "my_namespace"]
+                enum MyEnum {
+                    Variant1 = 0x10,
+                    Variant2 = 0x20,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_member_with_discriminants() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                #[repr(i64)]
+                enum MyEnum {
+                    Variant1 = -1,
+                    Variant2 = 0x20,
+                    Variant3,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+        let generated = generate(&qenums);
+        assert_eq!(generated.len(), 1);
+        assert_tokens_eq(
+            &generated[0].cxx_qt_mod_contents[0],
+            quote! {
+                mod cxx_qt_private_qenum_MyEnum {
+                    #[derive(PartialEq, Eq, Clone, Copy)]
+                    #[repr(transparent)]
+                    pub struct MyEnum {
+                        #[allow(missing_docs)]
+                        pub repr: i64
+                    }
+                    #[allow(non_upper_case_globals)]
+                    impl MyEnum {
+                        pub const Variant1: MyEnum = MyEnum { repr: -1i64 };
+                        pub const Variant2: MyEnum = MyEnum { repr: 32i64 };
+                        pub const Variant3: MyEnum = MyEnum { repr: 33i64 };
+                    }
+                    #[automatically_derived]
+                    unsafe impl ::cxx::ExternType for MyEnum {
+                        type Id = ::cxx::type_id!("MyEnum");
+                        type Kind = ::cxx::kind::Trivial;
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_flags() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                #[namespace = "my_namespace"]
+                #[qflags(MyFlags)]
+                #[repr(u32)]
+                enum MyFlag {
+                    A = 1,
+                    B = 2,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+        let generated = generate(&qenums);
+        assert_eq!(generated.len(), 1);
+        let generated = &generated[0];
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "C++" {
+                    #[doc = "[`QFlags`](cxx_qt_lib::QFlags) of [`MyFlag`]"]
+                    #[namespace = "my_namespace"]
+                    type MyFlags = super::cxx_qt_private_qflags_MyFlags::MyFlags;
+                }
+            },
+        );
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                mod cxx_qt_private_qflags_MyFlags {
+                    #[doc = "[`QFlags`](cxx_qt_lib::QFlags) of [`MyFlag`]"]
+                    pub type MyFlags = ::cxx_qt_lib::QFlags<super::qobject::MyFlag>;
+
+                    ::cxx_qt_lib::unsafe_impl_qflag!(super::qobject::MyFlag, "my_namespace::MyFlags", u32);
+                }
+            },
+        );
+    }
 }
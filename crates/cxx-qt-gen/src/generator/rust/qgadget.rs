@@ -0,0 +1,438 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::qgadget::{QGadgetNames, QGadgetPropertyNames},
+        rust::fragment::GeneratedRustFragment,
+    },
+    naming::{
+        rust::{syn_type_cxx_bridge_to_qualified, syn_type_is_cxx_bridge_unsafe},
+        TypeNames,
+    },
+    parser::qgadget::ParsedQGadget,
+};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Result};
+
+impl GeneratedRustFragment {
+    pub fn from_qgadget(qgadget: &ParsedQGadget, type_names: &TypeNames) -> Result<Self> {
+        let names = QGadgetNames::from_qgadget(qgadget, type_names)?;
+        let cfgs = &qgadget.cfgs;
+        let docs = &qgadget.docs;
+
+        let gadget_ident = names.name.rust_unqualified();
+        let gadget_cxx = names.name.cxx_unqualified();
+        let gadget_cxx_qualified = names.name.cxx_qualified();
+        let qualified_impl = type_names.rust_qualified(gadget_ident)?;
+        let rust_struct_ident = names.rust_struct.rust_unqualified();
+        let rust_wrapper = &names.rust_wrapper;
+        let namespace_internals = &names.namespace_internals;
+        let namespace = names
+            .name
+            .namespace()
+            .map(|namespace| quote! { #[namespace = #namespace] });
+        let cxx_name = if *gadget_ident == gadget_cxx {
+            quote! {}
+        } else {
+            quote! { #[cxx_name = #gadget_cxx] }
+        };
+
+        let create_rs_ident = format_ident!("create_rs_{rust_struct_ident}");
+        let clone_rs_ident = format_ident!("clone_rs_{rust_struct_ident}");
+
+        let (can_convert, can_convert_attrs, can_convert_qualified) = names
+            .cxx_qt_ffi_method("qvariantCanConvert", namespace_internals)
+            .into_cxx_parts();
+        let (construct, construct_attrs, construct_qualified) = names
+            .cxx_qt_ffi_method("qvariantConstruct", "rust::cxxqtlib1::qvariant")
+            .into_cxx_parts();
+        let (value_or_default, value_or_default_attrs, value_or_default_qualified) = names
+            .cxx_qt_ffi_method("qvariantValueOrDefault", "rust::cxxqtlib1::qvariant")
+            .into_cxx_parts();
+
+        let mut generated = GeneratedRustFragment {
+            cxx_mod_contents: vec![
+                parse_quote! {
+                    unsafe extern "C++" {
+                        #(#docs)*
+                        #namespace
+                        #cxx_name
+                        #(#cfgs)*
+                        type #gadget_ident = super::#rust_wrapper;
+                    }
+                },
+                parse_quote! {
+                    extern "Rust" {
+                        #namespace
+                        #(#cfgs)*
+                        type #rust_struct_ident;
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    extern "Rust" {
+                        #[cxx_name = "createRs"]
+                        #[namespace = #namespace_internals]
+                        fn #create_rs_ident() -> Box<#rust_struct_ident>;
+
+                        #[cxx_name = "cloneRs"]
+                        #[namespace = #namespace_internals]
+                        fn #clone_rs_ident(value: &#rust_struct_ident) -> Box<#rust_struct_ident>;
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    unsafe extern "C++" {
+                        include!("cxx-qt-lib/qvariant.h");
+
+                        #[doc(hidden)]
+                        #(#can_convert_attrs)*
+                        fn #can_convert(variant: &QVariant) -> bool;
+
+                        #[doc(hidden)]
+                        #(#construct_attrs)*
+                        fn #construct(value: &#gadget_ident) -> QVariant;
+
+                        #[doc(hidden)]
+                        #(#value_or_default_attrs)*
+                        fn #value_or_default(variant: &QVariant) -> #gadget_ident;
+                    }
+                },
+            ],
+            cxx_qt_mod_contents: vec![
+                parse_quote! {
+                    #[doc(hidden)]
+                    #[repr(transparent)]
+                    #(#cfgs)*
+                    pub struct #rust_wrapper {
+                        rust: ::std::boxed::Box<#rust_struct_ident>,
+                    }
+                },
+                // Safety:
+                //
+                // The C++ type only contains a rust::Box, which has the same layout as a Box.
+                // The generated C++ header specialises rust::IsRelocatable for the type.
+                parse_quote! {
+                    #(#cfgs)*
+                    unsafe impl ::cxx::ExternType for #rust_wrapper {
+                        type Id = ::cxx::type_id!(#gadget_cxx_qualified);
+                        type Kind = ::cxx::kind::Trivial;
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    impl ::core::ops::Deref for #rust_wrapper {
+                        type Target = #rust_struct_ident;
+
+                        fn deref(&self) -> &Self::Target {
+                            &self.rust
+                        }
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    impl ::core::ops::DerefMut for #rust_wrapper {
+                        fn deref_mut(&mut self) -> &mut Self::Target {
+                            &mut self.rust
+                        }
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    impl ::core::default::Default for #rust_wrapper {
+                        fn default() -> Self {
+                            Self {
+                                rust: #create_rs_ident(),
+                            }
+                        }
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    impl ::core::clone::Clone for #rust_wrapper {
+                        fn clone(&self) -> Self {
+                            Self {
+                                rust: #clone_rs_ident(&self.rust),
+                            }
+                        }
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    impl ::core::convert::From<#rust_struct_ident> for #rust_wrapper {
+                        fn from(value: #rust_struct_ident) -> Self {
+                            Self {
+                                rust: ::std::boxed::Box::new(value),
+                            }
+                        }
+                    }
+                },
+                parse_quote! {
+                    #(#cfgs)*
+                    impl ::cxx_qt_lib::QVariantValue for #rust_wrapper {
+                        fn can_convert(variant: &::cxx_qt_lib::QVariant) -> bool {
+                            #can_convert_qualified(variant)
+                        }
+
+                        fn construct(value: &Self) -> ::cxx_qt_lib::QVariant {
+                            #construct_qualified(value)
+                        }
+
+                        fn value_or_default(variant: &::cxx_qt_lib::QVariant) -> Self {
+                            #value_or_default_qualified(variant)
+                        }
+                    }
+                },
+                parse_quote! {
+                    #[doc(hidden)]
+                    #[allow(clippy::unnecessary_box_returns)]
+                    #(#cfgs)*
+                    pub fn #create_rs_ident() -> ::std::boxed::Box<#rust_struct_ident> {
+                        // Wrapping the call to Default::default in a Box::new call leads
+                        // to a nicer error message, as it's not trying to infer trait bounds
+                        // on Box, but directly on the given type.
+                        ::std::boxed::Box::new(::core::default::Default::default())
+                    }
+                },
+                parse_quote! {
+                    #[doc(hidden)]
+                    #[allow(clippy::unnecessary_box_returns)]
+                    #(#cfgs)*
+                    pub fn #clone_rs_ident(value: &#rust_struct_ident) -> ::std::boxed::Box<#rust_struct_ident> {
+                        ::std::boxed::Box::new(::core::clone::Clone::clone(value))
+                    }
+                },
+            ],
+        };
+
+        // Properties of this type on a QObject compare the value before setting it,
+        // so they require the gadget to derive PartialEq
+        if qgadget.partial_eq {
+            generated.cxx_qt_mod_contents.push(parse_quote! {
+                #(#cfgs)*
+                impl ::core::cmp::PartialEq for #rust_wrapper {
+                    fn eq(&self, other: &Self) -> bool {
+                        *self.rust == *other.rust
+                    }
+                }
+            });
+        }
+
+        for property in &qgadget.properties {
+            let property_names = QGadgetPropertyNames::from(property);
+            let ident = property_names.name.rust_unqualified();
+            let ident_str = ident.to_string();
+            let getter_cpp = property_names.getter.cxx_unqualified();
+            let getter_rust = property_names.getter.rust_unqualified();
+            let cxx_ty = &property.ty;
+            let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
+
+            generated.cxx_mod_contents.push(parse_quote! {
+                #(#cfgs)*
+                extern "Rust" {
+                    #[cxx_name = #getter_cpp]
+                    #namespace
+                    unsafe fn #getter_rust<'a>(self: &'a #gadget_ident) -> &'a #cxx_ty;
+                }
+            });
+            generated.cxx_qt_mod_contents.push(parse_quote! {
+                #(#cfgs)*
+                impl #qualified_impl {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    pub fn #getter_rust(&self) -> &#qualified_ty {
+                        &self.#ident
+                    }
+                }
+            });
+
+            if let Some(setter) = &property_names.setter {
+                let setter_cpp = setter.cxx_unqualified();
+                let setter_rust = setter.rust_unqualified();
+                let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
+                    quote! { unsafe }
+                } else {
+                    quote! {}
+                };
+
+                generated.cxx_mod_contents.push(parse_quote! {
+                    #(#cfgs)*
+                    extern "Rust" {
+                        #[cxx_name = #setter_cpp]
+                        #namespace
+                        #has_unsafe fn #setter_rust(self: &mut #gadget_ident, value: #cxx_ty);
+                    }
+                });
+                generated.cxx_qt_mod_contents.push(parse_quote! {
+                    #(#cfgs)*
+                    impl #qualified_impl {
+                        #[doc = "Setter for the Q_PROPERTY "]
+                        #[doc = #ident_str]
+                        pub fn #setter_rust(&mut self, value: #qualified_ty) {
+                            self.#ident = value;
+                        }
+                    }
+                });
+            }
+        }
+
+        // If this type is registered as a QML value type then ensure we have the right include
+        if qgadget.qml_name.is_some() {
+            generated.cxx_mod_contents.push(parse_quote! {
+                unsafe extern "C++" {
+                    include!(<QtQml/QQmlEngine>);
+                }
+            });
+        }
+
+        Ok(generated)
+    }
+
+    /// Declare the `QVariant` type for the conversions of Q_GADGETs, if it isn't declared already
+    pub fn qvariant_import() -> Self {
+        Self {
+            cxx_mod_contents: vec![parse_quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qvariant.h");
+                    #[doc(hidden)]
+                    #[namespace = ""]
+                    type QVariant = ::cxx_qt_lib::QVariant;
+                }
+            }],
+            cxx_qt_mod_contents: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_generate_rust_qgadget() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qgadget]
+                    #[qml_element]
+                    #[qproperty(i32, x)]
+                    #[qproperty(i32, y, READ)]
+                    type Point = super::PointRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let generated = GeneratedRustFragment::from_qgadget(
+            parser.cxx_qt_data.qgadgets()[0],
+            &parser.type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 8);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 13);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[namespace = "cxx_qt"]
+                    type Point = super::PointCxxQtGadget;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[3],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qvariant.h");
+
+                    #[doc(hidden)]
+                    #[cxx_name = "qvariantCanConvert"]
+                    #[namespace = "cxx_qt::cxx_qt_Point"]
+                    fn cxx_qt_ffi_Point_qvariantCanConvert(variant: &QVariant) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "qvariantConstruct"]
+                    #[namespace = "rust::cxxqtlib1::qvariant"]
+                    fn cxx_qt_ffi_Point_qvariantConstruct(value: &Point) -> QVariant;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "qvariantValueOrDefault"]
+                    #[namespace = "rust::cxxqtlib1::qvariant"]
+                    fn cxx_qt_ffi_Point_qvariantValueOrDefault(variant: &QVariant) -> Point;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[5],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "setX"]
+                    #[namespace = "cxx_qt"]
+                    fn set_x(self: &mut Point, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                unsafe impl ::cxx::ExternType for PointCxxQtGadget {
+                    type Id = ::cxx::type_id!("cxx_qt::Point");
+                    type Kind = ::cxx::kind::Trivial;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[11],
+            quote! {
+                impl ffi::Point {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "x"]
+                    pub fn set_x(&mut self, value: i32) {
+                        self.x = value;
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_qgadget_partial_eq() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qgadget]
+                    #[derive(PartialEq)]
+                    type Point = super::PointRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let generated = GeneratedRustFragment::from_qgadget(
+            parser.cxx_qt_data.qgadgets()[0],
+            &parser.type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 11);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[10],
+            quote! {
+                impl ::core::cmp::PartialEq for PointCxxQtGadget {
+                    fn eq(&self, other: &Self) -> bool {
+                        *self.rust == *other.rust
+                    }
+                }
+            },
+        );
+    }
+}
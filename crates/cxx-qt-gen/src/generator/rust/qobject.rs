@@ -7,9 +7,14 @@ use crate::{
     generator::{
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         rust::{
-            constructor, cxxqttype, fragment::GeneratedRustFragment, inherit,
-            method::generate_rust_methods, property::generate_rust_properties,
-            signals::generate_rust_signals, threading,
+            constructor, cxxqttype,
+            fragment::GeneratedRustFragment,
+            inherit,
+            method::generate_rust_methods,
+            model::{generate_list_model, generate_tree_model},
+            property::generate_rust_properties,
+            signals::generate_rust_signals,
+            threading,
         },
     },
     naming::TypeNames,
@@ -40,6 +45,23 @@ impl GeneratedRustFragment {
             generate_rust_signals(&structured_qobject.signals, &qobject_names, type_names)?,
         ];
 
+        if let Some(list_model) = &qobject.list_model {
+            generated.push(generate_list_model(
+                list_model,
+                &qobject_names,
+                type_names,
+                &qobject.cfgs,
+            )?);
+        }
+        if let Some(tree_model) = &qobject.tree_model {
+            generated.push(generate_tree_model(
+                tree_model,
+                &qobject_names,
+                type_names,
+                &qobject.cfgs,
+            )?);
+        }
+
         // If this type is using QML declarative macros then ensure we have the right include
         if qobject.qml_metadata.is_some() {
             generated.push(GeneratedRustFragment::from_cxx_item(parse_quote! {
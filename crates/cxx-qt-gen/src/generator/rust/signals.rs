@@ -18,7 +18,7 @@ use crate::{
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{parse_quote, parse_quote_spanned, FnArg, Ident, Result, Type};
+use syn::{parse_quote, parse_quote_spanned, FnArg, Ident, Item, Path, Result, Type};
 
 pub fn generate_rust_signal(
     signal: &ParsedSignal,
@@ -36,7 +36,7 @@ pub fn generate_rust_signal(
 
     let signal_name_cpp = idents.name.cxx_unqualified();
     let connect_ident_rust = idents.connect_name.rust_unqualified();
-    let on_ident_rust = idents.on_name;
+    let on_ident_rust = &idents.on_name;
 
     let free_connect_ident_cpp = idents_helper.connect_name.cxx_unqualified();
     let free_connect_ident_rust = idents_helper.connect_name.rust_unqualified();
@@ -119,6 +119,14 @@ pub fn generate_rust_signal(
     let signal_handler_drop = idents_helper.function_drop;
     let namespace_str = idents_helper.namespace;
 
+    let stream_impl = generate_stream_impl(
+        signal,
+        &idents,
+        &qualified_impl,
+        &self_type_qualified,
+        &parameters_qualified_type,
+    );
+
     let mut cxx_mod_contents = vec![];
 
     // TODO: what happens with RustQt signals, can they be private yet?
@@ -163,88 +171,172 @@ pub fn generate_rust_signal(
             }
         }]);
 
-    Ok(GeneratedRustFragment {
-        cxx_mod_contents,
-        cxx_qt_mod_contents: vec![
-            parse_quote_spanned! {
-                span=>
-                #(#cfgs)*
-                impl #qualified_impl {
-                    #[doc = "Connect the given function pointer to the signal "]
-                    #[doc = #signal_name_cpp]
-                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
-                    #[allow(dead_code)]
-                    pub fn #connect_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
-                    {
-                        cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
-                            conn_type,
-                        ))
-                    }
-                }
-            },
-            parse_quote_spanned! {
-                span=>
-                #(#cfgs)*
-                impl #qualified_impl {
-                    #[doc = "Connect the given function pointer to the signal "]
-                    #[doc = #signal_name_cpp]
-                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
-                    #[doc = "\n"]
-                    #[doc = "Note that this method uses a AutoConnection connection type."]
-                    #[allow(dead_code)]
-                    pub fn #on_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F) -> cxx_qt::QMetaObjectConnectionGuard
-                    {
-                        cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
-                            cxx_qt::ConnectionType::AutoConnection,
-                        ))
-                    }
+    let mut cxx_qt_mod_contents: Vec<Item> = vec![
+        parse_quote_spanned! {
+            span=>
+            #(#cfgs)*
+            impl #qualified_impl {
+                #[doc = "Connect the given function pointer to the signal "]
+                #[doc = #signal_name_cpp]
+                #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                #[allow(dead_code)]
+                pub fn #connect_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
+                {
+                    let sender = &*self as *const Self as *const core::ffi::c_void;
+                    cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<#closure_struct, F>(
+                        sender,
+                        closure,
+                        conn_type,
+                        |conn_type, mut connection_closure| {
+                            let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(
+                                move |self_value: #self_type_qualified, #(#parameters_qualified_arg),*| (connection_closure.get_mut())(self_value, #(#parameters_name),*)
+                            ));
+                            #module_ident::#free_connect_ident_rust(self, handler, conn_type)
+                        },
+                    ))
                 }
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                #[doc(hidden)]
-                pub struct #closure_struct {}
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for #closure_struct {
-                    type Id = cxx::type_id!(#signal_handler_alias_namespaced_str);
-                    type FnType = dyn FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + Send;
-                }
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                use core::mem::drop as #signal_handler_drop;
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                fn #signal_handler_call(
-                    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>,
-                    self_value: #self_type_qualified,
-                    #(#parameters_qualified_arg),*
-                ) {
-                    handler.closure()(self_value, #(#parameters_name),*);
+            }
+        },
+        parse_quote_spanned! {
+            span=>
+            #(#cfgs)*
+            impl #qualified_impl {
+                #[doc = "Connect the given function pointer to the signal "]
+                #[doc = #signal_name_cpp]
+                #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                #[doc = "\n"]
+                #[doc = "Note that this method uses a AutoConnection connection type."]
+                #[allow(dead_code)]
+                pub fn #on_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F) -> cxx_qt::QMetaObjectConnectionGuard
+                {
+                    cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
+                        self,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    ))
                 }
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, usize);
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, [usize; 2]);
-            },
-        ],
+            }
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            #[doc(hidden)]
+            pub struct #closure_struct {}
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for #closure_struct {
+                type Id = cxx::type_id!(#signal_handler_alias_namespaced_str);
+                type FnType = dyn FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + Send;
+            }
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            use core::mem::drop as #signal_handler_drop;
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            fn #signal_handler_call(
+                handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>,
+                self_value: #self_type_qualified,
+                #(#parameters_qualified_arg),*
+            ) {
+                handler.closure()(self_value, #(#parameters_name),*);
+            }
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, usize);
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, [usize; 2]);
+        },
+    ];
+    cxx_qt_mod_contents.extend(stream_impl);
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents,
+        cxx_qt_mod_contents,
+    })
+}
+
+/// Generate the `<signal>_stream` and `<signal>_once` methods for awaiting a signal
+fn generate_stream_impl(
+    signal: &ParsedSignal,
+    idents: &QSignalNames,
+    qualified_impl: &Path,
+    self_type_qualified: &Type,
+    parameters_qualified_type: &[Type],
+) -> Option<Item> {
+    // Only safe signals without pointer parameters can be marked with #[stream] by the parser
+    if !signal.stream {
+        return None;
+    }
+
+    let span = signal.method.span();
+    let cfgs = &signal.cfgs;
+    let signal_name_cpp = idents.name.cxx_unqualified();
+    let on_ident_rust = &idents.on_name;
+    let stream_ident_rust = &idents.stream_name;
+    let once_ident_rust = &idents.once_name;
+
+    let parameters_name: Vec<&Ident> = signal
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident)
+        .collect();
+    // The stream owns the arguments, so references are cloned
+    let (owned_types, owned_values): (Vec<Type>, Vec<TokenStream>) = parameters_qualified_type
+        .iter()
+        .zip(&parameters_name)
+        .map(|(ty, ident)| match ty {
+            Type::Reference(reference) => (
+                reference.elem.as_ref().clone(),
+                quote! { ::core::clone::Clone::clone(#ident) },
+            ),
+            ty => (ty.clone(), quote! { #ident }),
+        })
+        .unzip();
+    let (item_type, item_value) =
+        if let ([owned_type], [owned_value]) = (owned_types.as_slice(), owned_values.as_slice()) {
+            (quote! { #owned_type }, owned_value.clone())
+        } else {
+            (
+                quote! { (#(#owned_types),*) },
+                quote! { (#(#owned_values),*) },
+            )
+        };
+
+    Some(parse_quote_spanned! {
+        span=>
+        #(#cfgs)*
+        impl #qualified_impl {
+            #[doc = "Returns a stream of the arguments of each emission of the signal "]
+            #[doc = #signal_name_cpp]
+            #[doc = ", the signal is disconnected when the stream is dropped."]
+            #[allow(dead_code)]
+            pub fn #stream_ident_rust(self: #self_type_qualified) -> cxx_qt::QSignalStream<#item_type>
+            {
+                cxx_qt::QSignalStream::connect(|sender| {
+                    self.#on_ident_rust(move |_, #(#parameters_name),*| sender.send(#item_value))
+                })
+            }
+
+            #[doc = "Returns a future which resolves to the arguments of the next emission of the signal "]
+            #[doc = #signal_name_cpp]
+            #[doc = ", or to None if the signal is disconnected first."]
+            #[allow(dead_code)]
+            pub fn #once_ident_rust(self: #self_type_qualified) -> cxx_qt::QSignalOnce<#item_type>
+            {
+                self.#stream_ident_rust().once()
+            }
+        }
     })
 }
 
@@ -327,10 +419,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_ready<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + 'static + Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_ready(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosureready, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, | (connection_closure.get_mut())(self_value, )
+                                ));
+                                qobject::MyObject_connect_ready(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -407,6 +506,7 @@ mod tests {
     #[test]
     fn test_generate_rust_signal() {
         let method: ForeignItemFn = parse_quote! {
+            #[stream]
             fn ready(self: Pin<&mut MyObject>);
         };
         let qsignal = ParsedSignal::mock(&method);
@@ -437,9 +537,38 @@ mod tests {
             },
         );
 
+        // The stream methods follow the common contents
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
         common_asserts(
             &generated.cxx_mod_contents[1..].into(),
-            &generated.cxx_qt_mod_contents,
+            &generated.cxx_qt_mod_contents[..8].into(),
+        );
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Returns a stream of the arguments of each emission of the signal "]
+                    #[doc = "ready"]
+                    #[doc = ", the signal is disconnected when the stream is dropped."]
+                    #[allow(dead_code)]
+                    pub fn ready_stream(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::QSignalStream<()>
+                    {
+                        cxx_qt::QSignalStream::connect(|sender| {
+                            self.on_ready(move |_, | sender.send(()))
+                        })
+                    }
+
+                    #[doc = "Returns a future which resolves to the arguments of the next emission of the signal "]
+                    #[doc = "ready"]
+                    #[doc = ", or to None if the signal is disconnected first."]
+                    #[allow(dead_code)]
+                    pub fn ready_once(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::QSignalOnce<()>
+                    {
+                        self.ready_stream().once()
+                    }
+                }
+            },
         );
     }
 
@@ -507,10 +636,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_data_changed<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, i32, cxx::UniquePtr<QColor>) + 'static + Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_data_changed(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosuredataChanged>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosuredataChanged, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosuredataChanged>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, trivial: i32, opaque: cxx::UniquePtr<QColor>| (connection_closure.get_mut())(self_value, trivial, opaque)
+                                ));
+                                qobject::MyObject_connect_data_changed(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -586,6 +722,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_rust_signal_stream_parameters() {
+        let method: ForeignItemFn = parse_quote! {
+            #[cxx_name = "dataChanged"]
+            #[stream]
+            fn data_changed(self: Pin<&mut MyObject>, trivial: i32, point: &QPoint);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+        let qobject_names = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QPoint", None, None, None);
+        let generated = generate_rust_signals(&[&qsignal], &qobject_names, &type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        // References are cloned as the stream owns the arguments
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Returns a stream of the arguments of each emission of the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = ", the signal is disconnected when the stream is dropped."]
+                    #[allow(dead_code)]
+                    pub fn data_changed_stream(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::QSignalStream<(i32, QPoint)>
+                    {
+                        cxx_qt::QSignalStream::connect(|sender| {
+                            self.on_data_changed(move |_, trivial, point| sender.send((trivial, ::core::clone::Clone::clone(point))))
+                        })
+                    }
+
+                    #[doc = "Returns a future which resolves to the arguments of the next emission of the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = ", or to None if the signal is disconnected first."]
+                    #[allow(dead_code)]
+                    pub fn data_changed_once(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::QSignalOnce<(i32, QPoint)>
+                    {
+                        self.data_changed_stream().once()
+                    }
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generate_rust_signal_unsafe() {
         let method = parse_quote! {
@@ -650,10 +832,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_unsafe_signal<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, *mut T) + 'static +Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_unsafe_signal(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureunsafeSignal>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosureunsafeSignal, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureunsafeSignal>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, param: *mut T| (connection_closure.get_mut())(self_value, param)
+                                ));
+                                qobject::MyObject_connect_unsafe_signal(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -795,10 +984,17 @@ mod tests {
                     #[allow(dead_code)]
                     pub fn connect_existing_signal<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + 'static + Send>(self: core::pin::Pin<&mut qobject::MyObject>, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
                     {
-                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_existing_signal(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosurebaseName>::new(Box::new(closure)),
+                        let sender = &*self as *const Self as *const core::ffi::c_void;
+                        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<MyObjectCxxQtSignalClosurebaseName, F>(
+                            sender,
+                            closure,
                             conn_type,
+                            |conn_type, mut connection_closure| {
+                                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosurebaseName>::new(Box::new(
+                                    move |self_value: core::pin::Pin<&mut qobject::MyObject>, | (connection_closure.get_mut())(self_value, )
+                                ));
+                                qobject::MyObject_connect_existing_signal(self, handler, conn_type)
+                            },
                         ))
                     }
                 }
@@ -35,12 +35,25 @@ pub fn generate(
     let (thread_drop_name, thread_drop_attrs, thread_drop_qualified) = qobject_names
         .cxx_qt_ffi_method("cxxQtThreadDrop")
         .into_cxx_parts();
+    let (thread_retain_name, thread_retain_attrs, thread_retain_qualified) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadRetain")
+        .into_cxx_parts();
+    let (thread_release_name, thread_release_attrs, thread_release_qualified) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadRelease")
+        .into_cxx_parts();
     let (thread_fn_name, thread_fn_attrs, thread_fn_qualified) =
         qobject_names.cxx_qt_ffi_method("qtThread").into_cxx_parts();
     let (thread_is_destroyed_name, thread_is_destroyed_attrs, thread_is_destroyed_qualified) =
         qobject_names
             .cxx_qt_ffi_method("cxxQtThreadIsDestroyed")
             .into_cxx_parts();
+    let (
+        thread_is_current_thread_name,
+        thread_is_current_thread_attrs,
+        thread_is_current_thread_qualified,
+    ) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadIsCurrentThread")
+        .into_cxx_parts();
 
     let cxx_qt_thread_namespace = &namespace_ident.namespace;
     let namespace_internals = &namespace_ident.internal;
@@ -79,8 +92,28 @@ pub fn generate(
                         cxx_qt_thread: &#cxx_qt_thread_ident,
                         func: fn(Pin<&mut #cpp_struct_ident>, Box<#cxx_qt_thread_queued_fn_ident>),
                         arg: Box<#cxx_qt_thread_queued_fn_ident>,
+                        coalesce: bool,
+                        coalesce_key: u64,
+                        max_pending: usize,
+                        high_priority: bool,
                     ) -> u8;
 
+                    // SAFETY:
+                    // - Send + 'static: the value is dropped on the thread which releases it
+                    //   or destroys the QObject.
+                    #[doc(hidden)]
+                    #(#thread_retain_attrs)*
+                    #(#cfgs)*
+                    fn #thread_retain_name(
+                        cxx_qt_thread: &#cxx_qt_thread_ident,
+                        value: Box<#cxx_qt_thread_queued_fn_ident>,
+                    ) -> u64;
+
+                    #[doc(hidden)]
+                    #(#thread_release_attrs)*
+                    #(#cfgs)*
+                    fn #thread_release_name(cxx_qt_thread: &#cxx_qt_thread_ident, id: u64);
+
                     #[doc(hidden)]
                     #(#thread_clone_attrs)*
                     #(#cfgs)*
@@ -95,6 +128,11 @@ pub fn generate(
                     #(#thread_is_destroyed_attrs)*
                     #(#cfgs)*
                     fn #thread_is_destroyed_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
+
+                    #[doc(hidden)]
+                    #(#thread_is_current_thread_attrs)*
+                    #(#cfgs)*
+                    fn #thread_is_current_thread_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
                 }
             },
             parse_quote! {
@@ -124,7 +162,13 @@ pub fn generate(
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+                    fn is_current_thread(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #thread_is_current_thread_qualified(cxx_qt_thread)
+                    }
+
+                    #[doc(hidden)]
+                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F, options: cxx_qt::ThreadingQueueOptions) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
                     where
                         F: FnOnce(core::pin::Pin<&mut #qualified_impl>),
                         F: Send + 'static,
@@ -141,12 +185,39 @@ pub fn generate(
                             (arg.inner)(obj)
                         }
                         let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
-                        match #thread_queue_qualified(cxx_qt_thread, func, std::boxed::Box::new(arg)) {
+                        match #thread_queue_qualified(
+                            cxx_qt_thread,
+                            func,
+                            std::boxed::Box::new(arg),
+                            options.coalesce_key.is_some(),
+                            options.coalesce_key.unwrap_or_default(),
+                            options.max_pending.unwrap_or(usize::MAX),
+                            options.high_priority,
+                        ) {
                             0 => Ok(()),
                             others => Err(others.into()),
                         }
                     }
 
+                    #[doc(hidden)]
+                    fn retain<V>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, value: V) -> core::option::Option<u64>
+                    where
+                        V: Send + 'static,
+                    {
+                        // The closure is never called, the value is dropped with the closure
+                        let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(move |_| drop(value)) };
+                        match #thread_retain_qualified(cxx_qt_thread, std::boxed::Box::new(arg)) {
+                            0 => None,
+                            id => Some(id),
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    fn release(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, id: u64)
+                    {
+                        #thread_release_qualified(cxx_qt_thread, id);
+                    }
+
                     #[doc(hidden)]
                     fn threading_clone(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> #module_ident::#cxx_qt_thread_ident
                     {
@@ -221,8 +292,28 @@ mod tests {
                         cxx_qt_thread: &MyObjectCxxQtThread,
                         func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
                         arg: Box<MyObjectCxxQtThreadQueuedFn>,
+                        coalesce: bool,
+                        coalesce_key: u64,
+                        max_pending: usize,
+                        high_priority: bool,
                     ) -> u8;
 
+                    // SAFETY:
+                    // - Send + 'static: the value is dropped on the thread which releases it
+                    //   or destroys the QObject.
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadRetain"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadRetain(
+                        cxx_qt_thread: &MyObjectCxxQtThread,
+                        value: Box<MyObjectCxxQtThreadQueuedFn>,
+                    ) -> u64;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadRelease"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadRelease(cxx_qt_thread: &MyObjectCxxQtThread, id: u64);
+
                     #[doc(hidden)]
                     #[cxx_name = "cxxQtThreadClone"]
                     #[namespace = "rust::cxxqt1"]
@@ -237,6 +328,11 @@ mod tests {
                     #[cxx_name = "cxxQtThreadIsDestroyed"]
                     #[namespace = "rust::cxxqt1"]
                     fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadIsCurrentThread"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
                 }
             },
         );
@@ -269,7 +365,12 @@ mod tests {
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+                    fn is_current_thread(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool {
+                        qobject::cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread)
+                    }
+
+                    #[doc(hidden)]
+                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F, options: cxx_qt::ThreadingQueueOptions) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
                     where
                         F: FnOnce(core::pin::Pin<&mut qobject::MyObject>),
                         F: Send + 'static,
@@ -286,12 +387,39 @@ mod tests {
                             (arg.inner)(obj)
                         }
                         let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(f) };
-                        match qobject::cxx_qt_ffi_MyObject_cxxQtThreadQueue(cxx_qt_thread, func, std::boxed::Box::new(arg)) {
+                        match qobject::cxx_qt_ffi_MyObject_cxxQtThreadQueue(
+                            cxx_qt_thread,
+                            func,
+                            std::boxed::Box::new(arg),
+                            options.coalesce_key.is_some(),
+                            options.coalesce_key.unwrap_or_default(),
+                            options.max_pending.unwrap_or(usize::MAX),
+                            options.high_priority,
+                        ) {
                             0 => Ok(()),
                             others => Err(others.into()),
                         }
                     }
 
+                    #[doc(hidden)]
+                    fn retain<V>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, value: V) -> core::option::Option<u64>
+                    where
+                        V: Send + 'static,
+                    {
+                        // The closure is never called, the value is dropped with the closure
+                        let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(move |_| drop(value)) };
+                        match qobject::cxx_qt_ffi_MyObject_cxxQtThreadRetain(cxx_qt_thread, std::boxed::Box::new(arg)) {
+                            0 => None,
+                            id => Some(id),
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    fn release(cxx_qt_thread: &qobject::MyObjectCxxQtThread, id: u64)
+                    {
+                        qobject::cxx_qt_ffi_MyObject_cxxQtThreadRelease(cxx_qt_thread, id);
+                    }
+
                     #[doc(hidden)]
                     fn threading_clone(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> qobject::MyObjectCxxQtThread
                     {
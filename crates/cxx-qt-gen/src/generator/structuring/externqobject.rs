@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::parser::externqobject::ParsedExternQObject;
+use proc_macro2::Ident;
+
+/// The StructuredExternQObject contains a QObject from an extern "C++Qt" block
+/// and the marker traits which are implemented for it.
+pub struct StructuredExternQObject<'a> {
+    pub declaration: &'a ParsedExternQObject,
+    pub threading: bool,
+}
+
+impl<'a> StructuredExternQObject<'a> {
+    pub fn has_qobject_name(&self, ident: &Ident) -> bool {
+        self.declaration.name.rust_unqualified() == ident
+    }
+
+    /// Creates a [StructuredExternQObject] from a [ParsedExternQObject] without any marker traits
+    pub fn from_extern_qobject(qobject: &'a ParsedExternQObject) -> Self {
+        Self {
+            declaration: qobject,
+            threading: false,
+        }
+    }
+}
@@ -12,20 +12,25 @@
 /// This mostly means grouping QObjects with their QEnums, QSignals, etc...
 ///
 /// All resulting structures are listed in the `Structures` struct.
+pub mod externqobject;
 pub mod qobject;
 
 use crate::parser::{
     cxxqtdata::ParsedCxxQtData,
     trait_impl::{TraitImpl, TraitKind},
 };
+pub use externqobject::StructuredExternQObject;
 pub use qobject::StructuredQObject;
 use syn::{Error, Ident, Result};
 
 /// The list of all structures that could be associated from the parsed data.
 /// Most importantly, this includes the list of qobjects.
+#[derive(Default)]
 pub struct Structures<'a> {
     /// The list of qobjects
     pub qobjects: Vec<StructuredQObject<'a>>,
+    /// The list of qobjects declared in extern "C++Qt" blocks
+    pub extern_qobjects: Vec<StructuredExternQObject<'a>>,
 }
 
 /// Error for looking up against a QObject which isn't registered in Structures
@@ -50,10 +55,36 @@ fn find_qobject<'a, 'b>(
 impl<'a> Structures<'a> {
     fn structure_trait_impls(
         qobjects: &mut [StructuredQObject<'a>],
+        extern_qobjects: &mut [StructuredExternQObject<'a>],
         trait_impls: &'a [TraitImpl],
     ) -> Result<()> {
         // Associate each trait impl with its appropriate qobject
         for imp in trait_impls {
+            if let Some(extern_qobject) = extern_qobjects
+                .iter_mut()
+                .find(|extern_qobject| extern_qobject.has_qobject_name(&imp.qobject))
+            {
+                match imp.kind {
+                    TraitKind::Threading => {
+                        if extern_qobject.threading {
+                            return Err(Error::new_spanned(
+                                &imp.declaration,
+                                format!(
+                                    "Threading already enabled on QObject {qobject}!",
+                                    qobject = imp.qobject
+                                ),
+                            ));
+                        }
+                        extern_qobject.threading = true;
+                    }
+                    TraitKind::Constructor(_) => return Err(Error::new_spanned(
+                        &imp.declaration,
+                        "Constructors cannot be declared for QObjects in extern \"C++Qt\" blocks!",
+                    )),
+                }
+                continue;
+            }
+
             let qobject = find_qobject(qobjects, &imp.qobject)?;
             match imp.kind {
                 TraitKind::Threading => {
@@ -109,9 +140,99 @@ impl<'a> Structures<'a> {
             qobject.signals.push(signal);
         }
 
-        Self::structure_trait_impls(&mut qobjects, &cxxqtdata.trait_impls)?;
+        let mut extern_qobjects: Vec<_> = cxxqtdata
+            .extern_cxxqt_blocks
+            .iter()
+            .flat_map(|block| &block.qobjects)
+            .map(StructuredExternQObject::from_extern_qobject)
+            .collect();
+
+        Self::structure_trait_impls(&mut qobjects, &mut extern_qobjects, &cxxqtdata.trait_impls)?;
+
+        // The attached and extension objects of a QML element are constructed with the element
+        // as their parent, so they must be QObjects which are declared in the bridge
+        let is_declared_qobject = |ident: &Ident| {
+            qobjects.iter().any(|other| other.has_qobject_name(ident))
+                || extern_qobjects
+                    .iter()
+                    .any(|other| other.has_qobject_name(ident))
+        };
+        let qml_metadata = qobjects
+            .iter()
+            .filter_map(|qobject| qobject.declaration.qml_metadata.as_ref())
+            .chain(
+                extern_qobjects
+                    .iter()
+                    .filter_map(|qobject| qobject.declaration.qml_metadata.as_ref()),
+            );
+        for qml_metadata in qml_metadata {
+            if let Some(attached) = &qml_metadata.attached {
+                if !is_declared_qobject(attached) {
+                    return Err(Error::new_spanned(
+                        attached,
+                        format!("The attached type `{attached}` must be a #[qobject] declared in the bridge"),
+                    ));
+                }
+            }
+
+            if let Some(extended) = &qml_metadata.extended {
+                if !is_declared_qobject(extended) {
+                    return Err(Error::new_spanned(
+                        extended,
+                        format!("The extension type `{extended}` must be a #[qobject] declared in the bridge"),
+                    ));
+                }
+            }
+        }
+
+        // Async invokables deliver their result on the thread of the QObject
+        for qobject in &qobjects {
+            for method in qobject.methods.iter().filter(|method| method.is_async) {
+                if !qobject.threading {
+                    return Err(Error::new_spanned(
+                        &method.method.sig,
+                        format!(
+                            "async invokables require `impl cxx_qt::Threading for {qobject} {{}}`",
+                            qobject = qobject.declaration.name.rust_unqualified()
+                        ),
+                    ));
+                }
+
+                // The generated finished signal is also in the signals, so any other match is a collision
+                let finished_signal = method.async_finished_signal();
+                let finished = &finished_signal.name;
+                let matching = qobject
+                    .signals
+                    .iter()
+                    .filter(|signal| {
+                        signal.name.rust_unqualified() == finished.rust_unqualified()
+                            || signal.name.cxx_unqualified() == finished.cxx_unqualified()
+                    })
+                    .count();
+                if matching > 1 {
+                    return Err(Error::new_spanned(
+                        &method.method.sig,
+                        format!(
+                            "The async invokable `{invokable}` generates a `{signal}` signal, which is already declared on the QObject",
+                            invokable = method.name.rust_unqualified(),
+                            signal = finished.rust_unqualified()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(Structures {
+            qobjects,
+            extern_qobjects,
+        })
+    }
 
-        Ok(Structures { qobjects })
+    /// Returns whether threading is enabled for the extern "C++Qt" QObject with the given name
+    pub fn extern_qobject_threading(&self, ident: &Ident) -> bool {
+        self.extern_qobjects.iter().any(|extern_qobject| {
+            extern_qobject.has_qobject_name(ident) && extern_qobject.threading
+        })
     }
 }
 
@@ -263,6 +384,54 @@ mod tests {
         assert!(Structures::new(&parser.cxx_qt_data).is_err());
     }
 
+    #[test]
+    fn test_async_invokable_threading() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {
+                extern "RustQt" {
+                    #[qinvokable]
+                    async fn fetch(self: Pin<&mut MyObject>) -> i32;
+                }
+            },
+            parse_quote! {impl cxx_qt::Threading for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let qobject = structures.qobjects.first().unwrap();
+        assert!(qobject.methods[0].is_async);
+        assert!(qobject
+            .signal_lookup(&format_ident!("fetch_finished"))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_extern_qobject_threading() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+
+                    #[qobject]
+                    type QQuickItem;
+                }
+
+                impl cxx_qt::Threading for QTimer {}
+            }
+        };
+
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert!(structures.qobjects.is_empty());
+        assert_eq!(structures.extern_qobjects.len(), 2);
+        assert!(structures.extern_qobject_threading(&format_ident!("QTimer")));
+        assert!(!structures.extern_qobject_threading(&format_ident!("QQuickItem")));
+    }
+
     #[test]
     fn test_create_invalid_structures() {
         assert_parse_errors! {
@@ -319,6 +488,78 @@ mod tests {
                 }
             }
 
+            {
+                // Async invokable without threading
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        async fn fetch(self: Pin<&mut MyObject>) -> i32;
+                    }
+                }
+            }
+
+            {
+                // Attached type which is not a QObject
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qml_attached = MyAttached]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            }
+
+            {
+                // Extension type which is not a QObject
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qml_extended = MyExtension]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            }
+
+            {
+                // Extension type of an extern QObject which is not a QObject
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "C++Qt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qml_extended = MyExtension]
+                        type QPushButton;
+                    }
+                }
+            }
+
+            {
+                // Async invokable with a finished signal which is already declared
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        async fn fetch(self: Pin<&mut MyObject>) -> i32;
+
+                        #[qsignal]
+                        fn fetch_finished(self: Pin<&mut MyObject>);
+                    }
+
+                    impl cxx_qt::Threading for MyObject {}
+                }
+            }
+
             {
                 // Undeclared QObject for inherited method
                 #[cxx_qt::bridge]
@@ -329,6 +570,33 @@ mod tests {
                     }
                 }
             }
+
+            {
+                // Threading enabled twice on an extern QObject
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "C++Qt" {
+                        #[qobject]
+                        type QTimer;
+                    }
+
+                    impl cxx_qt::Threading for QTimer {}
+                    impl cxx_qt::Threading for QTimer {}
+                }
+            }
+
+            {
+                // Constructor for an extern QObject
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "C++Qt" {
+                        #[qobject]
+                        type QTimer;
+                    }
+
+                    impl cxx_qt::Constructor<()> for QTimer {}
+                }
+            }
         }
     }
 }
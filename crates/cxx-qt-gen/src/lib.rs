@@ -256,6 +256,36 @@ mod tests {
         test_code_generation!("qenum");
     }
 
+    #[test]
+    fn generates_qgadget() {
+        test_code_generation!("qgadget");
+    }
+
+    #[test]
+    fn generates_async_invokables() {
+        test_code_generation!("async_invokables");
+    }
+
+    #[test]
+    fn generates_extern_threading() {
+        test_code_generation!("extern_threading");
+    }
+
+    #[test]
+    fn generates_list_model() {
+        test_code_generation!("list_model");
+    }
+
+    #[test]
+    fn generates_tree_model() {
+        test_code_generation!("tree_model");
+    }
+
+    #[test]
+    fn generates_qpointer() {
+        test_code_generation!("qpointer");
+    }
+
     #[test]
     #[should_panic]
     fn fail_token_assert() {
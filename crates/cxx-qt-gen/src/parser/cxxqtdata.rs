@@ -9,11 +9,11 @@ use crate::parser::externrustqt::ParsedExternRustQt;
 use crate::{
     parser::{
         externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        qenum::ParsedQEnum, qobject::ParsedQObject, signals::ParsedSignal,
+        qenum::ParsedQEnum, qgadget::ParsedQGadget, qobject::ParsedQObject, signals::ParsedSignal,
     },
     syntax::{attribute::attribute_get_path, path::path_compare_str},
 };
-use syn::{Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, Meta, Result};
+use syn::{Error, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, Meta, Result};
 
 pub struct ParsedCxxQtData {
     /// List of QEnums defined in the module, that aren't associated with a QObject
@@ -71,6 +71,10 @@ impl ParsedCxxQtData {
         self.flatten_rust_blocks(|block| &block.qobjects)
     }
 
+    pub fn qgadgets(&self) -> Vec<&ParsedQGadget> {
+        self.flatten_rust_blocks(|block| &block.qgadgets)
+    }
+
     /// Determine if the given [syn::Item] is a CXX-Qt related item
     /// If it is then add the [syn::Item] into qobjects BTreeMap
     /// Otherwise return the [syn::Item] to pass through to CXX
@@ -106,7 +110,21 @@ impl ParsedCxxQtData {
 
     fn parse_macro(&mut self, item: ItemMacro) -> Result<Option<Item>> {
         if path_compare_str(&item.mac.path, &["qnamespace"]) {
+            let tokens = item.mac.tokens.clone();
             let qnamespace = ParsedQNamespace::parse(item)?;
+            if self
+                .qnamespaces
+                .iter()
+                .any(|existing| existing.namespace == qnamespace.namespace)
+            {
+                return Err(Error::new_spanned(
+                    tokens,
+                    format!(
+                        "qnamespace `{}` is already declared in this bridge",
+                        qnamespace.namespace
+                    ),
+                ));
+            }
             self.qnamespaces.push(qnamespace);
             Ok(None)
         } else {
@@ -176,6 +194,7 @@ mod tests {
         cxx_qt_data.extern_rustqt_blocks.push(ParsedExternRustQt {
             unsafety: None,
             qobjects: vec![create_parsed_qobject(), create_parsed_qobject()],
+            qgadgets: vec![],
             methods: vec![],
             signals: vec![],
             inherited_methods: vec![],
@@ -482,6 +501,29 @@ mod tests {
         assert_eq!(1, cxxqtdata.qenums.len());
     }
 
+    #[test]
+    fn test_parse_duplicate_qnamespace() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let qnamespace: Item = parse_quote! { qnamespace!("my_namespace"); };
+        assert!(cxxqtdata.parse_cxx_qt_item(qnamespace).unwrap().is_none());
+        assert!(cxxqtdata
+            .parse_cxx_qt_item(parse_quote! { qnamespace!("other_namespace"); })
+            .unwrap()
+            .is_none());
+        assert_eq!(2, cxxqtdata.qnamespaces.len());
+
+        let qnamespace: Item = parse_quote! { qnamespace!("my_namespace"); };
+        assert_eq!(
+            cxxqtdata
+                .parse_cxx_qt_item(qnamespace)
+                .err()
+                .unwrap()
+                .to_string(),
+            "qnamespace `my_namespace` is already declared in this bridge"
+        );
+    }
+
     #[test]
     fn test_find_qobjects() {
         let mut parsed_cxxqtdata = ParsedCxxQtData::new(format_ident!("ffi"), None);
@@ -6,13 +6,14 @@
 use crate::parser::signals::ImmutabilityConstraint;
 use crate::{
     parser::{
-        externqobject::ParsedExternQObject, require_attributes, signals::ParsedSignal,
-        CaseConversion,
+        externqobject::ParsedExternQObject, extract_cfgs, prepend_cfgs, require_attributes,
+        signals::ParsedSignal, CaseConversion,
     },
     syntax::{attribute::attribute_get_path, expr::expr_to_string},
 };
 use syn::{
-    spanned::Spanned, Error, ForeignItem, ForeignItemFn, Ident, ItemForeignMod, Result, Token,
+    spanned::Spanned, Error, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic,
+    ForeignItemType, Ident, ItemForeignMod, Result, Token,
 };
 
 /// Representation of an extern "C++Qt" block
@@ -36,12 +37,14 @@ impl ParsedExternCxxQt {
         module_ident: &Ident,
         parent_namespace: Option<&str>,
     ) -> Result<Self> {
-        // TODO: support cfg on foreign mod blocks
         let attrs = require_attributes(
             &foreign_mod.attrs,
-            &["namespace", "auto_cxx_name", "auto_rust_name"],
+            &["cfg", "namespace", "auto_cxx_name", "auto_rust_name"],
         )?;
 
+        // Any cfgs on the block apply to every item within it
+        let cfgs = extract_cfgs(&foreign_mod.attrs);
+
         let auto_case = CaseConversion::from_attrs(&attrs)?;
 
         let namespace = attrs
@@ -60,7 +63,15 @@ impl ParsedExternCxxQt {
         let mut qobjects = vec![];
 
         // Parse any signals, other items are passed through
-        for item in foreign_mod.items.drain(..) {
+        for mut item in foreign_mod.items.drain(..) {
+            match &mut item {
+                ForeignItem::Fn(ForeignItemFn { attrs, .. })
+                | ForeignItem::Static(ForeignItemStatic { attrs, .. })
+                | ForeignItem::Type(ForeignItemType { attrs, .. })
+                | ForeignItem::Macro(ForeignItemMacro { attrs, .. }) => prepend_cfgs(attrs, &cfgs),
+                _ => {}
+            }
+
             match item {
                 ForeignItem::Fn(foreign_fn) => {
                     extern_cxx_block.parse_invokable(foreign_fn, auto_case)?;
@@ -132,7 +143,7 @@ mod tests {
     use quote::format_ident;
 
     use crate::tests::assert_parse_errors;
-    use syn::parse_quote;
+    use syn::{parse_quote, Attribute};
 
     #[test]
     fn test_find_and_merge_cxx_qt_item_extern_cxx_qt() {
@@ -161,6 +172,40 @@ mod tests {
         assert!(extern_cxx_qt.unsafety.is_some());
     }
 
+    #[test]
+    fn test_extern_cxxqt_block_cfg() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                #[cfg(feature = "gui")]
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QPushButton;
+
+                    fn method(self: Pin<&mut QPushButton>);
+
+                    #[qsignal]
+                    #[cfg(enabled)]
+                    fn clicked(self: Pin<&mut QPushButton>, checked: bool);
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        let cfg_block: Attribute = parse_quote! { #[cfg(feature = "gui")] };
+        let cfg_signal: Attribute = parse_quote! { #[cfg(enabled)] };
+        assert_eq!(
+            extract_cfgs(&extern_cxx_qt.qobjects[0].declaration.attrs),
+            vec![cfg_block.clone()]
+        );
+        let ForeignItem::Fn(method) = &extern_cxx_qt.passthrough_items[0] else {
+            panic!("Expected a passthrough method");
+        };
+        assert_eq!(extract_cfgs(&method.attrs), vec![cfg_block.clone()]);
+        assert_eq!(extern_cxx_qt.signals[0].cfgs, vec![cfg_block, cfg_signal]);
+    }
+
     #[test]
     fn test_extern_cxxqt_type_qobject_attr() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(
@@ -182,6 +227,28 @@ mod tests {
         assert!(extern_cxx_qt.unsafety.is_none());
     }
 
+    #[test]
+    fn test_extern_cxxqt_type_qml_foreign() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qml_element = "PushButton"]
+                    #[qml_uncreatable]
+                    type QPushButton;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        let qml_metadata = extern_cxx_qt.qobjects[0].qml_metadata.as_ref().unwrap();
+        assert_eq!(qml_metadata.name, "PushButton");
+        assert!(qml_metadata.uncreatable);
+        assert!(qml_metadata.extended.is_none());
+    }
+
     #[test]
     fn test_extern_cxxqt_type_non_type() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(
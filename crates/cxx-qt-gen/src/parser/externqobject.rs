@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::naming::Name;
+use crate::parser::qobject::QmlElementMetadata;
 use crate::parser::{parse_base_type, require_attributes, CaseConversion};
 use syn::{ForeignItemType, Ident, Result};
 
@@ -14,10 +15,12 @@ pub struct ParsedExternQObject {
     pub declaration: ForeignItemType,
     /// The base class of the struct
     pub base_class: Option<Ident>,
+    /// QML specifiers to register the existing type with in QML
+    pub qml_metadata: Option<QmlElementMetadata>,
 }
 
 impl ParsedExternQObject {
-    const ALLOWED_ATTRS: [&'static str; 7] = [
+    const ALLOWED_ATTRS: [&'static str; 10] = [
         "cxx_name",
         "rust_name",
         "namespace",
@@ -25,6 +28,9 @@ impl ParsedExternQObject {
         "doc",
         "qobject",
         "base",
+        "qml_element",
+        "qml_uncreatable",
+        "qml_extended",
     ];
 
     pub fn parse(
@@ -36,16 +42,20 @@ impl ParsedExternQObject {
 
         let base_class = parse_base_type(&attributes)?;
 
+        let name = Name::from_ident_and_attrs(
+            &ty.ident,
+            &ty.attrs,
+            parent_namespace,
+            Some(module_ident),
+            CaseConversion::none(),
+        )?;
+        let qml_metadata = QmlElementMetadata::parse(&name, &attributes)?;
+
         Ok(Self {
-            name: Name::from_ident_and_attrs(
-                &ty.ident,
-                &ty.attrs,
-                parent_namespace,
-                Some(module_ident),
-                CaseConversion::none(),
-            )?,
+            name,
             declaration: ty,
             base_class,
+            qml_metadata,
         })
     }
 }
@@ -6,9 +6,10 @@
 use crate::naming::cpp::err_unsupported_item;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
+use crate::parser::qgadget::ParsedQGadget;
 use crate::parser::qobject::ParsedQObject;
 use crate::parser::signals::ParsedSignal;
-use crate::parser::{require_attributes, CaseConversion};
+use crate::parser::{extract_cfgs, prepend_cfgs, require_attributes, CaseConversion};
 use crate::syntax::attribute::attribute_get_path;
 use crate::syntax::expr::expr_to_string;
 use crate::syntax::foreignmod::ForeignTypeIdentAlias;
@@ -23,6 +24,8 @@ pub struct ParsedExternRustQt {
     pub unsafety: Option<Token![unsafe]>,
     /// List of QObjects defined in the module
     pub qobjects: Vec<ParsedQObject>,
+    /// List of Q_GADGETs defined in the module
+    pub qgadgets: Vec<ParsedQGadget>,
     /// List of methods and Q_INVOKABLES found
     pub methods: Vec<ParsedMethod>,
     /// List of the Q_SIGNALS found
@@ -37,12 +40,14 @@ impl ParsedExternRustQt {
         module_ident: &Ident,
         parent_namespace: Option<&str>,
     ) -> Result<Self> {
-        // TODO: support cfg on foreign mod blocks
         let attrs = require_attributes(
             &foreign_mod.attrs,
-            &["namespace", "auto_cxx_name", "auto_rust_name"],
+            &["cfg", "namespace", "auto_cxx_name", "auto_rust_name"],
         )?;
 
+        // Any cfgs on the block apply to every item within it
+        let cfgs = extract_cfgs(&foreign_mod.attrs);
+
         let auto_case = CaseConversion::from_attrs(&attrs)?;
 
         let mut extern_rustqt_block = Self {
@@ -58,11 +63,24 @@ impl ParsedExternRustQt {
 
         for item in foreign_mod.items.drain(..) {
             match item {
-                ForeignItem::Fn(foreign_fn) => {
+                ForeignItem::Fn(mut foreign_fn) => {
+                    prepend_cfgs(&mut foreign_fn.attrs, &cfgs);
                     extern_rustqt_block.parse_invokable(foreign_fn, auto_case)?;
                 }
                 ForeignItem::Verbatim(tokens) => {
-                    let foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
+                    let mut foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
+                    prepend_cfgs(&mut foreign_alias.attrs, &cfgs);
+
+                    // Load the Q_GADGET
+                    if attribute_get_path(&foreign_alias.attrs, &["qgadget"]).is_some() {
+                        extern_rustqt_block.qgadgets.push(ParsedQGadget::parse(
+                            foreign_alias,
+                            namespace.as_deref(),
+                            module_ident,
+                            auto_case,
+                        )?);
+                        continue;
+                    }
 
                     // Load the QObject
                     let qobject = ParsedQObject::parse(
@@ -120,6 +138,12 @@ impl ParsedExternRustQt {
         } else {
             let parsed_method =
                 ParsedMethod::parse(foreign_fn, auto_case, self.unsafety.is_some())?;
+
+            // Async invokables deliver their result with a generated signal
+            if parsed_method.is_async {
+                self.signals.push(parsed_method.async_finished_signal());
+            }
+
             self.methods.push(parsed_method);
         }
 
@@ -133,7 +157,7 @@ mod tests {
     use crate::naming::Name;
     use crate::tests::assert_parse_errors;
     use quote::format_ident;
-    use syn::parse_quote;
+    use syn::{parse_quote, Attribute};
 
     #[test]
     fn test_parse_qsignals_safe() {
@@ -211,6 +235,78 @@ mod tests {
         assert!(!methods[1].is_qinvokable);
     }
 
+    #[test]
+    fn test_parse_async_invokable() {
+        let block: ItemForeignMod = parse_quote! {
+            extern "RustQt" {
+                #[qinvokable]
+                #[cfg(enabled)]
+                async fn fetch(self: Pin<&mut MyObject>, url: QString) -> QString;
+
+                #[qinvokable]
+                #[cxx_name = "doWork"]
+                async fn do_work(self: &MyObject);
+            }
+        };
+        let parsed_rust_qt =
+            ParsedExternRustQt::parse(block, &format_ident!("qobject"), None).unwrap();
+
+        let methods = parsed_rust_qt.methods;
+        assert_eq!(methods.len(), 2);
+        assert!(methods[0].is_async);
+        assert!(methods[1].is_async);
+
+        let signals = parsed_rust_qt.signals;
+        assert_eq!(signals.len(), 2);
+        assert_eq!(
+            signals[0].name,
+            Name::mock_name_with_cxx("fetch_finished", "fetchFinished")
+        );
+        assert!(signals[0].mutable);
+        assert_eq!(signals[0].parameters.len(), 1);
+        assert_eq!(signals[0].parameters[0].ident, "result");
+        assert_eq!(signals[0].cfgs, methods[0].cfgs);
+        assert_eq!(
+            signals[1].name,
+            Name::mock_name_with_cxx("do_work_finished", "doWorkFinished")
+        );
+        assert!(signals[1].mutable);
+        assert!(signals[1].parameters.is_empty());
+    }
+
+    #[test]
+    fn test_parse_block_cfg() {
+        let block: ItemForeignMod = parse_quote! {
+            #[cfg(feature = "gui")]
+            unsafe extern "RustQt" {
+                #[qobject]
+                type MyObject = super::MyObjectRust;
+
+                #[qinvokable]
+                #[cfg(enabled)]
+                fn invokable(self: &MyObject);
+
+                #[qsignal]
+                fn ready(self: Pin<&mut MyObject>);
+
+                #[inherit]
+                fn has_children(self: &MyObject) -> bool;
+            }
+        };
+        let parsed_rust_qt =
+            ParsedExternRustQt::parse(block, &format_ident!("qobject"), None).unwrap();
+
+        let cfg_block: Attribute = parse_quote! { #[cfg(feature = "gui")] };
+        let cfg_invokable: Attribute = parse_quote! { #[cfg(enabled)] };
+        assert_eq!(parsed_rust_qt.qobjects[0].cfgs, vec![cfg_block.clone()]);
+        assert_eq!(
+            parsed_rust_qt.methods[0].cfgs,
+            vec![cfg_block.clone(), cfg_invokable]
+        );
+        assert_eq!(parsed_rust_qt.signals[0].cfgs, vec![cfg_block.clone()]);
+        assert_eq!(parsed_rust_qt.inherited_methods[0].cfgs, vec![cfg_block]);
+    }
+
     #[test]
     fn test_parse_invalid() {
         assert_parse_errors!(
@@ -250,6 +346,38 @@ mod tests {
                 }
             }
 
+            // Async methods must be invokables
+            {
+                extern "RustQt" {
+                    async fn fetch(self: &MyObject) -> i32;
+                }
+            }
+
+            // Async invokables cannot have specifiers
+            {
+                extern "RustQt" {
+                    #[qinvokable]
+                    #[cxx_override]
+                    async fn fetch(self: &MyObject) -> i32;
+                }
+            }
+
+            // Async invokables must be safe
+            {
+                extern "RustQt" {
+                    #[qinvokable]
+                    async unsafe fn fetch(self: &MyObject) -> i32;
+                }
+            }
+
+            // Async invokables cannot return references
+            {
+                extern "RustQt" {
+                    #[qinvokable]
+                    async fn fetch(self: &MyObject) -> &i32;
+                }
+            }
+
             // Unsupported Item
             {
                 extern "RustQt" {
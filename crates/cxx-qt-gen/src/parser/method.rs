@@ -5,14 +5,18 @@
 use crate::parser::{extract_docs, CaseConversion};
 use crate::{
     naming::Name,
-    parser::{extract_cfgs, parameter::ParsedFunctionParameter, require_attributes},
+    parser::{
+        extract_cfgs, parameter::ParsedFunctionParameter, require_attributes, signals::ParsedSignal,
+    },
     syntax::{foreignmod, types},
 };
 use core::ops::Deref;
-use quote::format_ident;
+use quote::{format_ident, quote};
 use std::collections::{BTreeMap, HashSet};
 use std::ops::DerefMut;
-use syn::{Attribute, ForeignItemFn, Ident, Result};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Result, ReturnType, Type,
+};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -59,6 +63,8 @@ pub struct ParsedMethod {
     pub is_qinvokable: bool,
     /// Whether the method is a pure virtual method
     pub is_pure: bool,
+    /// Whether the method is an async invokable which returns a future
+    pub is_async: bool,
     /// Whether to auto generate a wrapper for this method outside the bridge
     pub wrap: bool,
     /// Cfgs for the method
@@ -132,13 +138,19 @@ impl ParsedMethod {
         let is_qinvokable = attrs.contains_key("qinvokable");
         let is_pure = attrs.contains_key("cxx_pure");
         let wrap = attrs.contains_key("auto_wrap");
+        let is_async = fields.method.sig.asyncness.is_some();
         let specifiers = ParsedQInvokableSpecifiers::from_attrs(attrs);
 
+        if is_async {
+            Self::check_async(&fields, is_qinvokable, wrap, &specifiers)?;
+        }
+
         Ok(Self {
             method_fields: fields,
             specifiers,
             is_qinvokable,
             is_pure,
+            is_async,
             wrap,
             cfgs,
             docs,
@@ -147,6 +159,64 @@ impl ParsedMethod {
     }
 }
 
+impl ParsedMethod {
+    fn check_async(
+        fields: &MethodFields,
+        is_qinvokable: bool,
+        wrap: bool,
+        specifiers: &HashSet<ParsedQInvokableSpecifiers>,
+    ) -> Result<()> {
+        let span = fields.method.sig.asyncness.span();
+        if !is_qinvokable {
+            return Err(Error::new(span, "async methods must be a #[qinvokable]"));
+        }
+        if wrap || !specifiers.is_empty() {
+            return Err(Error::new(
+                span,
+                "async invokables cannot be combined with #[auto_wrap] or C++ specifiers",
+            ));
+        }
+        if !fields.safe {
+            return Err(Error::new(span, "async invokables must be safe"));
+        }
+        if let ReturnType::Type(_, ty) = &fields.method.sig.output {
+            if matches!(ty.as_ref(), Type::Reference(_)) {
+                return Err(Error::new(
+                    ty.span(),
+                    "async invokables cannot return a reference, as the result is sent to the thread of the QObject",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The signal which is emitted with the result of an async invokable once its future completes
+    pub fn async_finished_signal(&self) -> ParsedSignal {
+        let qobject_ident = &self.qobject_ident;
+        let signal_rust = format_ident!("{}_finished", self.name.rust_unqualified());
+        let signal_cpp = format!("{}Finished", self.name.cxx_unqualified());
+        let invokable_cpp = self.name.cxx_unqualified();
+        let doc = format!(
+            "Emitted with the result of the async invokable {invokable_cpp} once it has finished"
+        );
+        let cfgs = &self.cfgs;
+        let result = match &self.method.sig.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => Some(quote! { result: #ty }),
+        };
+
+        let method: ForeignItemFn = parse_quote! {
+            #(#cfgs)*
+            #[doc = #doc]
+            #[cxx_name = #signal_cpp]
+            fn #signal_rust(self: Pin<&mut #qobject_ident>, #result);
+        };
+        // The signal is built from a valid method so parsing cannot fail
+        ParsedSignal::parse_rust_qt_signal(method, CaseConversion::none())
+            .expect("async finished signal should be a valid signal")
+    }
+}
+
 impl Deref for ParsedMethod {
     type Target = MethodFields;
 
@@ -10,9 +10,11 @@ pub mod externqobject;
 mod externrustqt;
 pub mod inherit;
 pub mod method;
+pub mod model;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
+pub mod qgadget;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signals;
@@ -96,6 +98,11 @@ pub fn extract_cfgs(attrs: &[Attribute]) -> Vec<Attribute> {
         .collect()
 }
 
+/// Prepend the cfg attributes of a parent block, such as an `extern "RustQt"` block, to an item
+pub fn prepend_cfgs(attrs: &mut Vec<Attribute>, cfgs: &[Attribute]) {
+    attrs.splice(0..0, cfgs.iter().cloned());
+}
+
 /// Iterate the attributes of the method to extract Doc attributes (doc comments are parsed as this)
 pub fn extract_docs(attrs: &[Attribute]) -> Vec<Attribute> {
     attrs
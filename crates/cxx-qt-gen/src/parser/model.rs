@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::{parse::ParseStream, Attribute, Ident, Result, Token, Type};
+
+/// Describes a QAbstractListModel which is backed by a `Vec` in the inner Rust struct
+pub struct ParsedListModel {
+    /// The [syn::Type] of the items in the list
+    pub item: Type,
+    /// The [syn::Ident] of the field in the inner Rust struct storing the items
+    pub field: Ident,
+}
+
+impl ParsedListModel {
+    /// Parse a `#[list_model(T, field)]` attribute
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let (item, field) = parse_item_and_field(attr)?;
+        Ok(Self { item, field })
+    }
+}
+
+/// Describes a QAbstractItemModel which is backed by a `cxx_qt_lib::Tree` in the inner Rust struct
+pub struct ParsedTreeModel {
+    /// The [syn::Type] of the items in the tree
+    pub item: Type,
+    /// The [syn::Ident] of the field in the inner Rust struct storing the tree
+    pub field: Ident,
+}
+
+impl ParsedTreeModel {
+    /// Parse a `#[tree_model(T, field)]` attribute
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let (item, field) = parse_item_and_field(attr)?;
+        Ok(Self { item, field })
+    }
+}
+
+fn parse_item_and_field(attr: &Attribute) -> Result<(Type, Ident)> {
+    attr.parse_args_with(|input: ParseStream| -> Result<(Type, Ident)> {
+        let item = input.parse()?;
+        let _comma = input.parse::<Token![,]>()?;
+        let field = input.parse()?;
+
+        Ok((item, field))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_list_model() {
+        let list_model = ParsedListModel::parse(&parse_quote! {
+            #[list_model(TodoItem, todos)]
+        })
+        .unwrap();
+        assert_eq!(list_model.item, parse_quote! { TodoItem });
+        assert_eq!(list_model.field, "todos");
+    }
+
+    #[test]
+    fn test_parse_list_model_invalid() {
+        assert_parse_errors! {
+            |attr: Attribute| ParsedListModel::parse(&attr) =>
+
+            { #[list_model] }
+            { #[list_model(TodoItem)] }
+            { #[list_model(TodoItem, todos, extra)] }
+        }
+    }
+
+    #[test]
+    fn test_parse_tree_model() {
+        let tree_model = ParsedTreeModel::parse(&parse_quote! {
+            #[tree_model(FileItem, files)]
+        })
+        .unwrap();
+        assert_eq!(tree_model.item, parse_quote! { FileItem });
+        assert_eq!(tree_model.field, "files");
+    }
+
+    #[test]
+    fn test_parse_tree_model_invalid() {
+        assert_parse_errors! {
+            |attr: Attribute| ParsedTreeModel::parse(&attr) =>
+
+            { #[tree_model] }
+            { #[tree_model(FileItem)] }
+        }
+    }
+}
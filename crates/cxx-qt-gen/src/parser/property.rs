@@ -10,11 +10,13 @@ use syn::{
     parse::{Error, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Expr, Ident, Meta, MetaNameValue, Result, Token, Type,
+    Attribute, Expr, GenericArgument, Ident, Meta, MetaNameValue, PathArguments, PathSegment,
+    Result, Token, Type,
 };
 
 use crate::generator::naming::property::{
-    getter_name_from_property, notify_name_from_property, setter_name_from_property,
+    getter_name_from_property, notify_name_from_property, reset_name_from_property,
+    setter_name_from_property,
 };
 #[cfg(test)]
 use syn::ItemStruct;
@@ -34,10 +36,11 @@ pub struct QPropertyFlags {
     pub(crate) read: FlagState,
     pub(crate) write: Option<FlagState>,
     pub(crate) notify: Option<FlagState>,
-    pub(crate) reset: Option<Ident>, // TODO: in future might be able to generate the function if T has a default
+    pub(crate) reset: Option<FlagState>,
     pub(crate) is_final: bool,
     pub(crate) constant: bool,
     pub(crate) required: bool,
+    pub(crate) bindable: bool,
 }
 
 impl Default for QPropertyFlags {
@@ -51,6 +54,7 @@ impl Default for QPropertyFlags {
             is_final: false,
             constant: false,
             required: false,
+            bindable: false,
         }
     }
 }
@@ -90,6 +94,31 @@ fn parse_meta_name_value(name_value: &MetaNameValue) -> Result<(Ident, Ident)> {
     Ok((ident, value))
 }
 
+/// Returns the last segment of a `QPointer<T>` or `QBox<T>` type, which may be qualified with `cxx_qt::`,
+/// otherwise returns None
+fn qobject_pointer_segment(ty: &Type) -> Option<&PathSegment> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .filter(|segment| segment.ident == "QPointer" || segment.ident == "QBox")
+}
+
+/// Returns the `T` of a `QPointer<T>` or `QBox<T>` type, otherwise returns None
+fn qobject_pointer_inner(ty: &Type) -> Option<&Type> {
+    let segment = qobject_pointer_segment(ty)?;
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first() {
+        Some(GenericArgument::Type(inner)) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
 fn parse_meta(meta: Meta) -> Result<(Ident, Option<Ident>)> {
     match meta {
         Meta::Path(path) => Ok((path.require_ident()?.clone(), None)),
@@ -135,6 +164,7 @@ impl ParsedQProperty {
                 let mut constant = false;
                 let mut required = false;
                 let mut is_final = false;
+                let mut bindable = false;
                 let mut reset = None;
                 let mut cxx_name = None;
                 let mut rust_name = None;
@@ -168,10 +198,11 @@ impl ParsedQProperty {
                                 "CONSTANT" => constant = true,
                                 "REQUIRED" => required = true,
                                 "FINAL" => is_final = true,
-                                "RESET" => reset = require_value("RESET flag", "RESET = my_reset_fn")?,
+                                "BINDABLE" => bindable = true,
+                                "RESET" => map_auto_or_custom(&mut reset, &value),
                                 _ => return Err(Error::new(
                                     ident.span(),
-                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name",
+                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL, BINDABLE or cxx_name / rust_name",
                                 ))
                             }
                         }
@@ -193,6 +224,40 @@ impl ParsedQProperty {
                     ))
                 }
 
+                // Bindable properties store their value in C++, so the getter and setter must be generated
+                if bindable {
+                    if constant {
+                        return Err(Error::new(
+                            punctuated_flags.span(),
+                            "QProperties marked as BINDABLE cannot be CONSTANT!",
+                        ));
+                    }
+
+                    if matches!(read, Some(FlagState::Custom(_))) || matches!(write, Some(FlagState::Custom(_))) {
+                        return Err(Error::new(
+                            punctuated_flags.span(),
+                            "QProperties marked as BINDABLE cannot have a custom READ or WRITE function!",
+                        ));
+                    }
+                }
+
+                // QPointer and QBox properties store their value in C++, so the getter and setter must be generated
+                if qobject_pointer_inner(&ty).is_some() {
+                    if bindable {
+                        return Err(Error::new(
+                            punctuated_flags.span(),
+                            "QProperties of a QPointer or QBox cannot be BINDABLE!",
+                        ));
+                    }
+
+                    if matches!(read, Some(FlagState::Custom(_))) || matches!(write, Some(FlagState::Custom(_))) {
+                        return Err(Error::new(
+                            punctuated_flags.span(),
+                            "QProperties of a QPointer or QBox cannot have a custom READ or WRITE function!",
+                        ));
+                    }
+                }
+
                 let name = Name::new(ident).with_options(cxx_name.map(|ident| ident.to_string()), rust_name, auto_case);
 
                 // This check is needed otherwise this fn would error unless READ, WRITE, etc... was passed with cxx_name
@@ -209,6 +274,7 @@ impl ParsedQProperty {
                                 is_final,
                                 constant,
                                 required,
+                                bindable,
                             },
                         })
                     } else {
@@ -228,6 +294,19 @@ impl ParsedQProperty {
         })
     }
 
+    /// Returns the QObject type `T` if this is a property of a `QPointer<T>` or `QBox<T>`
+    ///
+    /// The pointer of these properties is stored in C++ so that it becomes null when the object is destroyed.
+    pub fn qobject_pointer(&self) -> Option<&Type> {
+        qobject_pointer_inner(&self.ty)
+    }
+
+    /// Returns `true` if this is a property of a `QBox<T>`, where the QObject owns the object of the property
+    pub fn owns_qobject(&self) -> bool {
+        self.qobject_pointer().is_some()
+            && qobject_pointer_segment(&self.ty).is_some_and(|segment| segment.ident == "QBox")
+    }
+
     /// Generates a list of method names which will be generated for this property
     pub fn pending_methods(&self) -> Vec<Name> {
         let mut pending = vec![];
@@ -240,6 +319,10 @@ impl ParsedQProperty {
             pending.push(setter_name_from_property(&self.name));
         }
 
+        if let Some(FlagState::Auto) = &self.flags.reset {
+            pending.push(reset_name_from_property(&self.name));
+        }
+
         pending
     }
 
@@ -346,8 +429,6 @@ mod tests {
 
             // Non-constant property with constant flag
             { #[qproperty(T, name, READ, WRITE, NOTIFY, CONSTANT)] }
-            // Reset was not provided a function
-            { #[qproperty(T, name, READ, RESET)] }
             // Unknown flag
             { #[qproperty(T, name, READ = blah, a, NOTIFY = blahblah)] }
             // Invalid function specification syntax
@@ -370,6 +451,20 @@ mod tests {
             { #[qproperty(T, name, cxx_name = "")] }
             // cxx_name had no value provided
             { #[qproperty(T, name, cxx_name)] }
+            // Bindable property with constant flag
+            { #[qproperty(T, name, READ, CONSTANT, BINDABLE)] }
+            // Bindable property with custom getter
+            { #[qproperty(T, name, READ = my_getter, WRITE, BINDABLE)] }
+            // Bindable property with custom setter
+            { #[qproperty(T, name, READ, WRITE = my_setter, BINDABLE)] }
+            // QPointer property which is bindable
+            { #[qproperty(QPointer<T>, name, READ, WRITE, BINDABLE)] }
+            // QPointer property with custom getter
+            { #[qproperty(QPointer<T>, name, READ = my_getter)] }
+            // QPointer property with custom setter
+            { #[qproperty(QPointer<T>, name, READ, WRITE = my_setter)] }
+            // QBox property which is bindable
+            { #[qproperty(QBox<T>, name, READ, WRITE, BINDABLE)] }
         }
     }
 
@@ -383,6 +478,54 @@ mod tests {
         assert!(property.flags.constant);
     }
 
+    #[test]
+    fn test_parse_bindable() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, WRITE, NOTIFY, BINDABLE)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert!(property.flags.bindable);
+        assert_eq!(property.flags.read, FlagState::Auto);
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
+        assert_eq!(property.flags.notify, Some(FlagState::Auto));
+    }
+
+    #[test]
+    fn test_parse_qobject_pointer() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(QPointer<T>, name)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.qobject_pointer(), Some(&parse_quote! { T }));
+        assert!(!property.owns_qobject());
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(cxx_qt::QPointer<T>, name, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.qobject_pointer(), Some(&parse_quote! { T }));
+        assert!(!property.owns_qobject());
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(cxx_qt::QBox<T>, name, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.qobject_pointer(), Some(&parse_quote! { T }));
+        assert!(property.owns_qobject());
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(*mut T, name)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.qobject_pointer(), None);
+        assert!(!property.owns_qobject());
+    }
+
     #[test]
     fn test_parse_property() {
         let input: ItemStruct = parse_quote! {
@@ -424,11 +567,28 @@ mod tests {
         assert!(property.flags.required);
         assert!(property.flags.is_final);
 
-        assert_eq!(property.flags.reset, Some(format_ident!("my_reset")));
+        assert_eq!(
+            property.flags.reset,
+            Some(FlagState::Custom(format_ident!("my_reset")))
+        );
         assert_eq!(property.flags.notify, Some(FlagState::Auto));
         assert_eq!(property.flags.write, Some(FlagState::Auto));
     }
 
+    #[test]
+    fn test_parse_flags_reset_auto() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, WRITE, NOTIFY, RESET)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.reset, Some(FlagState::Auto));
+
+        let pending = property.pending_methods();
+        assert_eq!(pending.len(), 3);
+        assert_eq!(pending[2].rust_unqualified(), "reset_name");
+    }
+
     #[test]
     fn test_parse_flags_kw() {
         let input: ItemStruct = parse_quote! {
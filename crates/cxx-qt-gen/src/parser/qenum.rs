@@ -5,14 +5,32 @@
 
 use crate::parser::{extract_cfgs, extract_docs, CaseConversion};
 use crate::{naming::Name, parser::require_attributes, syntax::path::path_compare_str};
-use quote::ToTokens;
-use syn::{Attribute, Ident, ItemEnum, Result, Variant};
+use quote::{format_ident, ToTokens};
+use std::collections::BTreeMap;
+use syn::{
+    spanned::Spanned, Attribute, Expr, ExprLit, ExprUnary, Ident, ItemEnum, Lit, Result, UnOp,
+    Variant,
+};
+
+/// A single variant of a QEnum, with its resolved value
+pub struct ParsedQEnumVariant {
+    /// The name of the variant
+    pub ident: Ident,
+    /// The value of the variant, either explicit or implied from the previous variant
+    pub value: i128,
+    /// Docs from the variant
+    pub docs: Vec<Attribute>,
+}
 
 pub struct ParsedQEnum {
     /// The name of the QObject
     pub name: Name,
     /// the values of the QEnum
-    pub variants: Vec<Ident>,
+    pub variants: Vec<ParsedQEnumVariant>,
+    /// The integer type used to store the QEnum, defaults to i32
+    pub repr: Ident,
+    /// The name of the QFlags type, if the QEnum was marked with #[qflags(...)]
+    pub flags: Option<Name>,
     /// The QObject to which this QEnum belongs.
     pub qobject: Option<Ident>,
     /// The original enum item
@@ -24,10 +42,101 @@ pub struct ParsedQEnum {
 }
 
 impl ParsedQEnum {
-    const ALLOWED_ATTRS: [&'static str; 6] =
-        ["cfg", "doc", "cxx_name", "rust_name", "namespace", "qenum"];
-    fn parse_variant(variant: &Variant) -> Result<Ident> {
-        fn err(spanned: &impl ToTokens, message: &str) -> Result<Ident> {
+    const ALLOWED_ATTRS: [&'static str; 8] = [
+        "cfg",
+        "doc",
+        "cxx_name",
+        "rust_name",
+        "namespace",
+        "qenum",
+        "qflags",
+        "repr",
+    ];
+
+    /// The integer types which can be used as the repr of a QEnum
+    const ALLOWED_REPRS: [&'static str; 8] = ["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64"];
+
+    fn parse_repr(attrs: &[Attribute]) -> Result<Ident> {
+        let Some(attr) = attrs
+            .iter()
+            .find(|attr| path_compare_str(attr.path(), &["repr"]))
+        else {
+            return Ok(format_ident!("i32"));
+        };
+
+        let repr: Ident = attr.parse_args()?;
+        if !Self::ALLOWED_REPRS.contains(&repr.to_string().as_str()) {
+            return Err(syn::Error::new_spanned(
+                &repr,
+                format!(
+                    "QEnum repr must be one of: {}",
+                    Self::ALLOWED_REPRS.join(", ")
+                ),
+            ));
+        }
+        Ok(repr)
+    }
+
+    fn parse_flags(attrs: &[Attribute], name: &Name) -> Result<Option<Name>> {
+        let Some(attr) = attrs
+            .iter()
+            .find(|attr| path_compare_str(attr.path(), &["qflags"]))
+        else {
+            return Ok(None);
+        };
+
+        let ident: Ident = attr.parse_args()?;
+        if &ident == name.rust_unqualified() || ident == name.cxx_unqualified() {
+            return Err(syn::Error::new_spanned(
+                &ident,
+                "The QFlags type must have a different name to the QEnum!",
+            ));
+        }
+
+        // The flags type lives next to the enum, so it shares the namespace and module
+        let mut flags = Name::new(ident);
+        if let Some(module) = name.module() {
+            flags = flags.with_module(module.clone());
+        }
+        if let Some(namespace) = name.namespace() {
+            flags = flags.with_namespace(namespace.to_owned());
+        }
+        Ok(Some(flags))
+    }
+
+    /// The range of values which can be stored in the given repr
+    fn repr_range(repr: &Ident) -> (i128, i128) {
+        match repr.to_string().as_str() {
+            "i8" => (i8::MIN.into(), i8::MAX.into()),
+            "u8" => (u8::MIN.into(), u8::MAX.into()),
+            "i16" => (i16::MIN.into(), i16::MAX.into()),
+            "u16" => (u16::MIN.into(), u16::MAX.into()),
+            "u32" => (u32::MIN.into(), u32::MAX.into()),
+            "i64" => (i64::MIN.into(), i64::MAX.into()),
+            "u64" => (u64::MIN.into(), u64::MAX.into()),
+            _ => (i32::MIN.into(), i32::MAX.into()),
+        }
+    }
+
+    fn parse_discriminant(expr: &Expr) -> Result<i128> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit.base10_parse(),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => Self::parse_discriminant(expr).map(|value| -value),
+            _ => Err(syn::Error::new_spanned(
+                expr,
+                "QEnum variants can only have integer literals as explicit values",
+            )),
+        }
+    }
+
+    fn parse_variant(variant: &Variant, next_value: i128) -> Result<ParsedQEnumVariant> {
+        fn err(spanned: &impl ToTokens, message: &str) -> Result<ParsedQEnumVariant> {
             Err(syn::Error::new_spanned(spanned, message))
         }
 
@@ -44,14 +153,54 @@ impl ParsedQEnum {
                 "QEnum variants can only have #[doc=\"...\"] attributes",
             );
         }
-        if let Some(discriminant) = variant.discriminant.as_ref() {
-            return err(
-                &discriminant.1,
-                "QEnum variants with explicit values are not supported (yet)",
-            );
+
+        let value = if let Some((_, discriminant)) = variant.discriminant.as_ref() {
+            Self::parse_discriminant(discriminant)?
+        } else {
+            next_value
+        };
+
+        Ok(ParsedQEnumVariant {
+            ident: variant.ident.clone(),
+            value,
+            docs: extract_docs(&variant.attrs),
+        })
+    }
+
+    fn parse_variants(qenum: &ItemEnum, repr: &Ident) -> Result<Vec<ParsedQEnumVariant>> {
+        let (min, max) = Self::repr_range(repr);
+        let mut seen = BTreeMap::new();
+        let mut next_value = 0;
+        let mut variants = Vec::with_capacity(qenum.variants.len());
+
+        for variant in &qenum.variants {
+            let parsed = Self::parse_variant(variant, next_value)?;
+
+            if parsed.value < min || parsed.value > max {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    format!(
+                        "QEnum variant value {} is out of range for repr {repr}",
+                        parsed.value
+                    ),
+                ));
+            }
+
+            if let Some(existing) = seen.insert(parsed.value, parsed.ident.clone()) {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    format!(
+                        "QEnum variant {} has the same value as {existing}",
+                        parsed.ident
+                    ),
+                ));
+            }
+
+            next_value = parsed.value + 1;
+            variants.push(parsed);
         }
 
-        Ok(variant.ident.clone())
+        Ok(variants)
     }
 
     pub fn parse(
@@ -86,16 +235,16 @@ impl ParsedQEnum {
             ));
         }
 
-        let variants = qenum
-            .variants
-            .iter()
-            .map(Self::parse_variant)
-            .collect::<Result<_>>()?;
+        let repr = Self::parse_repr(&qenum.attrs)?;
+        let variants = Self::parse_variants(&qenum, &repr)?;
+        let flags = Self::parse_flags(&qenum.attrs, &name)?;
 
         Ok(Self {
             name,
             qobject,
             variants,
+            repr,
+            flags,
             docs,
             cfgs,
             item: qenum,
@@ -115,7 +264,7 @@ mod tests {
         qenum
             .variants
             .iter()
-            .map(Ident::to_string)
+            .map(|variant| variant.ident.to_string())
             .collect::<Vec<_>>()
     }
 
@@ -143,6 +292,8 @@ mod tests {
         assert_eq!(parsed.qobject, qobject);
 
         assert_eq!(*variants_to_strings(&parsed), ["Variant1", "Variant2"],);
+        assert_eq!(parsed.docs.len(), 1);
+        assert_eq!(parsed.variants[0].docs.len(), 1);
         assert_tokens_eq(&parsed.item, qenum.to_token_stream());
     }
 
@@ -165,6 +316,94 @@ mod tests {
         assert_tokens_eq(&parsed.item, qenum.to_token_stream());
     }
 
+    #[test]
+    fn parse_discriminants_and_repr() {
+        let qenum: ItemEnum = parse_quote! {
+            #[repr(u8)]
+            enum MyEnum {
+                A = 0x10,
+                B = 0x20,
+                C,
+                D = 2,
+            }
+        };
+
+        let parsed =
+            ParsedQEnum::parse(qenum, Some(format_ident!("MyObject")), None, &mock_module())
+                .unwrap();
+        assert_eq!(parsed.repr, "u8");
+        assert_eq!(*variants_to_strings(&parsed), ["A", "B", "C", "D"]);
+        assert_eq!(
+            parsed
+                .variants
+                .iter()
+                .map(|variant| variant.value)
+                .collect::<Vec<_>>(),
+            [0x10, 0x20, 0x21, 2]
+        );
+    }
+
+    #[test]
+    fn parse_negative_discriminants() {
+        let qenum: ItemEnum = parse_quote! {
+            #[namespace = "my_namespace"]
+            #[repr(i64)]
+            enum MyEnum {
+                A = -2,
+                B,
+                C,
+            }
+        };
+
+        let parsed = ParsedQEnum::parse(qenum, None, None, &mock_module()).unwrap();
+        assert_eq!(parsed.repr, "i64");
+        assert_eq!(
+            parsed
+                .variants
+                .iter()
+                .map(|variant| variant.value)
+                .collect::<Vec<_>>(),
+            [-2, -1, 0]
+        );
+    }
+
+    #[test]
+    fn parse_default_repr() {
+        let qenum: ItemEnum = parse_quote! {
+            enum MyEnum {
+                A,
+                B,
+            }
+        };
+
+        let parsed =
+            ParsedQEnum::parse(qenum, Some(format_ident!("MyObject")), None, &mock_module())
+                .unwrap();
+        assert_eq!(parsed.repr, "i32");
+        assert_eq!(parsed.variants[1].value, 1);
+    }
+
+    #[test]
+    fn parse_flags() {
+        let qenum: ItemEnum = parse_quote! {
+            #[namespace = "my_namespace"]
+            #[qflags(MyFlags)]
+            #[repr(u32)]
+            enum MyFlag {
+                A = 0x1,
+                B = 0x2,
+            }
+        };
+
+        let parsed =
+            ParsedQEnum::parse(qenum, Some(format_ident!("MyObject")), None, &mock_module())
+                .unwrap();
+        let flags = parsed.flags.unwrap();
+        assert_eq!(flags.rust_unqualified(), "MyFlags");
+        assert_eq!(flags.cxx_unqualified(), "MyFlags");
+        assert_eq!(flags.namespace(), Some("my_namespace"));
+    }
+
     use crate::tests::assert_parse_errors;
 
     #[test]
@@ -179,8 +418,13 @@ mod tests {
                 enum MyEnum { A }
             }
             {
-                // Repr is not allowed either
-                #[repr(u32)]
+                // Only integer reprs are allowed
+                #[repr(C)]
+                enum MyEnum { A }
+            }
+            {
+                // Repr must be an integer type
+                #[repr(f32)]
                 enum MyEnum { A }
             }
             {
@@ -203,9 +447,56 @@ mod tests {
                 }
             }
             {
-                // TODO: Allow discriminants
+                // QFlags need a name
+                #[qflags]
+                enum MyEnum { A }
+            }
+            {
+                // QFlags cannot have the same name as the enum
+                #[qflags(MyEnum)]
+                enum MyEnum { A }
+            }
+            {
+                // Discriminants must be integer literals
+                enum MyEnum {
+                    A = 1 + 1
+                }
+            }
+            {
+                // Explicit duplicate values
+                enum MyEnum {
+                    A = 1,
+                    B = 1,
+                }
+            }
+            {
+                // Implicit duplicate values
+                enum MyEnum {
+                    A = 1,
+                    B = 0,
+                    C,
+                }
+            }
+            {
+                // Value out of range for the repr
+                #[repr(u8)]
+                enum MyEnum {
+                    A = 256
+                }
+            }
+            {
+                // Implicit value out of range for the repr
+                #[repr(i8)]
+                enum MyEnum {
+                    A = 127,
+                    B,
+                }
+            }
+            {
+                // Negative values are not allowed for unsigned reprs
+                #[repr(u16)]
                 enum MyEnum {
-                    A = 1
+                    A = -1
                 }
             }
         }
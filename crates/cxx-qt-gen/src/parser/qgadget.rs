@@ -0,0 +1,263 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    naming::Name,
+    parser::{
+        extract_cfgs, extract_docs,
+        property::{FlagState, ParsedQProperty},
+        require_attributes, CaseConversion,
+    },
+    syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
+};
+use syn::{punctuated::Punctuated, Attribute, Error, Ident, Meta, Path, Result, Token};
+
+/// A representation of a Q_GADGET value type within a CXX-Qt [syn::ItemMod]
+pub struct ParsedQGadget {
+    /// The name of the Q_GADGET
+    pub name: Name,
+    /// The ident of the inner type of the Q_GADGET
+    pub rust_type: Ident,
+    /// List of properties that need to be implemented on the C++ type
+    ///
+    /// These will be exposed as Q_PROPERTY on the C++ type
+    pub properties: Vec<ParsedQProperty>,
+    /// The name to register the value type as in QML, if any
+    pub qml_name: Option<String>,
+    /// Whether `#[derive(PartialEq)]` was specified, which compares the inner Rust types
+    pub partial_eq: bool,
+    /// Cfgs for the gadget
+    pub cfgs: Vec<Attribute>,
+    /// Docs for the gadget
+    pub docs: Vec<Attribute>,
+}
+
+impl ParsedQGadget {
+    const ALLOWED_ATTRS: [&'static str; 9] = [
+        "cxx_name",
+        "rust_name",
+        "namespace",
+        "cfg",
+        "derive",
+        "doc",
+        "qgadget",
+        "qml_element",
+        "qproperty",
+    ];
+
+    /// Parse a ForeignTypeIdentAlias with a #[qgadget] attribute into a [ParsedQGadget]
+    pub fn parse(
+        declaration: ForeignTypeIdentAlias,
+        namespace: Option<&str>,
+        module: &Ident,
+        auto_case: CaseConversion,
+    ) -> Result<Self> {
+        let attributes = require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
+
+        let name = Name::from_ident_and_attrs(
+            &declaration.ident_left,
+            &declaration.attrs,
+            namespace,
+            Some(module),
+            CaseConversion::none(),
+        )?;
+
+        // QML requires value types to have a name starting with a lower case letter
+        let qml_name = attributes
+            .get("qml_element")
+            .map(|attr| match &attr.meta {
+                Meta::NameValue(name_value) => expr_to_string(&name_value.value),
+                _ => Ok(lowercase_first(&name.cxx_unqualified())),
+            })
+            .transpose()?;
+        let partial_eq = Self::parse_derive(&declaration.attrs)?;
+
+        let properties = declaration
+            .attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
+            .map(|attr| {
+                let property = ParsedQProperty::parse(attr, auto_case)?;
+                Self::check_property(attr, &property)?;
+                Ok(property)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name,
+            rust_type: declaration.ident_right.clone(),
+            properties,
+            qml_name,
+            partial_eq,
+            cfgs: extract_cfgs(&declaration.attrs),
+            docs: extract_docs(&declaration.attrs),
+        })
+    }
+
+    /// Only PartialEq can be derived for a Q_GADGET, returns whether it was derived
+    fn parse_derive(attrs: &[Attribute]) -> Result<bool> {
+        let mut partial_eq = false;
+        for attr in attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["derive"]))
+        {
+            let paths = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+            for path in paths {
+                if !path_compare_str(&path, &["PartialEq"]) {
+                    return Err(Error::new_spanned(
+                        path,
+                        "Only PartialEq can be derived for a #[qgadget]!",
+                    ));
+                }
+                partial_eq = true;
+            }
+        }
+        Ok(partial_eq)
+    }
+
+    /// A Q_GADGET has no signals or custom methods, so only automatic READ and WRITE are supported
+    fn check_property(attr: &Attribute, property: &ParsedQProperty) -> Result<()> {
+        let flags = &property.flags;
+        if flags.read != FlagState::Auto
+            || flags
+                .write
+                .as_ref()
+                .is_some_and(|write| *write != FlagState::Auto)
+            || flags
+                .notify
+                .as_ref()
+                .is_some_and(|notify| *notify != FlagState::Auto)
+            || flags.reset.is_some()
+            || flags.bindable
+            || flags.required
+        {
+            return Err(Error::new_spanned(
+                attr,
+                "Properties of a #[qgadget] only support the READ, WRITE, CONSTANT and FINAL flags without custom functions!",
+            ));
+        }
+
+        // The QPointer of a property is stored in the QObject, which a Q_GADGET does not have
+        if property.qobject_pointer().is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "Properties of a #[qgadget] cannot be a QPointer or QBox!",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    fn parse(declaration: ForeignTypeIdentAlias) -> Result<ParsedQGadget> {
+        ParsedQGadget::parse(
+            declaration,
+            None,
+            &format_ident!("qobject"),
+            CaseConversion::none(),
+        )
+    }
+
+    #[test]
+    fn test_parse_qgadget() {
+        let gadget = parse(parse_quote! {
+            #[qgadget]
+            #[qproperty(i32, x)]
+            #[qproperty(i32, y, READ)]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+        assert_eq!(gadget.name.rust_unqualified(), "Point");
+        assert_eq!(gadget.rust_type, "PointRust");
+        assert_eq!(gadget.properties.len(), 2);
+        assert!(gadget.properties[0].flags.write.is_some());
+        assert!(gadget.properties[1].flags.write.is_none());
+        assert!(gadget.qml_name.is_none());
+        assert!(!gadget.partial_eq);
+    }
+
+    #[test]
+    fn test_parse_qgadget_derive() {
+        let gadget = parse(parse_quote! {
+            #[qgadget]
+            #[derive(PartialEq)]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+        assert!(gadget.partial_eq);
+    }
+
+    #[test]
+    fn test_parse_qgadget_qml_name() {
+        let gadget = parse(parse_quote! {
+            #[qgadget]
+            #[qml_element]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+        assert_eq!(gadget.qml_name.as_deref(), Some("point"));
+
+        let gadget = parse(parse_quote! {
+            #[qgadget]
+            #[qml_element = "coordinate"]
+            type Point = super::PointRust;
+        })
+        .unwrap();
+        assert_eq!(gadget.qml_name.as_deref(), Some("coordinate"));
+    }
+
+    #[test]
+    fn test_parse_qgadget_invalid() {
+        assert_parse_errors! {
+            parse =>
+
+            {
+                #[qgadget]
+                #[base = QObject]
+                type Point = super::PointRust;
+            }
+            {
+                #[qgadget]
+                #[qproperty(i32, x, READ, WRITE = set_x_custom)]
+                type Point = super::PointRust;
+            }
+            {
+                #[qgadget]
+                #[qproperty(i32, x, READ, RESET = reset_x)]
+                type Point = super::PointRust;
+            }
+            {
+                #[qgadget]
+                #[qproperty(QPointer<MyObject>, x)]
+                type Point = super::PointRust;
+            }
+            {
+                #[qgadget]
+                #[qml_element = 1]
+                type Point = super::PointRust;
+            }
+            {
+                #[qgadget]
+                #[derive(PartialEq, Debug)]
+                type Point = super::PointRust;
+            }
+        }
+    }
+}
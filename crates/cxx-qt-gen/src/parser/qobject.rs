@@ -5,14 +5,20 @@
 
 use crate::{
     naming::Name,
-    parser::{extract_cfgs, property::ParsedQProperty, require_attributes},
+    parser::{
+        extract_cfgs, extract_docs,
+        model::{ParsedListModel, ParsedTreeModel},
+        property::ParsedQProperty,
+        require_attributes,
+    },
     syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
 };
 #[cfg(test)]
 use quote::format_ident;
 
 use crate::parser::{parse_base_type, CaseConversion};
-use syn::{Attribute, Error, Ident, Meta, Result};
+use std::collections::BTreeMap;
+use syn::{Attribute, Error, Expr, Ident, Meta, Result};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -20,7 +26,67 @@ pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
     pub singleton: bool,
+    /// The QObject type to use for QML attached properties of this element
+    pub attached: Option<Ident>,
+    /// The QObject type which extends this element with further properties and methods in QML
+    pub extended: Option<Ident>,
 }
+
+impl QmlElementMetadata {
+    /// Parse the QML element attributes of a type, the name defaults to the C++ name of the type
+    pub fn parse(
+        name: &Name,
+        attributes: &BTreeMap<&str, &Attribute>,
+    ) -> Result<Option<QmlElementMetadata>> {
+        if let Some(attr) = attributes.get("qml_element") {
+            // Extract the name of the qml_element from macro, else use the c++ name
+            // This will use the name provided by cxx_name if that attr was present
+            let name = match &attr.meta {
+                Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
+                _ => name.cxx_unqualified(),
+            };
+            let uncreatable = attributes.contains_key("qml_uncreatable");
+            let singleton = attributes.contains_key("qml_singleton");
+            let attached = attributes
+                .get("qml_attached")
+                .map(|attr| parse_type_ident(attr))
+                .transpose()?;
+            let extended = attributes
+                .get("qml_extended")
+                .map(|attr| parse_type_ident(attr))
+                .transpose()?;
+            return Ok(Some(QmlElementMetadata {
+                name,
+                uncreatable,
+                singleton,
+                attached,
+                extended,
+            }));
+        }
+
+        if let Some(attr) = attributes.get("qml_attached") {
+            return Err(Error::new_spanned(
+                attr,
+                "#[qml_attached] can only be used on a type with #[qml_element]",
+            ));
+        }
+        Ok(None)
+    }
+}
+
+/// Parse the type from an attribute such as `#[qml_attached = MyAttached]`
+fn parse_type_ident(attr: &Attribute) -> Result<Ident> {
+    let expr = &attr.meta.require_name_value()?.value;
+    if let Expr::Path(path_expr) = expr {
+        Ok(path_expr.path.require_ident()?.clone())
+    } else {
+        Err(Error::new_spanned(
+            expr,
+            "Expected an identifier of a type, such as #[qml_attached = MyAttached]",
+        ))
+    }
+}
+
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
 ///
 /// This has initial splitting of [syn::Item]'s into relevant blocks, other phases will
@@ -36,6 +102,10 @@ pub struct ParsedQObject {
     ///
     /// These will be exposed as Q_PROPERTY on the C++ object
     pub properties: Vec<ParsedQProperty>,
+    /// The list model which is implemented by this QObject, if any
+    pub list_model: Option<ParsedListModel>,
+    /// The tree model which is implemented by this QObject, if any
+    pub tree_model: Option<ParsedTreeModel>,
     /// List of specifiers to register with in QML
     pub qml_metadata: Option<QmlElementMetadata>,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
@@ -44,10 +114,12 @@ pub struct ParsedQObject {
     pub declaration: ForeignTypeIdentAlias,
     /// Cfgs for the object
     pub cfgs: Vec<Attribute>,
+    /// Docs for the object
+    pub docs: Vec<Attribute>,
 }
 
 impl ParsedQObject {
-    const ALLOWED_ATTRS: [&'static str; 11] = [
+    const ALLOWED_ATTRS: [&'static str; 15] = [
         "cxx_name",
         "rust_name",
         "namespace",
@@ -58,7 +130,11 @@ impl ParsedQObject {
         "qml_element",
         "qml_uncreatable",
         "qml_singleton",
+        "qml_attached",
+        "qml_extended",
         "qproperty",
+        "list_model",
+        "tree_model",
     ];
     #[cfg(test)]
     pub fn mock() -> Self {
@@ -67,6 +143,8 @@ impl ParsedQObject {
             name: Name::new(format_ident!("MyObject")),
             rust_type: format_ident!("MyObjectRust"),
             properties: vec![],
+            list_model: None,
+            tree_model: None,
             qml_metadata: None,
             has_qobject_macro: false,
             declaration: ForeignTypeIdentAlias {
@@ -75,6 +153,7 @@ impl ParsedQObject {
                 ident_right: format_ident!("MyObjectRust"),
             },
             cfgs: vec![],
+            docs: vec![],
         }
     }
 
@@ -86,8 +165,8 @@ impl ParsedQObject {
         auto_case: CaseConversion,
     ) -> Result<Self> {
         let attributes = require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
-        // TODO: handle docs through to generation
         let cfgs = extract_cfgs(&declaration.attrs);
+        let docs = extract_docs(&declaration.attrs);
 
         let has_qobject_macro = attributes.contains_key("qobject");
 
@@ -109,8 +188,37 @@ impl ParsedQObject {
             CaseConversion::none(),
         )?;
 
+        let list_model = attributes
+            .get("list_model")
+            .map(|attr| ParsedListModel::parse(attr))
+            .transpose()?;
+        let tree_model = attributes
+            .get("tree_model")
+            .map(|attr| ParsedTreeModel::parse(attr))
+            .transpose()?;
+
+        // The generated model methods override those of the base class
+        if list_model.is_some() && base_class.is_none() {
+            return Err(Error::new_spanned(
+                declaration.ident_left,
+                "A #[list_model] must specify a #[base] attribute, such as #[base = QAbstractListModel]!",
+            ));
+        }
+        if tree_model.is_some() && base_class.is_none() {
+            return Err(Error::new_spanned(
+                declaration.ident_left,
+                "A #[tree_model] must specify a #[base] attribute, such as #[base = QAbstractItemModel]!",
+            ));
+        }
+        if list_model.is_some() && tree_model.is_some() {
+            return Err(Error::new_spanned(
+                declaration.ident_left,
+                "A QObject cannot have both a #[list_model] and a #[tree_model] attribute!",
+            ));
+        }
+
         // Find any QML metadata
-        let qml_metadata = Self::parse_qml_metadata(&name, &declaration.attrs)?;
+        let qml_metadata = QmlElementMetadata::parse(&name, &attributes)?;
 
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
@@ -123,32 +231,15 @@ impl ParsedQObject {
             name,
             rust_type: inner,
             properties,
+            list_model,
+            tree_model,
             qml_metadata,
             has_qobject_macro,
             cfgs,
+            docs,
         })
     }
 
-    fn parse_qml_metadata(name: &Name, attrs: &[Attribute]) -> Result<Option<QmlElementMetadata>> {
-        let attributes = require_attributes(attrs, &Self::ALLOWED_ATTRS)?;
-        if let Some(attr) = attributes.get("qml_element") {
-            // Extract the name of the qml_element from macro, else use the c++ name
-            // This will use the name provided by cxx_name if that attr was present
-            let name = match &attr.meta {
-                Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
-                _ => name.cxx_unqualified(),
-            };
-            let uncreatable = attributes.contains_key("qml_uncreatable");
-            let singleton = attributes.contains_key("qml_singleton");
-            return Ok(Some(QmlElementMetadata {
-                name,
-                uncreatable,
-                singleton,
-            }));
-        }
-        Ok(None)
-    }
-
     fn parse_property_attributes(
         attrs: &[Attribute],
         auto_case: CaseConversion,
@@ -233,6 +324,18 @@ pub mod tests {
         assert_eq!(qobject.properties.len(), 2);
     }
 
+    #[test]
+    fn test_from_struct_docs() {
+        let qobject = parse_qobject! {
+            /// My QObject
+            #[qobject]
+            type MyObject = super::MyObjectRust;
+        };
+
+        assert_eq!(qobject.docs.len(), 1);
+        assert!(create_parsed_qobject().docs.is_empty());
+    }
+
     #[test]
     fn test_from_struct_fields() {
         let qobject = create_parsed_qobject();
@@ -265,6 +368,8 @@ pub mod tests {
                 name: str_name.to_owned(),
                 uncreatable: false,
                 singleton: false,
+                attached: None,
+                extended: None,
             })
         );
     }
@@ -321,6 +426,8 @@ pub mod tests {
                 name: "MyObject".to_owned(),
                 uncreatable: false,
                 singleton: true,
+                attached: None,
+                extended: None,
             })
         );
     }
@@ -339,6 +446,28 @@ pub mod tests {
                 name: "MyObject".to_owned(),
                 uncreatable: true,
                 singleton: false,
+                attached: None,
+                extended: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_attached() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached = MyAttached]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_owned(),
+                uncreatable: false,
+                singleton: false,
+                attached: Some(format_ident!("MyAttached")),
+                extended: None,
             })
         );
     }
@@ -354,6 +483,66 @@ pub mod tests {
                 type MyObject = super::T;
             }
             { type MyObject = super::T; }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_attached = "MyAttached"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_attached]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_attached = MyAttached]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[list_model(TodoItem, todos)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[tree_model(FileItem, files)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[base = QAbstractItemModel]
+                #[list_model(TodoItem, todos)]
+                #[tree_model(FileItem, files)]
+                type MyObject = super::T;
+            }
         }
     }
+
+    #[test]
+    fn test_parse_list_model() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[base = QAbstractListModel]
+            #[list_model(TodoItem, todos)]
+            type MyObject = super::MyObjectRust;
+        };
+        let list_model = qobject.list_model.unwrap();
+        assert_eq!(list_model.field, "todos");
+        assert!(create_parsed_qobject().list_model.is_none());
+    }
+
+    #[test]
+    fn test_parse_tree_model() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[base = QAbstractItemModel]
+            #[tree_model(FileItem, files)]
+            type MyObject = super::MyObjectRust;
+        };
+        let tree_model = qobject.tree_model.unwrap();
+        assert_eq!(tree_model.field, "files");
+        assert!(create_parsed_qobject().tree_model.is_none());
+    }
 }
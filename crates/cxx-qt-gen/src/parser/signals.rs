@@ -10,7 +10,7 @@ use crate::{
 use core::ops::Deref;
 use std::ops::DerefMut;
 use syn::spanned::Spanned;
-use syn::{Attribute, Error, ForeignItemFn, Result, Visibility};
+use syn::{Attribute, Error, ForeignItemFn, Result, Type, Visibility};
 
 #[derive(Clone)]
 /// Describes an individual Signal
@@ -19,6 +19,8 @@ pub struct ParsedSignal {
     pub method_fields: MethodFields,
     /// If the signal is defined in the base class
     pub inherit: bool,
+    /// Whether `<signal>_stream` and `<signal>_once` are generated to await the signal
+    pub stream: bool,
     /// Whether the signal is private
     pub private: bool,
     /// All the doc attributes (each line) of the signal
@@ -33,8 +35,15 @@ pub enum ImmutabilityConstraint {
 }
 
 impl ParsedSignal {
-    const ALLOWED_ATTRS: [&'static str; 6] =
-        ["cfg", "cxx_name", "rust_name", "inherit", "doc", "qsignal"];
+    const ALLOWED_ATTRS: [&'static str; 7] = [
+        "cfg",
+        "cxx_name",
+        "rust_name",
+        "inherit",
+        "doc",
+        "qsignal",
+        "stream",
+    ];
 
     #[cfg(test)]
     /// Test fn for creating a mocked signal from a method body
@@ -61,6 +70,26 @@ impl ParsedSignal {
 
         let inherit = attrs.contains_key("inherit");
 
+        let stream = attrs.contains_key("stream");
+        if stream {
+            if !fields.safe {
+                return Err(Error::new(
+                    fields.method.span(),
+                    "#[stream] cannot be used on unsafe signals",
+                ));
+            }
+            if let Some(parameter) = fields
+                .parameters
+                .iter()
+                .find(|parameter| !is_streamable(&parameter.ty))
+            {
+                return Err(Error::new(
+                    parameter.ty.span(),
+                    "#[stream] cannot be used on signals with pointer, UniquePtr, SharedPtr or WeakPtr parameters, as the arguments are sent to the stream",
+                ));
+            }
+        }
+
         let private = if let Visibility::Restricted(vis_restricted) = &fields.method.vis {
             path_compare_str(&vis_restricted.path, &["self"])
         } else {
@@ -70,6 +99,7 @@ impl ParsedSignal {
         Ok(Self {
             method_fields: fields,
             inherit,
+            stream,
             private,
             docs,
             cfgs,
@@ -81,6 +111,23 @@ impl ParsedSignal {
     }
 }
 
+/// Whether the argument of a signal can be owned by a stream
+///
+/// Pointers and smart pointers cannot be sent to the stream, references are cloned.
+fn is_streamable(ty: &Type) -> bool {
+    match ty {
+        Type::Ptr(_) => false,
+        Type::Reference(reference) => is_streamable(&reference.elem),
+        Type::Path(path) => path.path.segments.last().is_none_or(|segment| {
+            !matches!(
+                segment.ident.to_string().as_str(),
+                "UniquePtr" | "SharedPtr" | "WeakPtr"
+            )
+        }),
+        _ => true,
+    }
+}
+
 impl Deref for ParsedSignal {
     type Target = MethodFields;
 
@@ -119,6 +166,20 @@ mod tests {
             { fn ready(x: f64); }
             // Immutable signals must be in "C++Qt" blocks
             { fn ready(&self); }
+            // Unsafe signals cannot be streamed
+            {
+                #[stream]
+                unsafe fn ready(self: Pin<&mut MyObject>);
+            }
+            // Pointers cannot be sent to a stream
+            {
+                #[stream]
+                fn ready(self: Pin<&mut MyObject>, ptr: *mut QObject);
+            }
+            {
+                #[stream]
+                fn ready(self: Pin<&mut MyObject>, value: &UniquePtr<QColor>);
+            }
         };
     }
 
@@ -136,9 +197,21 @@ mod tests {
         assert_eq!(signal.name, Name::new(format_ident!("ready")));
         assert!(signal.safe);
         assert!(!signal.inherit);
+        assert!(!signal.stream);
         assert!(!signal.private);
     }
 
+    #[test]
+    fn test_parse_signal_stream() {
+        let method: ForeignItemFn = parse_quote! {
+            #[stream]
+            fn data_changed(self: Pin<&mut MyObject>, value: &QString, count: i32);
+        };
+        let signal = ParsedSignal::parse_rust_qt_signal(method, CaseConversion::none()).unwrap();
+        assert!(signal.stream);
+        assert_eq!(signal.parameters.len(), 2);
+    }
+
     #[test]
     fn test_parse_signal_cxx_name() {
         let method: ForeignItemFn = parse_quote! {
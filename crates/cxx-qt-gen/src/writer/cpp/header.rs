@@ -6,7 +6,7 @@
 use std::collections::BTreeSet;
 
 use crate::generator::cpp::GeneratedCppBlocks;
-use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_header};
+use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_header, qgadgets_fragments};
 use indoc::formatdoc;
 
 /// With a given block name, join the given items and add them under the block
@@ -54,6 +54,18 @@ fn forward_declare(generated: &GeneratedCppBlocks) -> Vec<String> {
                 {forward_declares_namespaced}
             "#}
         })
+        .chain(generated.qgadgets.iter().map(|qgadget| {
+            namespaced(
+                qgadget.name.namespace().unwrap_or_default(),
+                &format!("class {};\n", qgadget.name.cxx_unqualified()),
+            )
+        }))
+        .chain(
+            generated
+                .qnamespaces
+                .iter()
+                .map(|qnamespace| qnamespace.declaration.clone()),
+        )
         .chain(generated.forward_declares.iter().cloned())
         .chain(
             generated
@@ -81,7 +93,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
         let class_definition = namespaced(
             qobject.name.namespace().unwrap_or_default(),
             &formatdoc! { r#"
-                class {ident} : {base_classes}
+                {docs}class {ident} : {base_classes}
                 {{
                   {qobject_macro}
                 public:
@@ -101,6 +113,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
                 }};
 
                 {qobject_assert}"#,
+            docs = qobject.docs,
             // Note that there is always a base class as we always have CxxQtType
             base_classes = qobject.blocks.base_classes.join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
@@ -155,6 +168,12 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
                         .iter()
                         .flat_map(|qobject| &qobject.blocks.includes),
                 )
+                .chain(
+                    generated
+                        .qgadgets
+                        .iter()
+                        .flat_map(|qgadget| &qgadget.includes),
+                )
                 .chain(
                     generated
                         .extern_cxx_qt
@@ -179,9 +198,10 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
         #include "{include_path}.cxx.h"
 
         {extern_cxx_qt}
-        {qobjects}
+        {qgadgets}{qobjects}
     "#,
     forward_declare = forward_declare(generated).join("\n"),
+    qgadgets = qgadgets_fragments(generated, pair_as_header),
     qobjects = qobjects_header(generated).join("\n"),
     }
 }
@@ -71,6 +71,20 @@ pub fn extract_extern_qt(
         .join("\n")
 }
 
+/// Write the fragments of all Q_GADGETs, each is followed by a newline so that nothing is added when empty
+pub fn qgadgets_fragments(
+    generated: &GeneratedCppBlocks,
+    filter_fn: impl FnMut(&CppFragment) -> Option<String>,
+) -> String {
+    generated
+        .qgadgets
+        .iter()
+        .flat_map(|qgadget| &qgadget.fragments)
+        .filter_map(filter_fn)
+        .map(|fragment| fragment + "\n")
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeSet;
@@ -95,6 +109,9 @@ mod tests {
         GeneratedCppBlocks {
             forward_declares: vec![],
             includes: BTreeSet::default(),
+            qgadgets: vec![],
+            qenums: vec![],
+            qnamespaces: vec![],
             extern_cxx_qt: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
@@ -110,6 +127,7 @@ mod tests {
                         "cxx_qt_my_object".to_owned()
                     },
                     has_qobject_macro: true,
+                    docs: String::new(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["public QStringListModel".to_owned()],
                         includes: {
@@ -225,6 +243,9 @@ mod tests {
         GeneratedCppBlocks {
             forward_declares: vec![],
             includes: BTreeSet::default(),
+            qgadgets: vec![],
+            qenums: vec![],
+            qnamespaces: vec![],
             extern_cxx_qt: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
@@ -232,6 +253,7 @@ mod tests {
                     rust_struct: Name::mock("FirstObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_first_object".to_owned(),
                     has_qobject_macro: true,
+                    docs: String::new(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["public QStringListModel".to_owned()],
                         includes: {
@@ -275,6 +297,7 @@ mod tests {
                     rust_struct: Name::mock("SecondObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_second_object".to_owned(),
                     has_qobject_macro: true,
+                    docs: String::new(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["public QStringListModel".to_owned()],
                         includes: {
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::generator::cpp::GeneratedCppBlocks;
-use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_source};
+use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_source, qgadgets_fragments};
 use indoc::formatdoc;
 
 /// For a given GeneratedCppBlocks write the implementations
@@ -43,8 +43,9 @@ pub fn write_cpp_source(generated: &GeneratedCppBlocks, include_path: &str) -> S
         #include "{include_path}.cxxqt.h"
 
         {extern_cxx_qt}
-        {qobjects}
+        {qgadgets}{qobjects}
     "#,
+    qgadgets = qgadgets_fragments(generated, pair_as_source),
     qobjects = qobjects_source(generated).join("\n"),
     }
 }
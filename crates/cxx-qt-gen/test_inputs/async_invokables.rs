@@ -0,0 +1,23 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        type MyObject = super::MyObjectRust;
+
+        /// Fetch the contents of the url
+        #[qinvokable]
+        async fn fetch(self: Pin<&mut MyObject>, url: QString) -> QString;
+
+        #[qinvokable]
+        #[cxx_name = "doWork"]
+        async fn do_work(self: &MyObject);
+    }
+
+    impl cxx_qt::Threading for MyObject {}
+}
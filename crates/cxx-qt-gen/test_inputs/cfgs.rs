@@ -32,6 +32,17 @@ mod ffi {
         fn signal_enabled2(self: Pin<&mut QObjectExternDisabled>);
     }
 
+    // Disabled C++Qt block
+    // - cfg on the block applies to the QObject and qsignal
+    #[cfg(not(enabled))]
+    unsafe extern "C++Qt" {
+        #[qobject]
+        type QObjectExternBlockDisabled;
+
+        #[qsignal]
+        fn signal_block_disabled(self: Pin<&mut QObjectExternBlockDisabled>);
+    }
+
     // Enabled RustQt QObject
     // - disabled and enabled qenum
 
@@ -133,4 +144,21 @@ mod ffi {
         #[cfg(enabled)]
         fn signal_enabled(self: Pin<&mut QObjectDisabled>);
     }
+
+    // Disabled RustQt block
+    // - cfg on the block applies to the QObject, inherit, invokable and signal
+    #[cfg(not(enabled))]
+    unsafe extern "RustQt" {
+        #[qobject]
+        type QObjectBlockDisabled = super::QObjectBlockDisabledRust;
+
+        #[inherit]
+        fn inherit_block_disabled(self: &QObjectBlockDisabled);
+
+        #[qinvokable]
+        fn invokable_block_disabled(self: &QObjectBlockDisabled);
+
+        #[qsignal]
+        fn signal_block_disabled(self: Pin<&mut QObjectBlockDisabled>);
+    }
 }
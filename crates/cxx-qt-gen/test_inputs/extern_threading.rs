@@ -0,0 +1,13 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::timer")]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!(<QtCore/QTimer>);
+        /// QTimer
+        #[qobject]
+        #[namespace = ""]
+        type QTimer;
+    }
+
+    // Enabling threading on the extern qobject
+    impl cxx_qt::Threading for QTimer {}
+}
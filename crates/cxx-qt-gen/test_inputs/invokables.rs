@@ -16,11 +16,13 @@ mod ffi {
     }
 
     unsafe extern "RustQt" {
+        /// An object with invokables
         #[qobject]
         type MyObject = super::MyObjectRust;
 
         fn cpp_method(self: &MyObject);
 
+        /// A trivial invokable
         #[qinvokable]
         fn invokable(self: &MyObject);
 
@@ -0,0 +1,15 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!(<QtCore/QAbstractListModel>);
+        #[namespace = ""]
+        type QAbstractListModel;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[base = QAbstractListModel]
+        #[list_model(Todo, todos)]
+        type TodoList = super::TodoListRust;
+    }
+}
@@ -27,6 +27,8 @@ mod ffi {
         #[qproperty(i32, resettable_prop, cxx_name = "resettableProp", READ, WRITE, RESET = myResetFn)]
         #[qproperty(i32, required_prop, cxx_name = "requiredProp", READ, WRITE, REQUIRED)]
         #[qproperty(i32, final_prop, cxx_name = "finalProp", READ, WRITE, FINAL)]
+        #[qproperty(i32, size, READ, WRITE, NOTIFY, BINDABLE)]
+        #[qproperty(i32, count, READ, WRITE, RESET)]
         type MyObject = super::MyObjectRust;
     }
 
@@ -8,12 +8,21 @@ mod ffi {
     // Associated QEnums can be namespaced independently
     #[qenum(MyObject)]
     #[namespace = "my_namespace"]
+    #[repr(u8)]
     enum MyOtherEnum {
-        X,
-        Y,
+        X = 1,
+        Y = 0x10,
         Z,
     }
 
+    #[qenum(MyObject)]
+    #[qflags(MyFlags)]
+    enum MyFlag {
+        A = 0x1,
+        B = 0x2,
+        C = 0x4,
+    }
+
     #[qml_element]
     qnamespace!("cxx_qt::my_object");
 
@@ -28,8 +37,9 @@ mod ffi {
 
     #[qenum]
     #[namespace = "other_namespace"]
+    #[repr(i16)]
     enum MyOtherNamespacedEnum {
-        Variant1,
+        Variant1 = -1,
         Variant2,
     }
 
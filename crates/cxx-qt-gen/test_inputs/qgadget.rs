@@ -0,0 +1,23 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        /// A point in 2D space
+        #[qgadget]
+        #[derive(PartialEq)]
+        #[qml_element]
+        #[qproperty(i32, x)]
+        #[qproperty(i32, y)]
+        #[qproperty(QString, label, READ, CONSTANT)]
+        type Point = super::PointRust;
+
+        #[qobject]
+        #[qproperty(Point, position)]
+        type MyObject = super::MyObjectRust;
+    }
+}
@@ -0,0 +1,17 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!(<QtQuick/QQuickItem>);
+        #[qobject]
+        #[namespace = ""]
+        type QQuickItem;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qproperty(QPointer<QQuickItem>, item)]
+        #[qproperty(QPointer<QQuickItem>, other, READ, WRITE, NOTIFY, RESET)]
+        #[qproperty(cxx_qt::QPointer<QQuickItem>, plain, READ, WRITE, RESET)]
+        type MyObject = super::MyObjectRust;
+    }
+}
@@ -16,6 +16,7 @@ mod ffi {
 
         /// When the QTimer timeout occurs
         #[qsignal]
+        #[stream]
         pub(self) fn timeout(self: Pin<&mut Self>);
 
         /// A constant signal for when the timer is ready
@@ -29,6 +30,7 @@ mod ffi {
         type MyObject = super::MyObjectRust;
 
         #[qsignal]
+        #[stream]
         fn ready(self: Pin<&mut Self>);
 
         #[qsignal]
@@ -0,0 +1,15 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!(<QtCore/QAbstractItemModel>);
+        #[namespace = ""]
+        type QAbstractItemModel;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[base = QAbstractItemModel]
+        #[tree_model(FileItem, files)]
+        type FileTree = super::FileTreeRust;
+    }
+}
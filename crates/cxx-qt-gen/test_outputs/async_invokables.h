@@ -0,0 +1,82 @@
+#pragma once
+
+#include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/threading.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = ::rust::cxxqt1::CxxQtThread<MyObject>;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerfetchFinished =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsfetchFinished*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerdoWorkFinished = ::rust::cxxqt1::SignalHandler<
+  struct MyObjectCxxQtSignalParamsdoWorkFinished*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_fetchFinishedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerfetchFinished
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_doWorkFinishedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdoWorkFinished
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<MyObjectRust>
+  , private ::rust::cxxqt1::CxxQtThreading<MyObject>
+{
+  Q_OBJECT
+public:
+  virtual ~MyObject() = default;
+
+public:
+  /// Fetch the contents of the url
+  Q_INVOKABLE void fetch(QString url) noexcept;
+  Q_INVOKABLE void doWork() const noexcept;
+  /// Emitted with the result of the async invokable fetch once it has finished
+  Q_SIGNAL void fetchFinished(QString result);
+  /// Emitted with the result of the async invokable doWork once it has finished
+  Q_SIGNAL void doWorkFinished();
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  template<typename T>
+  friend auto ::rust::cxxqt1::qtThread(const T& qobject)
+    -> ::rust::cxxqt1::CxxQtThread<T>;
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
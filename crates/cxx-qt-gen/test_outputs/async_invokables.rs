@@ -0,0 +1,479 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "fetch"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_async_fetch(self: Pin<&mut MyObject>, url: QString);
+    }
+    extern "Rust" {
+        #[cxx_name = "doWork"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_async_do_work(self: &MyObject);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "fetchFinished"]
+        #[doc = "Emitted with the result of the async invokable fetch once it has finished"]
+        #[namespace = "cxx_qt::my_object"]
+        fn fetch_finished(self: Pin<&mut MyObject>, result: QString);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerfetchFinished = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosurefetchFinished,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_fetchFinishedConnect"]
+        fn MyObject_connect_fetch_finished(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerfetchFinished,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_fetchFinished(
+            handler: MyObjectCxxQtSignalHandlerfetchFinished,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_fetchFinished(
+            handler: &mut MyObjectCxxQtSignalHandlerfetchFinished,
+            self_value: Pin<&mut MyObject>,
+            result: QString,
+        );
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "doWorkFinished"]
+        #[doc = "Emitted with the result of the async invokable doWork once it has finished"]
+        #[namespace = "cxx_qt::my_object"]
+        fn do_work_finished(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerdoWorkFinished = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosuredoWorkFinished,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_doWorkFinishedConnect"]
+        fn MyObject_connect_do_work_finished(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerdoWorkFinished,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_doWorkFinished(
+            handler: MyObjectCxxQtSignalHandlerdoWorkFinished,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_doWorkFinished(
+            handler: &mut MyObjectCxxQtSignalHandlerdoWorkFinished,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectCxxQtThread = cxx_qt::CxxQtThread<MyObject>;
+        include!("cxx-qt/thread.h");
+        #[doc(hidden)]
+        #[cxx_name = "qtThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_qtThread(qobject: &MyObject) -> MyObjectCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadQueue"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadQueue(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+            coalesce: bool,
+            coalesce_key: u64,
+            max_pending: usize,
+            high_priority: bool,
+        ) -> u8;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadRetain"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadRetain(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+            value: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> u64;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadRelease"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadRelease(cxx_qt_thread: &MyObjectCxxQtThread, id: u64);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadClone"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadClone(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> MyObjectCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadDrop"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadDrop(cxx_qt_thread: Pin<&mut MyObjectCxxQtThread>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsDestroyed"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsCurrentThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> bool;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcastPtr(thiz: *const MyObject) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcastPtr(base: *const QObject) -> *const MyObject;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        fn create_rs_MyObjectRust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    pub fn cxx_qt_async_fetch(self: Pin<&mut ffi::MyObject>, url: ffi::QString) {
+        let cxx_qt_thread = ::cxx_qt::Threading::qt_thread(&*self);
+        let cxx_qt_future = self.fetch(url);
+        cxx_qt_thread.spawn_invokable(cxx_qt_future, |qobject, result| {
+            qobject.fetch_finished(result)
+        });
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    pub fn cxx_qt_async_do_work(self: &ffi::MyObject) {
+        let cxx_qt_thread = ::cxx_qt::Threading::qt_thread(&*self);
+        let cxx_qt_future = self.do_work();
+        cxx_qt_thread.spawn_invokable(cxx_qt_future, |qobject, ()| qobject.do_work_finished());
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "fetchFinished"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_fetch_finished<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, ffi::QString) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurefetchFinished,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurefetchFinished,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>, result: ffi::QString| {
+                        (connection_closure.get_mut())(self_value, result)
+                    },
+                ));
+                ffi::MyObject_connect_fetch_finished(self, handler, conn_type)
+            },
+        ))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "fetchFinished"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_fetch_finished<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, ffi::QString) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_fetch_finished (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurefetchFinished > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurefetchFinished {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosurefetchFinished {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerfetchFinished"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>, ffi::QString) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_fetchFinished;
+fn call_MyObject_signal_handler_fetchFinished(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurefetchFinished,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+    result: ffi::QString,
+) {
+    handler.closure()(self_value, result);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurefetchFinished>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurefetchFinished>,
+    [usize; 2]
+);
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "doWorkFinished"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_do_work_finished<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosuredoWorkFinished,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuredoWorkFinished,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_do_work_finished(self, handler, conn_type)
+            },
+        ))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "doWorkFinished"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_do_work_finished<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_do_work_finished (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuredoWorkFinished > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosuredoWorkFinished {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuredoWorkFinished {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdoWorkFinished"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_doWorkFinished;
+fn call_MyObject_signal_handler_doWorkFinished(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosuredoWorkFinished,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredoWorkFinished>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredoWorkFinished>,
+    [usize; 2]
+);
+impl cxx_qt::Threading for ffi::MyObject {
+    type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
+    type ThreadingTypeId = cxx::type_id!("cxx_qt::my_object::MyObjectCxxQtThread");
+    fn qt_thread(&self) -> ffi::MyObjectCxxQtThread {
+        ffi::cxx_qt_ffi_MyObject_qtThread(self)
+    }
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn queue<F>(
+        cxx_qt_thread: &ffi::MyObjectCxxQtThread,
+        f: F,
+        options: cxx_qt::ThreadingQueueOptions,
+    ) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+    where
+        F: FnOnce(core::pin::Pin<&mut ffi::MyObject>),
+        F: Send + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        #[doc(hidden)]
+        fn func(
+            obj: core::pin::Pin<&mut ffi::MyObject>,
+            arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+        ) {
+            (arg.inner)(obj)
+        }
+        let arg = MyObjectCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(f),
+        };
+        match ffi::cxx_qt_ffi_MyObject_cxxQtThreadQueue(
+            cxx_qt_thread,
+            func,
+            std::boxed::Box::new(arg),
+            options.coalesce_key.is_some(),
+            options.coalesce_key.unwrap_or_default(),
+            options.max_pending.unwrap_or(usize::MAX),
+            options.high_priority,
+        ) {
+            0 => Ok(()),
+            others => Err(others.into()),
+        }
+    }
+    #[doc(hidden)]
+    fn retain<V>(cxx_qt_thread: &ffi::MyObjectCxxQtThread, value: V) -> core::option::Option<u64>
+    where
+        V: Send + 'static,
+    {
+        let arg = MyObjectCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(move |_| drop(value)),
+        };
+        match ffi::cxx_qt_ffi_MyObject_cxxQtThreadRetain(cxx_qt_thread, std::boxed::Box::new(arg)) {
+            0 => None,
+            id => Some(id),
+        }
+    }
+    #[doc(hidden)]
+    fn release(cxx_qt_thread: &ffi::MyObjectCxxQtThread, id: u64) {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadRelease(cxx_qt_thread, id);
+    }
+    #[doc(hidden)]
+    fn threading_clone(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> ffi::MyObjectCxxQtThread {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadClone(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn threading_drop(cxx_qt_thread: core::pin::Pin<&mut ffi::MyObjectCxxQtThread>) {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadDrop(cxx_qt_thread);
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtThreadQueuedFn {
+    inner: std::boxed::Box<dyn FnOnce(core::pin::Pin<&mut ffi::MyObject>) + Send>,
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+    }
+}
@@ -47,7 +47,7 @@ QObjectExternEnabled_signal_enabled1Connect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &QObjectExternEnabled::signal_enabled1,
     &self,
@@ -105,7 +105,7 @@ QObjectExternDisabled_signal_enabled2Connect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &QObjectExternDisabled::signal_enabled2,
     &self,
@@ -160,7 +160,7 @@ QObjectEnabled_signal_enabledConnect(
   ::rust::cxxqtgen1::QObjectEnabledCxxQtSignalHandlersignal_enabled closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &QObjectEnabled::signal_enabled,
     &self,
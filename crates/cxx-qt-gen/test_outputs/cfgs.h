@@ -2,6 +2,7 @@
 
 #include <cstdint>
 #include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
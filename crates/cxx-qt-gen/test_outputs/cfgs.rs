@@ -302,6 +302,104 @@ mod ffi {
             outer: Pin<&mut QObjectDisabled>,
         ) -> Pin<&mut QObjectDisabledRust>;
     }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "QObjectBlockDisabledRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[cfg(not(enabled))]
+        type QObjectBlockDisabled;
+    }
+    extern "Rust" {
+        #[cfg(not(enabled))]
+        type QObjectBlockDisabledRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "invokable_block_disabled"]
+        #[cfg(not(enabled))]
+        #[doc(hidden)]
+        unsafe fn invokable_block_disabled(self: &QObjectBlockDisabled);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "inherit_block_disabledCxxQtInherit"]
+        #[cfg(not(enabled))]
+        fn inherit_block_disabled(self: &QObjectBlockDisabled);
+    }
+    #[cfg(not(enabled))]
+    unsafe extern "C++" {
+        #[cxx_name = "signal_block_disabled"]
+        fn signal_block_disabled(self: Pin<&mut QObjectBlockDisabled>);
+    }
+    #[cfg(not(enabled))]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        type QObjectBlockDisabledCxxQtSignalHandlersignal_block_disabled =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+            >;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        #[cxx_name = "QObjectBlockDisabled_signal_block_disabledConnect"]
+        fn QObjectBlockDisabled_connect_signal_block_disabled(
+            self_value: Pin<&mut QObjectBlockDisabled>,
+            signal_handler: QObjectBlockDisabledCxxQtSignalHandlersignal_block_disabled,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "rust::cxxqtgen1"]
+    #[cfg(not(enabled))]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_QObjectBlockDisabled_signal_handler_signal_block_disabled(
+            handler: QObjectBlockDisabledCxxQtSignalHandlersignal_block_disabled,
+        );
+        #[doc(hidden)]
+        fn call_QObjectBlockDisabled_signal_handler_signal_block_disabled(
+            handler: &mut QObjectBlockDisabledCxxQtSignalHandlersignal_block_disabled,
+            self_value: Pin<&mut QObjectBlockDisabled>,
+        );
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QObjectBlockDisabled_upcastPtr(
+            thiz: *const QObjectBlockDisabled,
+        ) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QObjectBlockDisabled_downcastPtr(
+            base: *const QObject,
+        ) -> *const QObjectBlockDisabled;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt_QObjectBlockDisabled"]
+        #[cfg(not(enabled))]
+        fn create_rs_QObjectBlockDisabledRust() -> Box<QObjectBlockDisabledRust>;
+    }
+    #[cfg(not(enabled))]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QObjectBlockDisabled_unsafeRust(
+            outer: &QObjectBlockDisabled,
+        ) -> &QObjectBlockDisabledRust;
+    }
+    #[cfg(not(enabled))]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QObjectBlockDisabled_unsafeRustMut(
+            outer: Pin<&mut QObjectBlockDisabled>,
+        ) -> Pin<&mut QObjectBlockDisabledRust>;
+    }
     extern "C++" {
         #[doc(hidden)]
         #[cxx_name = "upcastPtr"]
@@ -478,6 +576,59 @@ mod ffi {
             self_value: Pin<&mut QObjectExternDisabled>,
         );
     }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QObjectExternBlockDisabled_upcastPtr(
+            thiz: *const QObjectExternBlockDisabled,
+        ) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QObjectExternBlockDisabled_downcastPtr(
+            base: *const QObject,
+        ) -> *const QObjectExternBlockDisabled;
+    }
+    unsafe extern "C++" {
+        #[cfg(not(enabled))]
+        type QObjectExternBlockDisabled;
+    }
+    #[cfg(not(enabled))]
+    unsafe extern "C++" {
+        #[cxx_name = "signal_block_disabled"]
+        fn signal_block_disabled(self: Pin<&mut QObjectExternBlockDisabled>);
+    }
+    #[cfg(not(enabled))]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        type QObjectExternBlockDisabledCxxQtSignalHandlersignal_block_disabled =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+            >;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        #[cxx_name = "QObjectExternBlockDisabled_signal_block_disabledConnect"]
+        fn QObjectExternBlockDisabled_connect_signal_block_disabled(
+            self_value: Pin<&mut QObjectExternBlockDisabled>,
+            signal_handler: QObjectExternBlockDisabledCxxQtSignalHandlersignal_block_disabled,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "rust::cxxqtgen1"]
+    #[cfg(not(enabled))]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_QObjectExternBlockDisabled_signal_handler_signal_block_disabled(
+            handler: QObjectExternBlockDisabledCxxQtSignalHandlersignal_block_disabled,
+        );
+        #[doc(hidden)]
+        fn call_QObjectExternBlockDisabled_signal_handler_signal_block_disabled(
+            handler: &mut QObjectExternBlockDisabledCxxQtSignalHandlersignal_block_disabled,
+            self_value: Pin<&mut QObjectExternBlockDisabled>,
+        );
+    }
     extern "C++" {
         #[doc(hidden)]
         #[namespace = ""]
@@ -513,12 +664,24 @@ impl ffi::QObjectEnabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QObjectEnabled_connect_signal_disabled(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                QObjectEnabledCxxQtSignalClosuresignal_disabled,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectEnabledCxxQtSignalClosuresignal_disabled,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectEnabledCxxQtSignalClosuresignal_disabled,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectEnabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectEnabled_connect_signal_disabled(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -589,12 +752,24 @@ impl ffi::QObjectEnabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QObjectEnabled_connect_signal_enabled(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                QObjectEnabledCxxQtSignalClosuresignal_enabled,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectEnabledCxxQtSignalClosuresignal_enabled,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectEnabledCxxQtSignalClosuresignal_enabled,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectEnabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectEnabled_connect_signal_enabled(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -696,12 +871,24 @@ impl ffi::QObjectDisabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QObjectDisabled_connect_signal_disabled(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                QObjectDisabledCxxQtSignalClosuresignal_disabled,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectDisabledCxxQtSignalClosuresignal_disabled,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectDisabledCxxQtSignalClosuresignal_disabled,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectDisabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectDisabled_connect_signal_disabled(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -772,12 +959,24 @@ impl ffi::QObjectDisabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QObjectDisabled_connect_signal_enabled(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                QObjectDisabledCxxQtSignalClosuresignal_enabled,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectDisabledCxxQtSignalClosuresignal_enabled,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectDisabledCxxQtSignalClosuresignal_enabled,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectDisabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectDisabled_connect_signal_enabled(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -866,6 +1065,133 @@ impl ::cxx_qt::CxxQtType for ffi::QObjectDisabled {
         ffi::cxx_qt_ffi_QObjectDisabled_unsafeRustMut(self)
     }
 }
+#[cfg(not(enabled))]
+impl ffi::QObjectBlockDisabled {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "signal_block_disabled"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_signal_block_disabled<
+        F: FnMut(core::pin::Pin<&mut ffi::QObjectBlockDisabled>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QObjectBlockDisabled>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectBlockDisabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectBlockDisabled_connect_signal_block_disabled(self, handler, conn_type)
+            },
+        ))
+    }
+}
+#[cfg(not(enabled))]
+impl ffi::QObjectBlockDisabled {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "signal_block_disabled"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_signal_block_disabled<
+        F: FnMut(core::pin::Pin<&mut ffi::QObjectBlockDisabled>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QObjectBlockDisabled>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::QObjectBlockDisabled_connect_signal_block_disabled(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[cfg(not(enabled))]
+#[doc(hidden)]
+pub struct QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled {}
+#[cfg(not(enabled))]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled
+{
+    type Id = cxx::type_id!(
+        "::rust::cxxqtgen1::QObjectBlockDisabledCxxQtSignalHandlersignal_block_disabled"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QObjectBlockDisabled>) + Send;
+}
+#[cfg(not(enabled))]
+use core::mem::drop as drop_QObjectBlockDisabled_signal_handler_signal_block_disabled;
+#[cfg(not(enabled))]
+fn call_QObjectBlockDisabled_signal_handler_signal_block_disabled(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+    >,
+    self_value: core::pin::Pin<&mut ffi::QObjectBlockDisabled>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(not(enabled))]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<
+        QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+    >,
+    usize
+);
+#[cfg(not(enabled))]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<
+        QObjectBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+    >,
+    [usize; 2]
+);
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::QObjectBlockDisabled {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_QObjectBlockDisabled_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_QObjectBlockDisabled_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+#[cfg(not(enabled))]
+pub fn create_rs_QObjectBlockDisabledRust() -> std::boxed::Box<QObjectBlockDisabledRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+#[cfg(not(enabled))]
+impl ::core::ops::Deref for ffi::QObjectBlockDisabled {
+    type Target = QObjectBlockDisabledRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_QObjectBlockDisabled_unsafeRust(self)
+    }
+}
+#[cfg(not(enabled))]
+impl ::cxx_qt::CxxQtType for ffi::QObjectBlockDisabled {
+    type Rust = QObjectBlockDisabledRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_QObjectBlockDisabled_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_QObjectBlockDisabled_unsafeRustMut(self)
+    }
+}
 unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::QObjectExternEnabled {
     unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
         ffi::cxx_qt_ffi_QObjectExternEnabled_upcastPtr(this)
@@ -887,15 +1213,25 @@ impl ffi::QObjectExternEnabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(
-            ffi::QObjectExternEnabled_connect_signal_disabled1(
-                self,
-                cxx_qt::signalhandler::CxxQtSignalHandler::<
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectExternEnabledCxxQtSignalClosuresignal_disabled1,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
                     QObjectExternEnabledCxxQtSignalClosuresignal_disabled1,
-                >::new(Box::new(closure)),
-                conn_type,
-            ),
-        )
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectExternEnabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectExternEnabled_connect_signal_disabled1(self, handler, conn_type)
+            },
+        ))
     }
 }
 #[cfg(not(enabled))]
@@ -972,12 +1308,24 @@ impl ffi::QObjectExternEnabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QObjectExternEnabled_connect_signal_enabled1(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                QObjectExternEnabledCxxQtSignalClosuresignal_enabled1,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectExternEnabledCxxQtSignalClosuresignal_enabled1,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectExternEnabledCxxQtSignalClosuresignal_enabled1,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectExternEnabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectExternEnabled_connect_signal_enabled1(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -1061,15 +1409,25 @@ impl ffi::QObjectExternDisabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(
-            ffi::QObjectExternDisabled_connect_signal_disabled2(
-                self,
-                cxx_qt::signalhandler::CxxQtSignalHandler::<
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectExternDisabledCxxQtSignalClosuresignal_disabled2,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
                     QObjectExternDisabledCxxQtSignalClosuresignal_disabled2,
-                >::new(Box::new(closure)),
-                conn_type,
-            ),
-        )
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectExternDisabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectExternDisabled_connect_signal_disabled2(self, handler, conn_type)
+            },
+        ))
     }
 }
 #[cfg(not(enabled))]
@@ -1146,15 +1504,25 @@ impl ffi::QObjectExternDisabled {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(
-            ffi::QObjectExternDisabled_connect_signal_enabled2(
-                self,
-                cxx_qt::signalhandler::CxxQtSignalHandler::<
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectExternDisabledCxxQtSignalClosuresignal_enabled2,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
                     QObjectExternDisabledCxxQtSignalClosuresignal_enabled2,
-                >::new(Box::new(closure)),
-                conn_type,
-            ),
-        )
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectExternDisabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectExternDisabled_connect_signal_enabled2(self, handler, conn_type)
+            },
+        ))
     }
 }
 #[cfg(enabled)]
@@ -1218,6 +1586,112 @@ cxx_qt::static_assertions::assert_eq_size!(
     >,
     [usize; 2]
 );
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::QObjectExternBlockDisabled {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_QObjectExternBlockDisabled_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_QObjectExternBlockDisabled_downcastPtr(base)
+    }
+}
+#[cfg(not(enabled))]
+impl ffi::QObjectExternBlockDisabled {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "signal_block_disabled"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_signal_block_disabled<
+        F: FnMut(core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QObjectExternBlockDisabled_connect_signal_block_disabled(
+                    self, handler, conn_type,
+                )
+            },
+        ))
+    }
+}
+#[cfg(not(enabled))]
+impl ffi::QObjectExternBlockDisabled {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "signal_block_disabled"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_signal_block_disabled<
+        F: FnMut(core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::QObjectExternBlockDisabled_connect_signal_block_disabled(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[cfg(not(enabled))]
+#[doc(hidden)]
+pub struct QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled {}
+#[cfg(not(enabled))]
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled
+{
+    type Id = cxx::type_id!(
+        "::rust::cxxqtgen1::QObjectExternBlockDisabledCxxQtSignalHandlersignal_block_disabled"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>) + Send;
+}
+#[cfg(not(enabled))]
+use core::mem::drop as drop_QObjectExternBlockDisabled_signal_handler_signal_block_disabled;
+#[cfg(not(enabled))]
+fn call_QObjectExternBlockDisabled_signal_handler_signal_block_disabled(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+    >,
+    self_value: core::pin::Pin<&mut ffi::QObjectExternBlockDisabled>,
+) {
+    handler.closure()(self_value);
+}
+#[cfg(not(enabled))]
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<
+        QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+    >,
+    usize
+);
+#[cfg(not(enabled))]
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<
+        QObjectExternBlockDisabledCxxQtSignalClosuresignal_block_disabled,
+    >,
+    [usize; 2]
+);
 #[cfg(not(enabled))]
 mod cxx_qt_private_qenum_EnumDisabled1 {
     #[derive(PartialEq, Eq, Clone, Copy)]
@@ -0,0 +1,6 @@
+#include "directory/file_ident.cxxqt.h"
+
+static_assert(alignof(QTimerCxxQtThread) <= alignof(::std::size_t),
+              "unexpected aligment");
+static_assert(sizeof(QTimerCxxQtThread) == sizeof(::std::size_t[2]),
+              "unexpected size");
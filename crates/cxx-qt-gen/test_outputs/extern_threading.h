@@ -0,0 +1,9 @@
+#pragma once
+
+#include <cxx-qt/casting.h>
+#include <cxx-qt/threading.h>
+
+class QTimer;
+using QTimerCxxQtThread = ::rust::cxxqt1::CxxQtThread<QTimer>;
+
+#include "directory/file_ident.cxx.h"
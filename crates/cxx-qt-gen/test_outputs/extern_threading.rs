@@ -0,0 +1,184 @@
+#[cxx::bridge(namespace = "cxx_qt::timer")]
+#[allow(unused_unsafe)]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QTimer_upcastPtr(thiz: *const QTimer) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QTimer_downcastPtr(base: *const QObject) -> *const QTimer;
+    }
+    unsafe extern "C++" {
+        #[doc = " QTimer"]
+        type QTimer;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QTimerCxxQtThread = cxx_qt::CxxQtThread<QTimer>;
+        include!("cxx-qt/thread.h");
+        #[doc(hidden)]
+        #[cxx_name = "qtThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_qtThread(qobject: &QTimer) -> QTimerCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadQueue"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadQueue(
+            cxx_qt_thread: &QTimerCxxQtThread,
+            func: fn(Pin<&mut QTimer>, Box<QTimerCxxQtThreadQueuedFn>),
+            arg: Box<QTimerCxxQtThreadQueuedFn>,
+            coalesce: bool,
+            coalesce_key: u64,
+            max_pending: usize,
+            high_priority: bool,
+        ) -> u8;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadRetain"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadRetain(
+            cxx_qt_thread: &QTimerCxxQtThread,
+            value: Box<QTimerCxxQtThreadQueuedFn>,
+        ) -> u64;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadRelease"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadRelease(cxx_qt_thread: &QTimerCxxQtThread, id: u64);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadClone"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadClone(
+            cxx_qt_thread: &QTimerCxxQtThread,
+        ) -> QTimerCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadDrop"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadDrop(cxx_qt_thread: Pin<&mut QTimerCxxQtThread>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsDestroyed"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadIsDestroyed(cxx_qt_thread: &QTimerCxxQtThread) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsCurrentThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_QTimer_cxxQtThreadIsCurrentThread(cxx_qt_thread: &QTimerCxxQtThread) -> bool;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt_QTimer"]
+        type QTimerCxxQtThreadQueuedFn;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QTimer >);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::QTimer {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_QTimer_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_QTimer_downcastPtr(base)
+    }
+}
+impl cxx_qt::Threading for ffi::QTimer {
+    type BoxedQueuedFn = QTimerCxxQtThreadQueuedFn;
+    type ThreadingTypeId = cxx::type_id!("QTimerCxxQtThread");
+    fn qt_thread(&self) -> ffi::QTimerCxxQtThread {
+        ffi::cxx_qt_ffi_QTimer_qtThread(self)
+    }
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &ffi::QTimerCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_QTimer_cxxQtThreadIsDestroyed(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &ffi::QTimerCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_QTimer_cxxQtThreadIsCurrentThread(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn queue<F>(
+        cxx_qt_thread: &ffi::QTimerCxxQtThread,
+        f: F,
+        options: cxx_qt::ThreadingQueueOptions,
+    ) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+    where
+        F: FnOnce(core::pin::Pin<&mut ffi::QTimer>),
+        F: Send + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        #[doc(hidden)]
+        fn func(
+            obj: core::pin::Pin<&mut ffi::QTimer>,
+            arg: std::boxed::Box<QTimerCxxQtThreadQueuedFn>,
+        ) {
+            (arg.inner)(obj)
+        }
+        let arg = QTimerCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(f),
+        };
+        match ffi::cxx_qt_ffi_QTimer_cxxQtThreadQueue(
+            cxx_qt_thread,
+            func,
+            std::boxed::Box::new(arg),
+            options.coalesce_key.is_some(),
+            options.coalesce_key.unwrap_or_default(),
+            options.max_pending.unwrap_or(usize::MAX),
+            options.high_priority,
+        ) {
+            0 => Ok(()),
+            others => Err(others.into()),
+        }
+    }
+    #[doc(hidden)]
+    fn retain<V>(cxx_qt_thread: &ffi::QTimerCxxQtThread, value: V) -> core::option::Option<u64>
+    where
+        V: Send + 'static,
+    {
+        let arg = QTimerCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(move |_| drop(value)),
+        };
+        match ffi::cxx_qt_ffi_QTimer_cxxQtThreadRetain(cxx_qt_thread, std::boxed::Box::new(arg)) {
+            0 => None,
+            id => Some(id),
+        }
+    }
+    #[doc(hidden)]
+    fn release(cxx_qt_thread: &ffi::QTimerCxxQtThread, id: u64) {
+        ffi::cxx_qt_ffi_QTimer_cxxQtThreadRelease(cxx_qt_thread, id);
+    }
+    #[doc(hidden)]
+    fn threading_clone(cxx_qt_thread: &ffi::QTimerCxxQtThread) -> ffi::QTimerCxxQtThread {
+        ffi::cxx_qt_ffi_QTimer_cxxQtThreadClone(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn threading_drop(cxx_qt_thread: core::pin::Pin<&mut ffi::QTimerCxxQtThread>) {
+        ffi::cxx_qt_ffi_QTimer_cxxQtThreadDrop(cxx_qt_thread);
+    }
+}
+#[doc(hidden)]
+pub struct QTimerCxxQtThreadQueuedFn {
+    inner: std::boxed::Box<dyn FnOnce(core::pin::Pin<&mut ffi::QTimer>) + Send>,
+}
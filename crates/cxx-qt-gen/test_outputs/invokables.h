@@ -13,6 +13,7 @@ using MyObjectCxxQtThread = ::rust::cxxqt1::CxxQtThread<MyObject>;
 #include "directory/file_ident.cxx.h"
 
 namespace cxx_qt::my_object {
+/// An object with invokables
 class MyObject
   : public QObject
   , private ::rust::cxxqt1::CxxQtType<MyObjectRust>
@@ -24,6 +25,7 @@ public:
 
 public:
   void cpp_method() const noexcept;
+  /// A trivial invokable
   Q_INVOKABLE void invokable() const noexcept;
   Q_INVOKABLE void invokable_mutable() noexcept;
   Q_INVOKABLE void invokable_parameters(
@@ -137,8 +137,23 @@ mod ffi {
             cxx_qt_thread: &MyObjectCxxQtThread,
             func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
             arg: Box<MyObjectCxxQtThreadQueuedFn>,
+            coalesce: bool,
+            coalesce_key: u64,
+            max_pending: usize,
+            high_priority: bool,
         ) -> u8;
         #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadRetain"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadRetain(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+            value: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> u64;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadRelease"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadRelease(cxx_qt_thread: &MyObjectCxxQtThread, id: u64);
+        #[doc(hidden)]
         #[cxx_name = "cxxQtThreadClone"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_MyObject_cxxQtThreadClone(
@@ -152,6 +167,12 @@ mod ffi {
         #[cxx_name = "cxxQtThreadIsDestroyed"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsCurrentThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> bool;
     }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
@@ -285,9 +306,14 @@ impl cxx_qt::Threading for ffi::MyObject {
         ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread)
     }
     #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread)
+    }
+    #[doc(hidden)]
     fn queue<F>(
         cxx_qt_thread: &ffi::MyObjectCxxQtThread,
         f: F,
+        options: cxx_qt::ThreadingQueueOptions,
     ) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
     where
         F: FnOnce(core::pin::Pin<&mut ffi::MyObject>),
@@ -308,12 +334,33 @@ impl cxx_qt::Threading for ffi::MyObject {
             cxx_qt_thread,
             func,
             std::boxed::Box::new(arg),
+            options.coalesce_key.is_some(),
+            options.coalesce_key.unwrap_or_default(),
+            options.max_pending.unwrap_or(usize::MAX),
+            options.high_priority,
         ) {
             0 => Ok(()),
             others => Err(others.into()),
         }
     }
     #[doc(hidden)]
+    fn retain<V>(cxx_qt_thread: &ffi::MyObjectCxxQtThread, value: V) -> core::option::Option<u64>
+    where
+        V: Send + 'static,
+    {
+        let arg = MyObjectCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(move |_| drop(value)),
+        };
+        match ffi::cxx_qt_ffi_MyObject_cxxQtThreadRetain(cxx_qt_thread, std::boxed::Box::new(arg)) {
+            0 => None,
+            id => Some(id),
+        }
+    }
+    #[doc(hidden)]
+    fn release(cxx_qt_thread: &ffi::MyObjectCxxQtThread, id: u64) {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadRelease(cxx_qt_thread, id);
+    }
+    #[doc(hidden)]
     fn threading_clone(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> ffi::MyObjectCxxQtThread {
         ffi::cxx_qt_ffi_MyObject_cxxQtThreadClone(cxx_qt_thread)
     }
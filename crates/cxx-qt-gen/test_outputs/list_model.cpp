@@ -0,0 +1,110 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::my_object {
+int
+TodoList::rowCount(const QModelIndex& parent) const
+{
+  if (parent.isValid()) {
+    return 0;
+  }
+  return cxxQtListModelRowCount();
+}
+
+QVariant
+TodoList::data(const QModelIndex& index, int role) const
+{
+  if (!index.isValid()) {
+    return QVariant();
+  }
+  return cxxQtListModelData(index.row(), role);
+}
+
+bool
+TodoList::setData(const QModelIndex& index, const QVariant& value, int role)
+{
+  if (!index.isValid() || !cxxQtListModelSetData(index.row(), role, value)) {
+    return false;
+  }
+  Q_EMIT dataChanged(index, index);
+  return true;
+}
+
+QHash<int, QByteArray>
+TodoList::roleNames() const
+{
+  auto roles = QAbstractListModel::roleNames();
+  const auto names = cxxQtListModelRoleNames();
+  for (::std::size_t i = 0; i < names.size(); ++i) {
+    roles.insert(Qt::UserRole + static_cast<int>(i),
+                 QByteArray(names[i].data(),
+                            static_cast<int>(names[i].size())));
+  }
+  return roles;
+}
+
+void
+TodoList::cxxQtListModelBeginInsertRows(::std::int32_t first,
+                                        ::std::int32_t last)
+{
+  beginInsertRows(QModelIndex(), first, last);
+}
+
+void
+TodoList::cxxQtListModelEndInsertRows()
+{
+  endInsertRows();
+}
+
+void
+TodoList::cxxQtListModelBeginRemoveRows(::std::int32_t first,
+                                        ::std::int32_t last)
+{
+  beginRemoveRows(QModelIndex(), first, last);
+}
+
+void
+TodoList::cxxQtListModelEndRemoveRows()
+{
+  endRemoveRows();
+}
+
+bool
+TodoList::cxxQtListModelBeginMoveRows(::std::int32_t first,
+                                      ::std::int32_t last,
+                                      ::std::int32_t destination)
+{
+  return beginMoveRows(QModelIndex(), first, last, QModelIndex(), destination);
+}
+
+void
+TodoList::cxxQtListModelEndMoveRows()
+{
+  endMoveRows();
+}
+
+void
+TodoList::cxxQtListModelBeginResetModel()
+{
+  beginResetModel();
+}
+
+void
+TodoList::cxxQtListModelEndResetModel()
+{
+  endResetModel();
+}
+
+void
+TodoList::cxxQtListModelRowsChanged(::std::int32_t first, ::std::int32_t last)
+{
+  Q_EMIT dataChanged(index(first, 0), index(last, 0));
+}
+
+TodoList::TodoList(QObject* parent)
+  : QAbstractListModel(parent)
+  , ::rust::cxxqt1::CxxQtType<TodoListRust>(
+      ::cxx_qt::my_object::cxx_qt_TodoList::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
@@ -0,0 +1,68 @@
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QModelIndex>
+#include <QtCore/QVariant>
+#include <cxx-qt/casting.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class TodoList;
+
+} // namespace cxx_qt::my_object
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object {
+class TodoList
+  : public QAbstractListModel
+  , private ::rust::cxxqt1::CxxQtType<TodoListRust>
+{
+  Q_OBJECT
+public:
+  virtual ~TodoList() = default;
+
+public:
+  int rowCount(const QModelIndex& parent = QModelIndex()) const override;
+  QVariant data(const QModelIndex& index,
+                int role = Qt::DisplayRole) const override;
+  bool setData(const QModelIndex& index,
+               const QVariant& value,
+               int role = Qt::EditRole) override;
+  QHash<int, QByteArray> roleNames() const override;
+  void cxxQtListModelBeginInsertRows(::std::int32_t first, ::std::int32_t last);
+  void cxxQtListModelEndInsertRows();
+  void cxxQtListModelBeginRemoveRows(::std::int32_t first, ::std::int32_t last);
+  void cxxQtListModelEndRemoveRows();
+  bool cxxQtListModelBeginMoveRows(::std::int32_t first,
+                                   ::std::int32_t last,
+                                   ::std::int32_t destination);
+  void cxxQtListModelEndMoveRows();
+  void cxxQtListModelBeginResetModel();
+  void cxxQtListModelEndResetModel();
+  void cxxQtListModelRowsChanged(::std::int32_t first, ::std::int32_t last);
+  explicit TodoList(QObject* parent = nullptr);
+
+private:
+  ::std::int32_t cxxQtListModelRowCount() const noexcept;
+  QVariant cxxQtListModelData(::std::int32_t row,
+                              ::std::int32_t role) const noexcept;
+  bool cxxQtListModelSetData(::std::int32_t row,
+                             ::std::int32_t role,
+                             QVariant const& value) noexcept;
+  ::rust::Vec<::rust::String> cxxQtListModelRoleNames() const noexcept;
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, TodoList>::value,
+              "TodoList must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::TodoList*)
@@ -0,0 +1,231 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QAbstractListModel >);
+        #[namespace = ""]
+        type QAbstractListModel;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "TodoListRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type TodoList;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type TodoListRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtListModelRowCount"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_row_count(self: &TodoList) -> i32;
+        #[cxx_name = "cxxQtListModelData"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_data(self: &TodoList, row: i32, role: i32) -> QVariant;
+        #[cxx_name = "cxxQtListModelSetData"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_set_data(
+            self: Pin<&mut TodoList>,
+            row: i32,
+            role: i32,
+            value: &QVariant,
+        ) -> bool;
+        #[cxx_name = "cxxQtListModelRoleNames"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_role_names(self: &TodoList) -> Vec<String>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelBeginInsertRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_begin_insert_rows(self: Pin<&mut TodoList>, first: i32, last: i32);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelEndInsertRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_end_insert_rows(self: Pin<&mut TodoList>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelBeginRemoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_begin_remove_rows(self: Pin<&mut TodoList>, first: i32, last: i32);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelEndRemoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_end_remove_rows(self: Pin<&mut TodoList>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelBeginMoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_begin_move_rows(
+            self: Pin<&mut TodoList>,
+            first: i32,
+            last: i32,
+            destination: i32,
+        ) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelEndMoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_end_move_rows(self: Pin<&mut TodoList>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelBeginResetModel"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_begin_reset_model(self: Pin<&mut TodoList>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelEndResetModel"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_end_reset_model(self: Pin<&mut TodoList>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtListModelRowsChanged"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_list_model_rows_changed(self: Pin<&mut TodoList>, first: i32, last: i32);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_TodoList_upcastPtr(thiz: *const TodoList)
+            -> *const QAbstractListModel;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_TodoList_downcastPtr(
+            base: *const QAbstractListModel,
+        ) -> *const TodoList;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_TodoList"]
+        fn create_rs_TodoListRust() -> Box<TodoListRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_TodoList_unsafeRust(outer: &TodoList) -> &TodoListRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_TodoList_unsafeRustMut(outer: Pin<&mut TodoList>) -> Pin<&mut TodoListRust>;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QVariant = ::cxx_qt_lib::QVariant;
+    }
+}
+impl ffi::TodoList {
+    #[doc(hidden)]
+    pub fn cxx_qt_list_model_row_count(&self) -> i32 {
+        ::cxx_qt_lib::ListModel::cxx_qt_row_count(self)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_list_model_data(&self, row: i32, role: i32) -> ::cxx_qt_lib::QVariant {
+        ::cxx_qt_lib::ListModel::cxx_qt_data(self, row, role)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_list_model_set_data(
+        self: core::pin::Pin<&mut Self>,
+        row: i32,
+        role: i32,
+        value: &::cxx_qt_lib::QVariant,
+    ) -> bool {
+        ::cxx_qt_lib::ListModel::cxx_qt_set_data(self, row, role, value)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_list_model_role_names(&self) -> ::std::vec::Vec<::std::string::String> {
+        <Self as ::cxx_qt_lib::ListModel>::cxx_qt_role_names()
+    }
+}
+impl ::cxx_qt_lib::ListModel for ffi::TodoList {
+    type Item = Todo;
+    fn cxx_qt_items(&self) -> &::std::vec::Vec<Self::Item> {
+        &self.todos
+    }
+    fn cxx_qt_items_mut(self: core::pin::Pin<&mut Self>) -> &mut ::std::vec::Vec<Self::Item> {
+        use cxx_qt::CxxQtType;
+        &mut self.rust_mut().get_mut().todos
+    }
+    fn cxx_qt_begin_insert_rows(self: core::pin::Pin<&mut Self>, first: i32, last: i32) {
+        self.cxx_qt_list_model_begin_insert_rows(first, last);
+    }
+    fn cxx_qt_end_insert_rows(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_list_model_end_insert_rows();
+    }
+    fn cxx_qt_begin_remove_rows(self: core::pin::Pin<&mut Self>, first: i32, last: i32) {
+        self.cxx_qt_list_model_begin_remove_rows(first, last);
+    }
+    fn cxx_qt_end_remove_rows(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_list_model_end_remove_rows();
+    }
+    fn cxx_qt_begin_move_rows(
+        self: core::pin::Pin<&mut Self>,
+        first: i32,
+        last: i32,
+        destination: i32,
+    ) -> bool {
+        self.cxx_qt_list_model_begin_move_rows(first, last, destination)
+    }
+    fn cxx_qt_end_move_rows(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_list_model_end_move_rows();
+    }
+    fn cxx_qt_begin_reset_model(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_list_model_begin_reset_model();
+    }
+    fn cxx_qt_end_reset_model(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_list_model_end_reset_model();
+    }
+    fn cxx_qt_rows_changed(self: core::pin::Pin<&mut Self>, first: i32, last: i32) {
+        self.cxx_qt_list_model_rows_changed(first, last);
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<ffi::QAbstractListModel> for ffi::TodoList {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::QAbstractListModel {
+        ffi::cxx_qt_ffi_TodoList_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ffi::QAbstractListModel) -> *const Self {
+        ffi::cxx_qt_ffi_TodoList_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_TodoListRust() -> std::boxed::Box<TodoListRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::TodoList {
+    type Target = TodoListRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_TodoList_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::TodoList {
+    type Rust = TodoListRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_TodoList_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_TodoList_unsafeRustMut(self)
+    }
+}
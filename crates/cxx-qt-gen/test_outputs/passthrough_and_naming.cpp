@@ -44,7 +44,7 @@ QPushButton_clickedConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::multi_object::QPushButton::clicked,
     &self,
@@ -101,7 +101,7 @@ ExternObjectCpp_dataReadyConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &mynamespace::ExternObjectCpp::dataReady,
     &self,
@@ -157,7 +157,7 @@ ExternObjectCpp_errorOccurredConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &mynamespace::ExternObjectCpp::errorOccurred,
     &self,
@@ -214,7 +214,7 @@ MyObject_propertyNameChangedConnect(
     MyObjectCxxQtSignalHandlerpropertyNameChanged closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::multi_object::MyObject::propertyNameChanged,
     &self,
@@ -268,7 +268,7 @@ MyObject_readyConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::multi_object::MyObject::ready,
     &self,
@@ -334,7 +334,7 @@ SecondObject_propertyNameChangedConnect(
     SecondObjectCxxQtSignalHandlerpropertyNameChanged closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &second_object::SecondObject::propertyNameChanged,
     &self,
@@ -390,7 +390,7 @@ SecondObject_readyConnect(
   ::second_object::rust::cxxqtgen1::SecondObjectCxxQtSignalHandlerready closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &second_object::SecondObject::ready,
     &self,
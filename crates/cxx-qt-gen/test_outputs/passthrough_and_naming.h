@@ -1,6 +1,7 @@
 #pragma once
 
 #include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -117,8 +118,11 @@ public:
   virtual ~MyObject() = default;
 
 public:
+  /// Getter for the Q_PROPERTY propertyName
   ::std::int32_t const& getPropertyName() const noexcept;
+  /// Setter for the Q_PROPERTY propertyName
   Q_SLOT void setPropertyName(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY propertyName
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokable_name() noexcept;
   Q_SIGNAL void ready();
@@ -168,8 +172,11 @@ public:
   virtual ~SecondObject() = default;
 
 public:
+  /// Getter for the Q_PROPERTY propertyName
   ::std::int32_t const& getPropertyName() const noexcept;
+  /// Setter for the Q_PROPERTY propertyName
   Q_SLOT void setPropertyName(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY propertyName
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokableName() noexcept;
   void myRenamedFunction(::std::int32_t param) const noexcept;
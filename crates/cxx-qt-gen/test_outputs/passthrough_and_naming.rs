@@ -98,7 +98,7 @@ pub mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "propertyNameChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY propertyName"]
         #[namespace = "cxx_qt::multi_object"]
         fn property_name_changed(self: Pin<&mut MyObject>);
     }
@@ -219,7 +219,7 @@ pub mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "propertyNameChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY propertyName"]
         #[namespace = "second_object"]
         fn property_name_changed(self: Pin<&mut SecondObject>);
     }
@@ -533,12 +533,24 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_property_name_changed(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                MyObjectCxxQtSignalClosurepropertyNameChanged,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurepropertyNameChanged,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurepropertyNameChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_property_name_changed(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -601,12 +613,24 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_ready(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosureready,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosureready,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_ready(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -711,12 +735,24 @@ impl ffi::SecondObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::SecondObject_connect_property_name_changed(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                SecondObjectCxxQtSignalClosurepropertyNameChanged,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            SecondObjectCxxQtSignalClosurepropertyNameChanged,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    SecondObjectCxxQtSignalClosurepropertyNameChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::SecondObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::SecondObject_connect_property_name_changed(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -791,12 +827,24 @@ impl ffi::SecondObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::SecondObject_connect_ready(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<SecondObjectCxxQtSignalClosureready>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            SecondObjectCxxQtSignalClosureready,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    SecondObjectCxxQtSignalClosureready,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::SecondObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::SecondObject_connect_ready(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -926,12 +974,24 @@ impl ffi::QPushButton {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QPushButton_connect_clicked(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<QPushButtonCxxQtSignalClosureclicked>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QPushButtonCxxQtSignalClosureclicked,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QPushButtonCxxQtSignalClosureclicked,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QPushButton>, checked: bool| {
+                        (connection_closure.get_mut())(self_value, checked)
+                    },
+                ));
+                ffi::QPushButton_connect_clicked(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -989,7 +1049,25 @@ impl ffi::ExternObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: ExternObject_connect_data_ready (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < ExternObjectCxxQtSignalClosuredataReady > :: new (Box :: new (closure)) , conn_type ,))
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            ExternObjectCxxQtSignalClosuredataReady,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    ExternObjectCxxQtSignalClosuredataReady,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::ExternObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::ExternObject_connect_data_ready(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::ExternObject {
@@ -1042,15 +1120,25 @@ impl ffi::ExternObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(
-            ffi::ExternObject_connect_error_occurred(
-                self,
-                cxx_qt::signalhandler::CxxQtSignalHandler::<
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            ExternObjectCxxQtSignalClosureerrorOccurred,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
                     ExternObjectCxxQtSignalClosureerrorOccurred,
-                >::new(Box::new(closure)),
-                conn_type,
-            ),
-        )
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::ExternObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::ExternObject_connect_error_occurred(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::ExternObject {
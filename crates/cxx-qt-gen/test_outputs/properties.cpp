@@ -45,7 +45,7 @@ MyObject_primitiveChangedConnect(
     MyObjectCxxQtSignalHandlerprimitiveChanged closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::primitiveChanged,
     &self,
@@ -101,7 +101,7 @@ MyObject_trivialChangedConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::trivialChanged,
     &self,
@@ -157,7 +157,7 @@ MyObject_propAutoCxxNameChangedConnect(
     MyObjectCxxQtSignalHandlerpropAutoCxxNameChanged closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::propAutoCxxNameChanged,
     &self,
@@ -215,7 +215,7 @@ MyObject_customFunctionPropChangedConnect(
     MyObjectCxxQtSignalHandlercustomFunctionPropChanged closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::customFunctionPropChanged,
     &self,
@@ -271,7 +271,7 @@ MyObject_renamedPropertyChangedConnect(
     MyObjectCxxQtSignalHandlerrenamedPropertyChanged closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::renamedPropertyChanged,
     &self,
@@ -327,7 +327,7 @@ MyObject_named_prop_2ChangedConnect(
     MyObjectCxxQtSignalHandlernamed_prop_2Changed closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::named_prop_2Changed,
     &self,
@@ -338,6 +338,62 @@ MyObject_named_prop_2ChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalParamssizeChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_sizeChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamssizeChanged*>::
+operator()<cxx_qt::my_object::MyObject&>(cxx_qt::my_object::MyObject& self)
+{
+  call_MyObject_signal_handler_sizeChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MyObjectCxxQtSignalParamssizeChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MyObjectCxxQtSignalParamssizeChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_sizeChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlersizeChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::rust::cxxqt1::qobjectConnect(
+    &self,
+    &cxx_qt::my_object::MyObject::sizeChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::my_object::MyObject&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -383,7 +439,7 @@ MyObject_my_on_changedConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::my_on_changed,
     &self,
@@ -395,6 +451,30 @@ MyObject_my_on_changedConnect(
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
 namespace cxx_qt::my_object {
+::std::int32_t
+MyObject::getSize() const noexcept
+{
+  return m_size.value();
+}
+
+void
+MyObject::setSize(::std::int32_t value) noexcept
+{
+  m_size.setValue(::std::move(value));
+}
+
+QBindable<::std::int32_t>
+MyObject::bindableSize() noexcept
+{
+  return QBindable<::std::int32_t>(&m_size);
+}
+
+void
+MyObject::bindSize(::rust::Fn<::std::int32_t(MyObject const&)> binding) noexcept
+{
+  m_size.setBinding([this, binding]() { return binding(*this); });
+}
+
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
   , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
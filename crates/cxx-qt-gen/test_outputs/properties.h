@@ -1,6 +1,8 @@
 #pragma once
 
+#include <QtCore/QProperty>
 #include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -44,6 +46,11 @@ using MyObjectCxxQtSignalHandlernamed_prop_2Changed =
     struct MyObjectCxxQtSignalParamsnamed_prop_2Changed*>;
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlersizeChanged =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamssizeChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 using MyObjectCxxQtSignalHandlermy_on_changed =
   ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsmy_on_changed*>;
@@ -105,6 +112,15 @@ MyObject_named_prop_2ChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_sizeChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlersizeChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_my_on_changedConnect(
@@ -145,44 +161,95 @@ public:
                setRequiredProp REQUIRED)
   Q_PROPERTY(
     ::std::int32_t finalProp READ getFinalProp WRITE setFinalProp FINAL)
+  Q_PROPERTY(::std::int32_t size READ getSize WRITE setSize NOTIFY sizeChanged
+               BINDABLE bindableSize)
+  Q_PROPERTY(::std::int32_t count READ getCount WRITE setCount RESET resetCount)
 
   virtual ~MyObject() = default;
 
 public:
+  /// Getter for the Q_PROPERTY primitive
   ::std::int32_t const& getPrimitive() const noexcept;
+  /// Setter for the Q_PROPERTY primitive
   Q_SLOT void setPrimitive(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY trivial
   QPoint const& getTrivial() const noexcept;
+  /// Setter for the Q_PROPERTY trivial
   Q_SLOT void setTrivial(QPoint value) noexcept;
+  /// Getter for the Q_PROPERTY propAutoCxxName
   ::std::int32_t const& getPropAutoCxxName() const noexcept;
+  /// Setter for the Q_PROPERTY propAutoCxxName
   Q_SLOT void setPropAutoCxxName(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY readonlyProp
   ::std::int32_t const& getReadonlyProp() const noexcept;
+  /// Getter for the Q_PROPERTY renamedProperty
   ::std::int32_t const& getRenamedProperty() const noexcept;
+  /// Setter for the Q_PROPERTY renamedProperty
   Q_SLOT void setRenamedProperty(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY reusedSignalProp
   ::std::int32_t const& getReusedSignalProp() const noexcept;
+  /// Setter for the Q_PROPERTY reusedSignalProp
   Q_SLOT void setReusedSignalProp(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY named_prop_2
   ::std::int32_t const& getNamed_prop_2() const noexcept;
+  /// Setter for the Q_PROPERTY named_prop_2
   Q_SLOT void setNamed_prop_2(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY customOnChangedProp
   ::std::int32_t const& getCustomOnChangedProp() const noexcept;
+  /// Setter for the Q_PROPERTY customOnChangedProp
   Q_SLOT void setCustomOnChangedProp(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY constProp
   ::std::int32_t const& getConstProp() const noexcept;
+  /// Getter for the Q_PROPERTY resettableProp
   ::std::int32_t const& getResettableProp() const noexcept;
+  /// Setter for the Q_PROPERTY resettableProp
   Q_SLOT void setResettableProp(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY requiredProp
   ::std::int32_t const& getRequiredProp() const noexcept;
+  /// Setter for the Q_PROPERTY requiredProp
   Q_SLOT void setRequiredProp(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY finalProp
   ::std::int32_t const& getFinalProp() const noexcept;
+  /// Setter for the Q_PROPERTY finalProp
   Q_SLOT void setFinalProp(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY size
+  ::std::int32_t getSize() const noexcept;
+  /// Setter for the Q_PROPERTY size
+  Q_SLOT void setSize(::std::int32_t value) noexcept;
+  /// QBindable for the Q_PROPERTY size
+  QBindable<::std::int32_t> bindableSize() noexcept;
+  /// Set a binding on the Q_PROPERTY size
+  void bindSize(::rust::Fn<::std::int32_t(MyObject const&)> binding) noexcept;
+  /// Getter for the Q_PROPERTY count
+  ::std::int32_t const& getCount() const noexcept;
+  /// Setter for the Q_PROPERTY count
+  Q_SLOT void setCount(::std::int32_t value) noexcept;
+  /// Reset for the Q_PROPERTY count
+  Q_SLOT void resetCount() noexcept;
+  /// Notify for the Q_PROPERTY primitive
   Q_SIGNAL void primitiveChanged();
+  /// Notify for the Q_PROPERTY trivial
   Q_SIGNAL void trivialChanged();
+  /// Notify for the Q_PROPERTY propAutoCxxName
   Q_SIGNAL void propAutoCxxNameChanged();
+  /// Notify for the Q_PROPERTY customFunctionProp
   Q_SIGNAL void customFunctionPropChanged();
+  /// Notify for the Q_PROPERTY renamedProperty
   Q_SIGNAL void renamedPropertyChanged();
+  /// Notify for the Q_PROPERTY named_prop_2
   Q_SIGNAL void named_prop_2Changed();
+  /// Notify for the Q_PROPERTY size
+  Q_SIGNAL void sizeChanged();
   ::std::int32_t myGetter() const noexcept;
   void MyCustomSetter(::std::int32_t value) noexcept;
   void myResetFn() noexcept;
   Q_SIGNAL void my_on_changed();
   explicit MyObject(QObject* parent = nullptr);
 
+private:
+  Q_OBJECT_BINDABLE_PROPERTY(
+    MyObject, ::std::int32_t, m_size, &MyObject::sizeChanged)
+
 private:
   template<typename Inner, typename Outer>
   friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
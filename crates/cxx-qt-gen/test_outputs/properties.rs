@@ -147,9 +147,49 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         fn set_final_prop(self: Pin<&mut MyObject>, value: i32);
     }
+    unsafe extern "C++" {
+        #[doc = "Getter for the Q_PROPERTY "]
+        #[doc = "size"]
+        #[cxx_name = "getSize"]
+        #[namespace = "cxx_qt::my_object"]
+        fn size(self: &MyObject) -> i32;
+    }
+    unsafe extern "C++" {
+        #[doc = "Setter for the Q_PROPERTY "]
+        #[doc = "size"]
+        #[doc = ""]
+        #[doc = "This removes any binding which is currently set on the property."]
+        #[cxx_name = "setSize"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_size(self: Pin<&mut MyObject>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[doc = "Set a binding on the Q_PROPERTY "]
+        #[doc = "size"]
+        #[doc = ""]
+        #[doc = "The function is evaluated lazily, any bindable properties it reads are tracked as dependencies."]
+        #[cxx_name = "bindSize"]
+        #[namespace = "cxx_qt::my_object"]
+        fn bind_size(self: Pin<&mut MyObject>, binding: fn(&MyObject) -> i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "getCount"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn count<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setCount"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_count(self: Pin<&mut MyObject>, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "resetCount"]
+        #[namespace = "cxx_qt::my_object"]
+        fn reset_count(self: Pin<&mut MyObject>);
+    }
     unsafe extern "C++" {
         #[cxx_name = "primitiveChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY primitive"]
         #[namespace = "cxx_qt::my_object"]
         fn primitive_changed(self: Pin<&mut MyObject>);
     }
@@ -182,7 +222,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "trivialChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY trivial"]
         #[namespace = "cxx_qt::my_object"]
         fn trivial_changed(self: Pin<&mut MyObject>);
     }
@@ -215,7 +255,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "propAutoCxxNameChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY propAutoCxxName"]
         #[namespace = "cxx_qt::my_object"]
         fn prop_auto_cxx_name_changed(self: Pin<&mut MyObject>);
     }
@@ -249,7 +289,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "customFunctionPropChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY customFunctionProp"]
         #[namespace = "cxx_qt::my_object"]
         fn custom_function_prop_changed(self: Pin<&mut MyObject>);
     }
@@ -283,7 +323,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "renamedPropertyChanged"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY renamedProperty"]
         #[namespace = "cxx_qt::my_object"]
         fn renamed_property_changed(self: Pin<&mut MyObject>);
     }
@@ -317,7 +357,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "named_prop_2Changed"]
-        #[doc = "Notify for the Q_PROPERTY"]
+        #[doc = "Notify for the Q_PROPERTY named_prop_2"]
         #[namespace = "cxx_qt::my_object"]
         fn renamed_property_2_changed(self: Pin<&mut MyObject>);
     }
@@ -349,6 +389,36 @@ mod ffi {
             self_value: Pin<&mut MyObject>,
         );
     }
+    unsafe extern "C++" {
+        #[cxx_name = "sizeChanged"]
+        #[doc = "Notify for the Q_PROPERTY size"]
+        #[namespace = "cxx_qt::my_object"]
+        fn size_changed(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlersizeChanged =
+            cxx_qt::signalhandler::CxxQtSignalHandler<super::MyObjectCxxQtSignalClosuresizeChanged>;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_sizeChangedConnect"]
+        fn MyObject_connect_size_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlersizeChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_sizeChanged(handler: MyObjectCxxQtSignalHandlersizeChanged);
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_sizeChanged(
+            handler: &mut MyObjectCxxQtSignalHandlersizeChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
     extern "Rust" {
         #[cxx_name = "myGetter"]
         #[namespace = "cxx_qt::my_object"]
@@ -633,6 +703,38 @@ impl ffi::MyObject {
         self.as_mut().rust_mut().final_prop = value;
     }
 }
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "count"]
+    pub fn count(&self) -> &i32 {
+        &self.count
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "count"]
+    pub fn set_count(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.count == value {
+            return;
+        }
+        self.as_mut().rust_mut().count = value;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Reset for the Q_PROPERTY "]
+    #[doc = "count"]
+    #[doc = ""]
+    #[doc = "This sets the property back to the value it has in the Default implementation of the Rust struct."]
+    pub fn reset_count(mut self: core::pin::Pin<&mut Self>) {
+        use cxx_qt::CxxQtType;
+        let value = <MyObjectRust as Default>::default().count;
+        if self.count == value {
+            return;
+        }
+        self.as_mut().rust_mut().count = value;
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "primitiveChanged"]
@@ -645,15 +747,25 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(
-            ffi::MyObject_connect_primitive_changed(
-                self,
-                cxx_qt::signalhandler::CxxQtSignalHandler::<
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosureprimitiveChanged,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
                     MyObjectCxxQtSignalClosureprimitiveChanged,
-                >::new(Box::new(closure)),
-                conn_type,
-            ),
-        )
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_primitive_changed(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::MyObject {
@@ -717,7 +829,25 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_trivial_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuretrivialChanged > :: new (Box :: new (closure)) , conn_type ,))
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosuretrivialChanged,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuretrivialChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_trivial_changed(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::MyObject {
@@ -771,12 +901,24 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_prop_auto_cxx_name_changed(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                MyObjectCxxQtSignalClosurepropAutoCxxNameChanged,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurepropAutoCxxNameChanged,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurepropAutoCxxNameChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_prop_auto_cxx_name_changed(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -841,15 +983,25 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(
-            ffi::MyObject_connect_custom_function_prop_changed(
-                self,
-                cxx_qt::signalhandler::CxxQtSignalHandler::<
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurecustomFunctionPropChanged,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
                     MyObjectCxxQtSignalClosurecustomFunctionPropChanged,
-                >::new(Box::new(closure)),
-                conn_type,
-            ),
-        )
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_custom_function_prop_changed(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::MyObject {
@@ -915,12 +1067,24 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_renamed_property_changed(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                MyObjectCxxQtSignalClosurerenamedPropertyChanged,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurerenamedPropertyChanged,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurerenamedPropertyChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_renamed_property_changed(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -985,12 +1149,24 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_renamed_property_2_changed(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<
-                MyObjectCxxQtSignalClosurenamed_prop_2Changed,
-            >::new(Box::new(closure)),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurenamed_prop_2Changed,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurenamed_prop_2Changed,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_renamed_property_2_changed(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -1043,6 +1219,80 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenamed_prop_2Changed>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "sizeChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_size_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosuresizeChanged,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuresizeChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_size_changed(self, handler, conn_type)
+            },
+        ))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "sizeChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_size_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_size_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosuresizeChanged>::new(
+                Box::new(closure),
+            ),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosuresizeChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuresizeChanged {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlersizeChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_sizeChanged;
+fn call_MyObject_signal_handler_sizeChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuresizeChanged>,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuresizeChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuresizeChanged>,
+    [usize; 2]
+);
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "my_on_changed"]
@@ -1053,7 +1303,25 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_my_on_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuremy_on_changed > :: new (Box :: new (closure)) , conn_type ,))
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosuremy_on_changed,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuremy_on_changed,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_my_on_changed(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::MyObject {
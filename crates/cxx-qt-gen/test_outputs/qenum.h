@@ -1,5 +1,6 @@
 #pragma once
 
+#include <QtCore/QFlags>
 #include <QtCore/QObject>
 #include <QtQml/QQmlEngine>
 #include <cstdint>
@@ -38,10 +39,10 @@ Q_ENUM_NS(MyNamespacedEnum)
 
 namespace other_namespace {
 Q_NAMESPACE
-enum class MyOtherNamespacedEnum : ::std::int32_t
+enum class MyOtherNamespacedEnum : ::std::int16_t
 {
-  Variant1 = 0,
-  Variant2 = 1
+  Variant1 = -1,
+  Variant2 = 0
 };
 Q_ENUM_NS(MyOtherNamespacedEnum)
 } // namespace other_namespace
@@ -60,13 +61,21 @@ public:
     A = 0
   };
   Q_ENUM(MyEnum)
-  enum class MyOtherEnum : ::std::int32_t
+  enum class MyOtherEnum : ::std::uint8_t
   {
-    X = 0,
-    Y = 1,
-    Z = 2
+    X = 1,
+    Y = 16,
+    Z = 17
   };
   Q_ENUM(MyOtherEnum)
+  enum class MyFlag : ::std::int32_t
+  {
+    A = 1,
+    B = 2,
+    C = 4
+  };
+  Q_DECLARE_FLAGS(MyFlags, MyFlag)
+  Q_FLAG(MyFlags)
 
   virtual ~MyObject() = default;
 
@@ -96,6 +105,16 @@ namespace my_namespace {
 using MyOtherEnum = ::cxx_qt::my_object::MyObject::MyOtherEnum;
 } // namespace my_namespace
 
+namespace cxx_qt::my_object {
+using MyFlag = ::cxx_qt::my_object::MyObject::MyFlag;
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object {
+using MyFlags = ::cxx_qt::my_object::MyObject::MyFlags;
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_OPERATORS_FOR_FLAGS(::cxx_qt::my_object::MyObject::MyFlags)
+
 Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
 
 namespace cxx_qt::my_object {
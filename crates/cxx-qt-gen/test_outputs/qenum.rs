@@ -124,6 +124,15 @@ mod ffi {
         #[namespace = "my_namespace"]
         type MyOtherEnum = super::cxx_qt_private_qenum_MyOtherEnum::MyOtherEnum;
     }
+    extern "C++" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyFlag = super::cxx_qt_private_qenum_MyFlag::MyFlag;
+    }
+    extern "C++" {
+        #[doc = "[`QFlags`](cxx_qt_lib::QFlags) of [`MyFlag`]"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyFlags = super::cxx_qt_private_qflags_MyFlags::MyFlags;
+    }
     #[repr(i32)]
     #[namespace = "cxx_qt::my_object"]
     enum MyNamespacedEnum {
@@ -135,10 +144,10 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyNamespacedEnum;
     }
-    #[repr(i32)]
+    #[repr(i16)]
     #[namespace = "other_namespace"]
     enum MyOtherNamespacedEnum {
-        Variant1,
+        Variant1 = -1,
         Variant2,
     }
     extern "C++" {
@@ -228,13 +237,13 @@ mod cxx_qt_private_qenum_MyOtherEnum {
     #[repr(transparent)]
     pub struct MyOtherEnum {
         #[allow(missing_docs)]
-        pub repr: i32,
+        pub repr: u8,
     }
     #[allow(non_upper_case_globals)]
     impl MyOtherEnum {
-        pub const X: MyOtherEnum = MyOtherEnum { repr: 0i32 };
-        pub const Y: MyOtherEnum = MyOtherEnum { repr: 1i32 };
-        pub const Z: MyOtherEnum = MyOtherEnum { repr: 2i32 };
+        pub const X: MyOtherEnum = MyOtherEnum { repr: 1u8 };
+        pub const Y: MyOtherEnum = MyOtherEnum { repr: 16u8 };
+        pub const Z: MyOtherEnum = MyOtherEnum { repr: 17u8 };
     }
     #[automatically_derived]
     unsafe impl ::cxx::ExternType for MyOtherEnum {
@@ -242,6 +251,30 @@ mod cxx_qt_private_qenum_MyOtherEnum {
         type Kind = ::cxx::kind::Trivial;
     }
 }
+mod cxx_qt_private_qenum_MyFlag {
+    #[derive(PartialEq, Eq, Clone, Copy)]
+    #[repr(transparent)]
+    pub struct MyFlag {
+        #[allow(missing_docs)]
+        pub repr: i32,
+    }
+    #[allow(non_upper_case_globals)]
+    impl MyFlag {
+        pub const A: MyFlag = MyFlag { repr: 1i32 };
+        pub const B: MyFlag = MyFlag { repr: 2i32 };
+        pub const C: MyFlag = MyFlag { repr: 4i32 };
+    }
+    #[automatically_derived]
+    unsafe impl ::cxx::ExternType for MyFlag {
+        type Id = ::cxx::type_id!("MyFlag");
+        type Kind = ::cxx::kind::Trivial;
+    }
+}
+mod cxx_qt_private_qflags_MyFlags {
+    #[doc = "[`QFlags`](cxx_qt_lib::QFlags) of [`MyFlag`]"]
+    pub type MyFlags = ::cxx_qt_lib::QFlags<super::ffi::MyFlag>;
+    ::cxx_qt_lib::unsafe_impl_qflag!(super::ffi::MyFlag, "cxx_qt::my_object::MyFlags", i32);
+}
 mod cxx_qt_private_qenum_MyRenamedEnum {
     #[derive(PartialEq, Eq, Clone, Copy)]
     #[repr(transparent)]
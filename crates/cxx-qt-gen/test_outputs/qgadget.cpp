@@ -0,0 +1,96 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::my_object {
+Point::Point()
+  : m_rustObj(::cxx_qt::my_object::cxx_qt_Point::createRs())
+{
+}
+
+Point::Point(const Point& other)
+  : m_rustObj(::cxx_qt::my_object::cxx_qt_Point::cloneRs(*other.m_rustObj))
+{
+}
+
+Point&
+Point::operator=(const Point& other)
+{
+  m_rustObj = ::cxx_qt::my_object::cxx_qt_Point::cloneRs(*other.m_rustObj);
+  return *this;
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_Point {
+bool
+qvariantCanConvert(const QVariant& variant)
+{
+  return variant.canConvert<::cxx_qt::my_object::Point>();
+}
+
+} // namespace cxx_qt::my_object::cxx_qt_Point
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalParamspositionChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_positionChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamspositionChanged*>::
+operator()<cxx_qt::my_object::MyObject&>(cxx_qt::my_object::MyObject& self)
+{
+  call_MyObject_signal_handler_positionChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MyObjectCxxQtSignalParamspositionChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MyObjectCxxQtSignalParamspositionChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_positionChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerpositionChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::rust::cxxqt1::qobjectConnect(
+    &self,
+    &cxx_qt::my_object::MyObject::positionChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::my_object::MyObject&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::my_object::cxx_qt_MyObject::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
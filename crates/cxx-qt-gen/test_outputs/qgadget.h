@@ -0,0 +1,119 @@
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+#include <QtQml/QQmlEngine>
+#include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerpositionChanged = ::rust::cxxqt1::SignalHandler<
+  struct MyObjectCxxQtSignalParamspositionChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+class Point;
+
+} // namespace cxx_qt::my_object
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object {
+/// A point in 2D space
+class Point
+{
+  Q_GADGET
+  QML_VALUE_TYPE(point)
+  Q_PROPERTY(::std::int32_t x READ getX WRITE setX)
+  Q_PROPERTY(::std::int32_t y READ getY WRITE setY)
+  Q_PROPERTY(QString label READ getLabel CONSTANT)
+
+public:
+  Point();
+  Point(const Point& other);
+  Point(Point&& other) noexcept = default;
+  ~Point() = default;
+  Point& operator=(const Point& other);
+  Point& operator=(Point&& other) noexcept = default;
+
+  /// Getter for the Q_PROPERTY x
+  ::std::int32_t const& getX() const noexcept;
+  /// Setter for the Q_PROPERTY x
+  void setX(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY y
+  ::std::int32_t const& getY() const noexcept;
+  /// Setter for the Q_PROPERTY y
+  void setY(::std::int32_t value) noexcept;
+  /// Getter for the Q_PROPERTY label
+  QString const& getLabel() const noexcept;
+
+private:
+  ::rust::Box<PointRust> m_rustObj;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace rust {
+template<>
+struct IsRelocatable<::cxx_qt::my_object::Point> : ::std::true_type
+{};
+} // namespace rust
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::Point)
+
+namespace cxx_qt::my_object::cxx_qt_Point {
+bool
+qvariantCanConvert(const QVariant& variant);
+
+} // namespace cxx_qt::my_object::cxx_qt_Point
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_positionChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerpositionChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(cxx_qt::my_object::Point position READ getPosition WRITE
+               setPosition NOTIFY positionChanged)
+
+  virtual ~MyObject() = default;
+
+public:
+  /// Getter for the Q_PROPERTY position
+  cxx_qt::my_object::Point const& getPosition() const noexcept;
+  /// Setter for the Q_PROPERTY position
+  Q_SLOT void setPosition(cxx_qt::my_object::Point value) noexcept;
+  /// Notify for the Q_PROPERTY position
+  Q_SIGNAL void positionChanged();
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,412 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "getPosition"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn position<'a>(self: &'a MyObject) -> &'a Point;
+    }
+    extern "Rust" {
+        #[cxx_name = "setPosition"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_position(self: Pin<&mut MyObject>, value: Point);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "positionChanged"]
+        #[doc = "Notify for the Q_PROPERTY position"]
+        #[namespace = "cxx_qt::my_object"]
+        fn position_changed(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerpositionChanged = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosurepositionChanged,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_positionChangedConnect"]
+        fn MyObject_connect_position_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerpositionChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_positionChanged(
+            handler: MyObjectCxxQtSignalHandlerpositionChanged,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_positionChanged(
+            handler: &mut MyObjectCxxQtSignalHandlerpositionChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcastPtr(thiz: *const MyObject) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcastPtr(base: *const QObject) -> *const MyObject;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        fn create_rs_MyObjectRust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = " A point in 2D space"]
+        #[namespace = "cxx_qt::my_object"]
+        type Point = super::PointCxxQtGadget;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type PointRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_Point"]
+        fn create_rs_PointRust() -> Box<PointRust>;
+        #[cxx_name = "cloneRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_Point"]
+        fn clone_rs_PointRust(value: &PointRust) -> Box<PointRust>;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        #[doc(hidden)]
+        #[cxx_name = "qvariantCanConvert"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_Point"]
+        fn cxx_qt_ffi_Point_qvariantCanConvert(variant: &QVariant) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "qvariantConstruct"]
+        #[namespace = "rust::cxxqtlib1::qvariant"]
+        fn cxx_qt_ffi_Point_qvariantConstruct(value: &Point) -> QVariant;
+        #[doc(hidden)]
+        #[cxx_name = "qvariantValueOrDefault"]
+        #[namespace = "rust::cxxqtlib1::qvariant"]
+        fn cxx_qt_ffi_Point_qvariantValueOrDefault(variant: &QVariant) -> Point;
+    }
+    extern "Rust" {
+        #[cxx_name = "getX"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn x<'a>(self: &'a Point) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setX"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_x(self: &mut Point, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "getY"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn y<'a>(self: &'a Point) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setY"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_y(self: &mut Point, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "getLabel"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn label<'a>(self: &'a Point) -> &'a QString;
+    }
+    unsafe extern "C++" {
+        include ! (< QtQml / QQmlEngine >);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QVariant = ::cxx_qt_lib::QVariant;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "position"]
+    pub fn position(&self) -> &ffi::Point {
+        &self.position
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "position"]
+    pub fn set_position(mut self: core::pin::Pin<&mut Self>, value: ffi::Point) {
+        use cxx_qt::CxxQtType;
+        if self.position == value {
+            return;
+        }
+        self.as_mut().rust_mut().position = value;
+        self.as_mut().position_changed();
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "positionChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_position_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurepositionChanged,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurepositionChanged,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_position_changed(self, handler, conn_type)
+            },
+        ))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "positionChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_position_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MyObject_connect_position_changed(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurepositionChanged,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurepositionChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosurepositionChanged
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerpositionChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_positionChanged;
+fn call_MyObject_signal_handler_positionChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurepositionChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepositionChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepositionChanged>,
+    [usize; 2]
+);
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+    }
+}
+#[doc(hidden)]
+#[repr(transparent)]
+pub struct PointCxxQtGadget {
+    rust: ::std::boxed::Box<PointRust>,
+}
+unsafe impl ::cxx::ExternType for PointCxxQtGadget {
+    type Id = ::cxx::type_id!("cxx_qt::my_object::Point");
+    type Kind = ::cxx::kind::Trivial;
+}
+impl ::core::ops::Deref for PointCxxQtGadget {
+    type Target = PointRust;
+    fn deref(&self) -> &Self::Target {
+        &self.rust
+    }
+}
+impl ::core::ops::DerefMut for PointCxxQtGadget {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rust
+    }
+}
+impl ::core::default::Default for PointCxxQtGadget {
+    fn default() -> Self {
+        Self {
+            rust: create_rs_PointRust(),
+        }
+    }
+}
+impl ::core::clone::Clone for PointCxxQtGadget {
+    fn clone(&self) -> Self {
+        Self {
+            rust: clone_rs_PointRust(&self.rust),
+        }
+    }
+}
+impl ::core::convert::From<PointRust> for PointCxxQtGadget {
+    fn from(value: PointRust) -> Self {
+        Self {
+            rust: ::std::boxed::Box::new(value),
+        }
+    }
+}
+impl ::cxx_qt_lib::QVariantValue for PointCxxQtGadget {
+    fn can_convert(variant: &::cxx_qt_lib::QVariant) -> bool {
+        ffi::cxx_qt_ffi_Point_qvariantCanConvert(variant)
+    }
+    fn construct(value: &Self) -> ::cxx_qt_lib::QVariant {
+        ffi::cxx_qt_ffi_Point_qvariantConstruct(value)
+    }
+    fn value_or_default(variant: &::cxx_qt_lib::QVariant) -> Self {
+        ffi::cxx_qt_ffi_Point_qvariantValueOrDefault(variant)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_PointRust() -> ::std::boxed::Box<PointRust> {
+    ::std::boxed::Box::new(::core::default::Default::default())
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn clone_rs_PointRust(value: &PointRust) -> ::std::boxed::Box<PointRust> {
+    ::std::boxed::Box::new(::core::clone::Clone::clone(value))
+}
+impl ::core::cmp::PartialEq for PointCxxQtGadget {
+    fn eq(&self, other: &Self) -> bool {
+        *self.rust == *other.rust
+    }
+}
+impl ffi::Point {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "x"]
+    pub fn x(&self) -> &i32 {
+        &self.x
+    }
+}
+impl ffi::Point {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "x"]
+    pub fn set_x(&mut self, value: i32) {
+        self.x = value;
+    }
+}
+impl ffi::Point {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "y"]
+    pub fn y(&self) -> &i32 {
+        &self.y
+    }
+}
+impl ffi::Point {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "y"]
+    pub fn set_y(&mut self, value: i32) {
+        self.y = value;
+    }
+}
+impl ffi::Point {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "label"]
+    pub fn label(&self) -> &ffi::QString {
+        &self.label
+    }
+}
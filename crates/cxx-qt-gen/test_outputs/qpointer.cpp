@@ -0,0 +1,209 @@
+#include "directory/file_ident.cxxqt.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalParamsitemChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_itemChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsitemChanged*>::
+operator()<cxx_qt::my_object::MyObject&>(cxx_qt::my_object::MyObject& self)
+{
+  call_MyObject_signal_handler_itemChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MyObjectCxxQtSignalParamsitemChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MyObjectCxxQtSignalParamsitemChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_itemChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandleritemChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::rust::cxxqt1::qobjectConnect(
+    &self,
+    &cxx_qt::my_object::MyObject::itemChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::my_object::MyObject&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalParamsotherChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_otherChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsotherChanged*>::
+operator()<cxx_qt::my_object::MyObject&>(cxx_qt::my_object::MyObject& self)
+{
+  call_MyObject_signal_handler_otherChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MyObjectCxxQtSignalParamsotherChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MyObjectCxxQtSignalParamsotherChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_otherChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerotherChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::rust::cxxqt1::qobjectConnect(
+    &self,
+    &cxx_qt::my_object::MyObject::otherChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::my_object::MyObject&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+QQuickItem*
+MyObject::getItem() const noexcept
+{
+  return m_item.data();
+}
+
+void
+MyObject::setItem(QQuickItem* value) noexcept
+{
+  if (m_item == value) {
+    return;
+  }
+
+  QObject::disconnect(m_itemDestroyedConnection);
+  m_item = value;
+  if (!m_item.isNull()) {
+    m_itemDestroyedConnection =
+      QObject::connect(m_item.data(), &QObject::destroyed, this, [this]() {
+        Q_EMIT itemChanged();
+      });
+  }
+  Q_EMIT itemChanged();
+}
+
+QQuickItem*
+MyObject::getOther() const noexcept
+{
+  return m_other.data();
+}
+
+void
+MyObject::setOther(QQuickItem* value) noexcept
+{
+  if (m_other == value) {
+    return;
+  }
+
+  QObject::disconnect(m_otherDestroyedConnection);
+  m_other = value;
+  if (!m_other.isNull()) {
+    m_otherDestroyedConnection =
+      QObject::connect(m_other.data(), &QObject::destroyed, this, [this]() {
+        Q_EMIT otherChanged();
+      });
+  }
+  Q_EMIT otherChanged();
+}
+
+void
+MyObject::resetOther() noexcept
+{
+  if (m_other.isNull()) {
+    return;
+  }
+
+  QObject::disconnect(m_otherDestroyedConnection);
+  m_other.clear();
+  Q_EMIT otherChanged();
+}
+
+QQuickItem*
+MyObject::getPlain() const noexcept
+{
+  return m_plain.data();
+}
+
+void
+MyObject::setPlain(QQuickItem* value) noexcept
+{
+  if (m_plain == value) {
+    return;
+  }
+
+  m_plain = value;
+}
+
+void
+MyObject::resetPlain() noexcept
+{
+  if (m_plain.isNull()) {
+    return;
+  }
+
+  m_plain.clear();
+}
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::my_object::cxx_qt_MyObject::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
@@ -0,0 +1,100 @@
+#pragma once
+
+#include <QtCore/QPointer>
+#include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandleritemChanged =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsitemChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerotherChanged =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsotherChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_itemChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandleritemChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_otherChangedConnect(
+  cxx_qt::my_object::MyObject& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerotherChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(QQuickItem* item READ getItem WRITE setItem NOTIFY itemChanged)
+  Q_PROPERTY(QQuickItem* other READ getOther WRITE setOther NOTIFY otherChanged
+               RESET resetOther)
+  Q_PROPERTY(QQuickItem* plain READ getPlain WRITE setPlain RESET resetPlain)
+
+  virtual ~MyObject() = default;
+
+public:
+  /// Getter for the Q_PROPERTY item
+  QQuickItem* getItem() const noexcept;
+  /// Setter for the Q_PROPERTY item
+  Q_SLOT void setItem(QQuickItem* value) noexcept;
+  /// Getter for the Q_PROPERTY other
+  QQuickItem* getOther() const noexcept;
+  /// Setter for the Q_PROPERTY other
+  Q_SLOT void setOther(QQuickItem* value) noexcept;
+  /// Reset for the Q_PROPERTY other
+  Q_SLOT void resetOther() noexcept;
+  /// Getter for the Q_PROPERTY plain
+  QQuickItem* getPlain() const noexcept;
+  /// Setter for the Q_PROPERTY plain
+  Q_SLOT void setPlain(QQuickItem* value) noexcept;
+  /// Reset for the Q_PROPERTY plain
+  Q_SLOT void resetPlain() noexcept;
+  /// Notify for the Q_PROPERTY item
+  Q_SIGNAL void itemChanged();
+  /// Notify for the Q_PROPERTY other
+  Q_SIGNAL void otherChanged();
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  ::QMetaObject::Connection m_itemDestroyedConnection;
+  QPointer<QQuickItem> m_item;
+  ::QMetaObject::Connection m_otherDestroyedConnection;
+  QPointer<QQuickItem> m_other;
+  QPointer<QQuickItem> m_plain;
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
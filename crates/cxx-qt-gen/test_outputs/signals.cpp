@@ -45,7 +45,7 @@ QTimer_timeoutConnect(
   ::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalHandlertimeout closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::QTimer::timeout,
     &self,
@@ -99,7 +99,7 @@ QTimer_const_readyConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::QTimer::const_ready,
     &self,
@@ -155,7 +155,7 @@ MyObject_readyConnect(
   ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerready closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::ready,
     &self,
@@ -223,7 +223,7 @@ MyObject_data_changedConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::data_changed,
     &self,
@@ -300,7 +300,7 @@ MyObject_newDataConnect(
     closure,
   ::Qt::ConnectionType type)
 {
-  return ::QObject::connect(
+  return ::rust::cxxqt1::qobjectConnect(
     &self,
     &cxx_qt::my_object::MyObject::newData,
     &self,
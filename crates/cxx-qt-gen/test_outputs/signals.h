@@ -1,6 +1,7 @@
 #pragma once
 
 #include <cxx-qt/casting.h>
+#include <cxx-qt/connection.h>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -269,12 +269,24 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_ready(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosureready,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosureready,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::MyObject_connect_ready(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -320,6 +332,22 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Returns a stream of the arguments of each emission of the signal "]
+    #[doc = "ready"]
+    #[doc = ", the signal is disconnected when the stream is dropped."]
+    #[allow(dead_code)]
+    pub fn ready_stream(self: core::pin::Pin<&mut ffi::MyObject>) -> cxx_qt::QSignalStream<()> {
+        cxx_qt::QSignalStream::connect(|sender| self.on_ready(move |_| sender.send(())))
+    }
+    #[doc = "Returns a future which resolves to the arguments of the next emission of the signal "]
+    #[doc = "ready"]
+    #[doc = ", or to None if the signal is disconnected first."]
+    #[allow(dead_code)]
+    pub fn ready_once(self: core::pin::Pin<&mut ffi::MyObject>) -> cxx_qt::QSignalOnce<()> {
+        self.ready_stream().once()
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "data_changed"]
@@ -340,7 +368,29 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_data_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuredata_changed > :: new (Box :: new (closure)) , conn_type ,))
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosuredata_changed,
+            F,
+        >(
+            sender,
+            closure,
+            conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosuredata_changed,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>,
+                          first: i32,
+                          second: cxx::UniquePtr<ffi::Opaque>,
+                          third: ffi::QPoint,
+                          fourth: &ffi::QPoint| {
+                        (connection_closure.get_mut())(self_value, first, second, third, fourth)
+                    },
+                ));
+                ffi::MyObject_connect_data_changed(self, handler, conn_type)
+            },
+        ))
     }
 }
 impl ffi::MyObject {
@@ -420,12 +470,28 @@ impl ffi::MyObject {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_base_class_new_data(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosurenewData>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            MyObjectCxxQtSignalClosurenewData,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurenewData,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::MyObject>,
+                          first: i32,
+                          second: cxx::UniquePtr<ffi::Opaque>,
+                          third: ffi::QPoint,
+                          fourth: &'a ffi::QPoint| {
+                        (connection_closure.get_mut())(self_value, first, second, third, fourth)
+                    },
+                ));
+                ffi::MyObject_connect_base_class_new_data(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -537,12 +603,24 @@ impl ffi::QTimer {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QTimer_connect_timeout(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<QTimerCxxQtSignalClosuretimeout>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QTimerCxxQtSignalClosuretimeout,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QTimerCxxQtSignalClosuretimeout,
+                >::new(Box::new(
+                    move |self_value: core::pin::Pin<&mut ffi::QTimer>| {
+                        (connection_closure.get_mut())(self_value)
+                    },
+                ));
+                ffi::QTimer_connect_timeout(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -588,6 +666,22 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<QTimerCxxQtSignalClosuretimeout>,
     [usize; 2]
 );
+impl ffi::QTimer {
+    #[doc = "Returns a stream of the arguments of each emission of the signal "]
+    #[doc = "timeout"]
+    #[doc = ", the signal is disconnected when the stream is dropped."]
+    #[allow(dead_code)]
+    pub fn timeout_stream(self: core::pin::Pin<&mut ffi::QTimer>) -> cxx_qt::QSignalStream<()> {
+        cxx_qt::QSignalStream::connect(|sender| self.on_timeout(move |_| sender.send(())))
+    }
+    #[doc = "Returns a future which resolves to the arguments of the next emission of the signal "]
+    #[doc = "timeout"]
+    #[doc = ", or to None if the signal is disconnected first."]
+    #[allow(dead_code)]
+    pub fn timeout_once(self: core::pin::Pin<&mut ffi::QTimer>) -> cxx_qt::QSignalOnce<()> {
+        self.timeout_stream().once()
+    }
+}
 impl ffi::QTimer {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "const_ready"]
@@ -598,12 +692,22 @@ impl ffi::QTimer {
         closure: F,
         conn_type: cxx_qt::ConnectionType,
     ) -> cxx_qt::QMetaObjectConnectionGuard {
-        cxx_qt::QMetaObjectConnectionGuard::from(ffi::QTimer_connect_const_ready(
-            self,
-            cxx_qt::signalhandler::CxxQtSignalHandler::<QTimerCxxQtSignalClosureconst_ready>::new(
-                Box::new(closure),
-            ),
+        let sender = &*self as *const Self as *const core::ffi::c_void;
+        cxx_qt::QMetaObjectConnectionGuard::from(cxx_qt::signalhandler::connect::<
+            QTimerCxxQtSignalClosureconst_ready,
+            F,
+        >(
+            sender,
+            closure,
             conn_type,
+            |conn_type, mut connection_closure| {
+                let handler = cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QTimerCxxQtSignalClosureconst_ready,
+                >::new(Box::new(move |self_value: &ffi::QTimer| {
+                    (connection_closure.get_mut())(self_value)
+                }));
+                ffi::QTimer_connect_const_ready(self, handler, conn_type)
+            },
         ))
     }
 }
@@ -0,0 +1,175 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::my_object {
+QModelIndex
+FileTree::index(int row, int column, const QModelIndex& parent) const
+{
+  if (!hasIndex(row, column, parent)) {
+    return QModelIndex();
+  }
+  const auto id = cxxQtTreeModelChild(
+    static_cast<::std::size_t>(parent.internalId()), row);
+  if (id == 0) {
+    return QModelIndex();
+  }
+  return createIndex(row, column, static_cast<quintptr>(id));
+}
+
+QModelIndex
+FileTree::parent(const QModelIndex& index) const
+{
+  if (!index.isValid()) {
+    return QModelIndex();
+  }
+  return cxxQtTreeModelIndex(
+    cxxQtTreeModelParent(static_cast<::std::size_t>(index.internalId())), 0);
+}
+
+int
+FileTree::rowCount(const QModelIndex& parent) const
+{
+  if (parent.column() > 0) {
+    return 0;
+  }
+  return cxxQtTreeModelRowCount(
+    static_cast<::std::size_t>(parent.internalId()));
+}
+
+int
+FileTree::columnCount(const QModelIndex& parent) const
+{
+  Q_UNUSED(parent);
+  return cxxQtTreeModelColumnCount();
+}
+
+QVariant
+FileTree::data(const QModelIndex& index, int role) const
+{
+  if (!index.isValid()) {
+    return QVariant();
+  }
+  return cxxQtTreeModelData(
+    static_cast<::std::size_t>(index.internalId()), index.column(), role);
+}
+
+bool
+FileTree::setData(const QModelIndex& index, const QVariant& value, int role)
+{
+  if (!index.isValid() ||
+      !cxxQtTreeModelSetData(static_cast<::std::size_t>(index.internalId()),
+                             index.column(),
+                             role,
+                             value)) {
+    return false;
+  }
+  Q_EMIT dataChanged(index, index);
+  return true;
+}
+
+Qt::ItemFlags
+FileTree::flags(const QModelIndex& index) const
+{
+  if (!index.isValid()) {
+    return QAbstractItemModel::flags(index);
+  }
+  return Qt::ItemFlags(QFlag(cxxQtTreeModelFlags(
+    static_cast<::std::size_t>(index.internalId()), index.column())));
+}
+
+QHash<int, QByteArray>
+FileTree::roleNames() const
+{
+  auto roles = QAbstractItemModel::roleNames();
+  const auto names = cxxQtTreeModelRoleNames();
+  for (::std::size_t i = 0; i < names.size(); ++i) {
+    roles.insert(Qt::UserRole + static_cast<int>(i),
+                 QByteArray(names[i].data(),
+                            static_cast<int>(names[i].size())));
+  }
+  return roles;
+}
+
+QModelIndex
+FileTree::cxxQtTreeModelIndex(::std::size_t id, ::std::int32_t column) const
+{
+  if (id == 0) {
+    return QModelIndex();
+  }
+  return createIndex(cxxQtTreeModelRow(id), column, static_cast<quintptr>(id));
+}
+
+void
+FileTree::cxxQtTreeModelBeginInsertRows(::std::size_t parent,
+                                        ::std::int32_t first,
+                                        ::std::int32_t last)
+{
+  beginInsertRows(cxxQtTreeModelIndex(parent, 0), first, last);
+}
+
+void
+FileTree::cxxQtTreeModelEndInsertRows()
+{
+  endInsertRows();
+}
+
+void
+FileTree::cxxQtTreeModelBeginRemoveRows(::std::size_t parent,
+                                        ::std::int32_t first,
+                                        ::std::int32_t last)
+{
+  beginRemoveRows(cxxQtTreeModelIndex(parent, 0), first, last);
+}
+
+void
+FileTree::cxxQtTreeModelEndRemoveRows()
+{
+  endRemoveRows();
+}
+
+bool
+FileTree::cxxQtTreeModelBeginMoveRows(::std::size_t sourceParent,
+                                      ::std::int32_t first,
+                                      ::std::int32_t last,
+                                      ::std::size_t destinationParent,
+                                      ::std::int32_t destination)
+{
+  return beginMoveRows(cxxQtTreeModelIndex(sourceParent, 0),
+                       first,
+                       last,
+                       cxxQtTreeModelIndex(destinationParent, 0),
+                       destination);
+}
+
+void
+FileTree::cxxQtTreeModelEndMoveRows()
+{
+  endMoveRows();
+}
+
+void
+FileTree::cxxQtTreeModelBeginResetModel()
+{
+  beginResetModel();
+}
+
+void
+FileTree::cxxQtTreeModelEndResetModel()
+{
+  endResetModel();
+}
+
+void
+FileTree::cxxQtTreeModelNodeChanged(::std::size_t id)
+{
+  Q_EMIT dataChanged(cxxQtTreeModelIndex(id, 0),
+                     cxxQtTreeModelIndex(id, columnCount() - 1));
+}
+
+FileTree::FileTree(QObject* parent)
+  : QAbstractItemModel(parent)
+  , ::rust::cxxqt1::CxxQtType<FileTreeRust>(
+      ::cxx_qt::my_object::cxx_qt_FileTree::createRs())
+{
+}
+
+} // namespace cxx_qt::my_object
@@ -0,0 +1,92 @@
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QModelIndex>
+#include <QtCore/QVariant>
+#include <cxx-qt/casting.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class FileTree;
+
+} // namespace cxx_qt::my_object
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object {
+class FileTree
+  : public QAbstractItemModel
+  , private ::rust::cxxqt1::CxxQtType<FileTreeRust>
+{
+  Q_OBJECT
+public:
+  virtual ~FileTree() = default;
+
+public:
+  using QObject::parent;
+  QModelIndex index(int row,
+                    int column,
+                    const QModelIndex& parent = QModelIndex()) const override;
+  QModelIndex parent(const QModelIndex& index) const override;
+  int rowCount(const QModelIndex& parent = QModelIndex()) const override;
+  int columnCount(const QModelIndex& parent = QModelIndex()) const override;
+  QVariant data(const QModelIndex& index,
+                int role = Qt::DisplayRole) const override;
+  bool setData(const QModelIndex& index,
+               const QVariant& value,
+               int role = Qt::EditRole) override;
+  Qt::ItemFlags flags(const QModelIndex& index) const override;
+  QHash<int, QByteArray> roleNames() const override;
+  QModelIndex cxxQtTreeModelIndex(::std::size_t id,
+                                  ::std::int32_t column) const;
+  void cxxQtTreeModelBeginInsertRows(::std::size_t parent,
+                                     ::std::int32_t first,
+                                     ::std::int32_t last);
+  void cxxQtTreeModelEndInsertRows();
+  void cxxQtTreeModelBeginRemoveRows(::std::size_t parent,
+                                     ::std::int32_t first,
+                                     ::std::int32_t last);
+  void cxxQtTreeModelEndRemoveRows();
+  bool cxxQtTreeModelBeginMoveRows(::std::size_t sourceParent,
+                                   ::std::int32_t first,
+                                   ::std::int32_t last,
+                                   ::std::size_t destinationParent,
+                                   ::std::int32_t destination);
+  void cxxQtTreeModelEndMoveRows();
+  void cxxQtTreeModelBeginResetModel();
+  void cxxQtTreeModelEndResetModel();
+  void cxxQtTreeModelNodeChanged(::std::size_t id);
+  explicit FileTree(QObject* parent = nullptr);
+
+private:
+  ::std::size_t cxxQtTreeModelChild(::std::size_t parent,
+                                    ::std::int32_t row) const noexcept;
+  ::std::size_t cxxQtTreeModelParent(::std::size_t id) const noexcept;
+  ::std::int32_t cxxQtTreeModelRow(::std::size_t id) const noexcept;
+  ::std::int32_t cxxQtTreeModelRowCount(::std::size_t parent) const noexcept;
+  ::std::int32_t cxxQtTreeModelColumnCount() const noexcept;
+  QVariant cxxQtTreeModelData(::std::size_t id,
+                              ::std::int32_t column,
+                              ::std::int32_t role) const noexcept;
+  bool cxxQtTreeModelSetData(::std::size_t id,
+                             ::std::int32_t column,
+                             ::std::int32_t role,
+                             QVariant const& value) noexcept;
+  ::std::int32_t cxxQtTreeModelFlags(::std::size_t id,
+                                     ::std::int32_t column) const noexcept;
+  ::rust::Vec<::rust::String> cxxQtTreeModelRoleNames() const noexcept;
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, FileTree>::value,
+              "FileTree must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::FileTree*)
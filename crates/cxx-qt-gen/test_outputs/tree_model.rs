@@ -0,0 +1,316 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+#[allow(unused_unsafe)]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QAbstractItemModel >);
+        #[namespace = ""]
+        type QAbstractItemModel;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "FileTreeRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type FileTree;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type FileTreeRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtTreeModelChild"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_child(self: &FileTree, parent: usize, row: i32) -> usize;
+        #[cxx_name = "cxxQtTreeModelParent"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_parent(self: &FileTree, id: usize) -> usize;
+        #[cxx_name = "cxxQtTreeModelRow"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_row(self: &FileTree, id: usize) -> i32;
+        #[cxx_name = "cxxQtTreeModelRowCount"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_row_count(self: &FileTree, parent: usize) -> i32;
+        #[cxx_name = "cxxQtTreeModelColumnCount"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_column_count(self: &FileTree) -> i32;
+        #[cxx_name = "cxxQtTreeModelData"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_data(self: &FileTree, id: usize, column: i32, role: i32) -> QVariant;
+        #[cxx_name = "cxxQtTreeModelSetData"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_set_data(
+            self: Pin<&mut FileTree>,
+            id: usize,
+            column: i32,
+            role: i32,
+            value: &QVariant,
+        ) -> bool;
+        #[cxx_name = "cxxQtTreeModelFlags"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_flags(self: &FileTree, id: usize, column: i32) -> i32;
+        #[cxx_name = "cxxQtTreeModelRoleNames"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_role_names(self: &FileTree) -> Vec<String>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelIndex"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_index(self: &FileTree, id: usize, column: i32) -> QModelIndex;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelBeginInsertRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_begin_insert_rows(
+            self: Pin<&mut FileTree>,
+            parent: usize,
+            first: i32,
+            last: i32,
+        );
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelEndInsertRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_end_insert_rows(self: Pin<&mut FileTree>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelBeginRemoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_begin_remove_rows(
+            self: Pin<&mut FileTree>,
+            parent: usize,
+            first: i32,
+            last: i32,
+        );
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelEndRemoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_end_remove_rows(self: Pin<&mut FileTree>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelBeginMoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_begin_move_rows(
+            self: Pin<&mut FileTree>,
+            source_parent: usize,
+            first: i32,
+            last: i32,
+            destination_parent: usize,
+            destination: i32,
+        ) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelEndMoveRows"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_end_move_rows(self: Pin<&mut FileTree>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelBeginResetModel"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_begin_reset_model(self: Pin<&mut FileTree>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelEndResetModel"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_end_reset_model(self: Pin<&mut FileTree>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtTreeModelNodeChanged"]
+        #[namespace = "cxx_qt::my_object"]
+        fn cxx_qt_tree_model_node_changed(self: Pin<&mut FileTree>, id: usize);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_FileTree_upcastPtr(thiz: *const FileTree)
+            -> *const QAbstractItemModel;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_FileTree_downcastPtr(
+            base: *const QAbstractItemModel,
+        ) -> *const FileTree;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_FileTree"]
+        fn create_rs_FileTreeRust() -> Box<FileTreeRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FileTree_unsafeRust(outer: &FileTree) -> &FileTreeRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FileTree_unsafeRustMut(outer: Pin<&mut FileTree>) -> Pin<&mut FileTreeRust>;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QVariant = ::cxx_qt_lib::QVariant;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QModelIndex = ::cxx_qt_lib::QModelIndex;
+    }
+}
+impl ffi::FileTree {
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_child(&self, parent: usize, row: i32) -> usize {
+        ::cxx_qt_lib::TreeModel::cxx_qt_child(self, parent, row)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_parent(&self, id: usize) -> usize {
+        ::cxx_qt_lib::TreeModel::cxx_qt_parent(self, id)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_row(&self, id: usize) -> i32 {
+        ::cxx_qt_lib::TreeModel::cxx_qt_row(self, id)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_row_count(&self, parent: usize) -> i32 {
+        ::cxx_qt_lib::TreeModel::cxx_qt_row_count(self, parent)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_column_count(&self) -> i32 {
+        ::cxx_qt_lib::TreeModel::cxx_qt_column_count(self)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_data(
+        &self,
+        id: usize,
+        column: i32,
+        role: i32,
+    ) -> ::cxx_qt_lib::QVariant {
+        ::cxx_qt_lib::TreeModel::cxx_qt_data(self, id, column, role)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_set_data(
+        self: core::pin::Pin<&mut Self>,
+        id: usize,
+        column: i32,
+        role: i32,
+        value: &::cxx_qt_lib::QVariant,
+    ) -> bool {
+        ::cxx_qt_lib::TreeModel::cxx_qt_set_data(self, id, column, role, value)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_flags(&self, id: usize, column: i32) -> i32 {
+        ::cxx_qt_lib::TreeModel::cxx_qt_flags(self, id, column)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_tree_model_role_names(&self) -> ::std::vec::Vec<::std::string::String> {
+        <Self as ::cxx_qt_lib::TreeModel>::cxx_qt_role_names()
+    }
+}
+impl ::cxx_qt_lib::TreeModel for ffi::FileTree {
+    type Item = FileItem;
+    fn cxx_qt_tree(&self) -> &::cxx_qt_lib::Tree<Self::Item> {
+        &self.files
+    }
+    fn cxx_qt_tree_mut(self: core::pin::Pin<&mut Self>) -> &mut ::cxx_qt_lib::Tree<Self::Item> {
+        use cxx_qt::CxxQtType;
+        &mut self.rust_mut().get_mut().files
+    }
+    fn cxx_qt_create_index(&self, id: usize, column: i32) -> ::cxx_qt_lib::QModelIndex {
+        self.cxx_qt_tree_model_index(id, column)
+    }
+    fn cxx_qt_begin_insert_rows(
+        self: core::pin::Pin<&mut Self>,
+        parent: usize,
+        first: i32,
+        last: i32,
+    ) {
+        self.cxx_qt_tree_model_begin_insert_rows(parent, first, last);
+    }
+    fn cxx_qt_end_insert_rows(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_tree_model_end_insert_rows();
+    }
+    fn cxx_qt_begin_remove_rows(
+        self: core::pin::Pin<&mut Self>,
+        parent: usize,
+        first: i32,
+        last: i32,
+    ) {
+        self.cxx_qt_tree_model_begin_remove_rows(parent, first, last);
+    }
+    fn cxx_qt_end_remove_rows(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_tree_model_end_remove_rows();
+    }
+    fn cxx_qt_begin_move_rows(
+        self: core::pin::Pin<&mut Self>,
+        source_parent: usize,
+        first: i32,
+        last: i32,
+        destination_parent: usize,
+        destination: i32,
+    ) -> bool {
+        self.cxx_qt_tree_model_begin_move_rows(
+            source_parent,
+            first,
+            last,
+            destination_parent,
+            destination,
+        )
+    }
+    fn cxx_qt_end_move_rows(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_tree_model_end_move_rows();
+    }
+    fn cxx_qt_begin_reset_model(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_tree_model_begin_reset_model();
+    }
+    fn cxx_qt_end_reset_model(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_tree_model_end_reset_model();
+    }
+    fn cxx_qt_node_changed(self: core::pin::Pin<&mut Self>, id: usize) {
+        self.cxx_qt_tree_model_node_changed(id);
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<ffi::QAbstractItemModel> for ffi::FileTree {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::QAbstractItemModel {
+        ffi::cxx_qt_ffi_FileTree_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ffi::QAbstractItemModel) -> *const Self {
+        ffi::cxx_qt_ffi_FileTree_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_FileTreeRust() -> std::boxed::Box<FileTreeRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::FileTree {
+    type Target = FileTreeRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_FileTree_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::FileTree {
+    type Rust = FileTreeRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_FileTree_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_FileTree_unsafeRustMut(self)
+    }
+}
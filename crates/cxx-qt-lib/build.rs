@@ -61,6 +61,7 @@ fn main() {
     };
 
     let mut rust_bridges = vec![
+        "core/qabstractitemmodel",
         "core/qbytearray",
         "core/qcoreapplication",
         "core/qdate",
@@ -100,6 +101,11 @@ fn main() {
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaclassinfo",
+        "core/qmetaenum",
+        "core/qmetamethod",
+        "core/qmetaobject",
+        "core/qmetaproperty",
         "core/qmodelindex",
         "core/qobject",
         "core/qpersistentmodelindex",
@@ -127,9 +133,11 @@ fn main() {
         "core/qset/qset_u64",
         "core/qsize",
         "core/qsizef",
+        "core/qsortfilterproxymodel",
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qthread",
         "core/qtime",
         "core/qtlogging",
         "core/qtypes",
@@ -270,7 +278,9 @@ fn main() {
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaobject",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
@@ -281,6 +291,7 @@ fn main() {
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qthread",
         "core/qtime",
         "core/qtlogging",
         "core/qtypes",
@@ -0,0 +1,87 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QMetaMethodAccess = QMetaMethod::Access;
+using QMetaMethodType = QMetaMethod::MethodType;
+
+QString
+qmetaclassinfoName(const QMetaClassInfo& classInfo);
+QString
+qmetaclassinfoValue(const QMetaClassInfo& classInfo);
+
+QString
+qmetaenumEnumName(const QMetaEnum& metaEnum);
+QString
+qmetaenumKey(const QMetaEnum& metaEnum, ::std::int32_t index);
+::std::int32_t
+qmetaenumKeyToValue(const QMetaEnum& metaEnum, ::rust::Str key, bool& ok);
+::std::int32_t
+qmetaenumKeysToValue(const QMetaEnum& metaEnum, ::rust::Str keys, bool& ok);
+QString
+qmetaenumName(const QMetaEnum& metaEnum);
+QString
+qmetaenumScope(const QMetaEnum& metaEnum);
+QString
+qmetaenumValueToKey(const QMetaEnum& metaEnum, ::std::int32_t value);
+
+bool
+qmetamethodInvoke(const QMetaMethod& method,
+                  QObject& object,
+                  const QList<QVariant>& arguments,
+                  ::Qt::ConnectionType type,
+                  QVariant& returnValue);
+QString
+qmetamethodTypeName(const QMetaMethod& method);
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject);
+::std::int32_t
+qmetaobjectIndexOfClassInfo(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfEnumerator(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfMethod(const QMetaObject& metaObject, ::rust::Str method);
+::std::int32_t
+qmetaobjectIndexOfProperty(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfSignal(const QMetaObject& metaObject, ::rust::Str signal);
+::std::int32_t
+qmetaobjectIndexOfSlot(const QMetaObject& metaObject, ::rust::Str slot);
+bool
+qmetaobjectInherits(const QMetaObject& metaObject,
+                    const QMetaObject& superClass);
+
+QString
+qmetapropertyName(const QMetaProperty& property);
+QVariant
+qmetapropertyRead(const QMetaProperty& property, const QObject& object);
+bool
+qmetapropertyReset(const QMetaProperty& property, QObject& object);
+QString
+qmetapropertyTypeName(const QMetaProperty& property);
+bool
+qmetapropertyWrite(const QMetaProperty& property,
+                   QObject& object,
+                   const QVariant& value);
+
+}
+}
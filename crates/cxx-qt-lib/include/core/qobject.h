@@ -6,4 +6,34 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
-#include <QtCore/QObject>
\ No newline at end of file
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+#include <QtCore/QVariant>
+#include <QtCore/Qt>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+struct QObjectDynamicSignalClosure;
+
+QObject*
+qobjectChild(const QObject& obj, ::rust::isize index);
+::rust::isize
+qobjectChildCount(const QObject& obj);
+bool
+qobjectMoveToThread(QObject& obj, QThread* thread);
+QVariant
+qobjectProperty(const QObject& obj, ::rust::Str name);
+bool
+qobjectSetProperty(QObject& obj, ::rust::Str name, const QVariant& value);
+
+::QMetaObject::Connection
+qobjectConnectByName(QObject& sender,
+                     ::rust::Str signal,
+                     ::rust::Box<QObjectDynamicSignalClosure> closure,
+                     ::Qt::ConnectionType type);
+
+}
+}
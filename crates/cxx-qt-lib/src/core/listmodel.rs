@@ -0,0 +1,462 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QVariant;
+use core::pin::Pin;
+
+/// The value of `Qt::DisplayRole`
+const DISPLAY_ROLE: i32 = 0;
+/// The value of `Qt::EditRole`
+const EDIT_ROLE: i32 = 2;
+/// The value of `Qt::UserRole`, which is the role of the first role name
+const USER_ROLE: i32 = 0x0100;
+
+/// An item which is stored in a [ListModel]
+///
+/// Each of the [ROLE_NAMES](ListModelItem::ROLE_NAMES) is a role of the model, starting at `Qt::UserRole`.
+/// The `Qt::DisplayRole` and `Qt::EditRole` are mapped to the first role name.
+pub trait ListModelItem {
+    /// The names of the roles of the item, as used in QML delegates
+    const ROLE_NAMES: &'static [&'static str];
+
+    /// Retrieve the data for the given index into [ROLE_NAMES](ListModelItem::ROLE_NAMES)
+    fn data(&self, role: usize) -> QVariant;
+
+    /// Set the data for the given index into [ROLE_NAMES](ListModelItem::ROLE_NAMES)
+    ///
+    /// Returns true if the data was set, by default items are read-only and this returns false.
+    fn set_data(&mut self, _role: usize, _value: &QVariant) -> bool {
+        false
+    }
+}
+
+/// A QAbstractListModel which is backed by a `Vec` of [ListModelItem]s
+///
+/// This is implemented by CXX-Qt for a QObject with a `#[list_model(T, field)]` attribute.
+/// The methods of this trait modify the list and notify any views of the change,
+/// so that the correct `begin` and `end` methods of the model are always called.
+pub trait ListModel {
+    /// The type of the items in the list
+    type Item: ListModelItem;
+
+    #[doc(hidden)]
+    fn cxx_qt_items(&self) -> &Vec<Self::Item>;
+    #[doc(hidden)]
+    fn cxx_qt_items_mut(self: Pin<&mut Self>) -> &mut Vec<Self::Item>;
+    #[doc(hidden)]
+    fn cxx_qt_begin_insert_rows(self: Pin<&mut Self>, first: i32, last: i32);
+    #[doc(hidden)]
+    fn cxx_qt_end_insert_rows(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_begin_remove_rows(self: Pin<&mut Self>, first: i32, last: i32);
+    #[doc(hidden)]
+    fn cxx_qt_end_remove_rows(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_begin_move_rows(
+        self: Pin<&mut Self>,
+        first: i32,
+        last: i32,
+        destination: i32,
+    ) -> bool;
+    #[doc(hidden)]
+    fn cxx_qt_end_move_rows(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_begin_reset_model(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_end_reset_model(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_rows_changed(self: Pin<&mut Self>, first: i32, last: i32);
+
+    /// The items in the list
+    fn items(&self) -> &[Self::Item] {
+        self.cxx_qt_items()
+    }
+
+    /// Append an item to the end of the list
+    fn push(self: Pin<&mut Self>, item: Self::Item) {
+        let row = self.items().len();
+        self.insert(row, item);
+    }
+
+    /// Append all of the items to the end of the list
+    fn extend<I: IntoIterator<Item = Self::Item>>(mut self: Pin<&mut Self>, items: I) {
+        let mut items: Vec<Self::Item> = items.into_iter().collect();
+        if items.is_empty() {
+            return;
+        }
+
+        let first = self.items().len();
+        let last = first + items.len() - 1;
+        self.as_mut()
+            .cxx_qt_begin_insert_rows(to_row(first), to_row(last));
+        self.as_mut().cxx_qt_items_mut().append(&mut items);
+        self.cxx_qt_end_insert_rows();
+    }
+
+    /// Insert an item at the given row, shifting all items after it down
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row > len`.
+    fn insert(mut self: Pin<&mut Self>, row: usize, item: Self::Item) {
+        let len = self.items().len();
+        assert!(
+            row <= len,
+            "insertion row (is {row}) should be <= len (is {len})"
+        );
+
+        self.as_mut()
+            .cxx_qt_begin_insert_rows(to_row(row), to_row(row));
+        self.as_mut().cxx_qt_items_mut().insert(row, item);
+        self.cxx_qt_end_insert_rows();
+    }
+
+    /// Remove and return the item at the given row, shifting all items after it up
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= len`.
+    fn remove(mut self: Pin<&mut Self>, row: usize) -> Self::Item {
+        let len = self.items().len();
+        assert!(
+            row < len,
+            "removal row (is {row}) should be < len (is {len})"
+        );
+
+        self.as_mut()
+            .cxx_qt_begin_remove_rows(to_row(row), to_row(row));
+        let item = self.as_mut().cxx_qt_items_mut().remove(row);
+        self.cxx_qt_end_remove_rows();
+        item
+    }
+
+    /// Move the item at row `from` so that it is at row `to`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from >= len` or `to >= len`.
+    fn move_row(mut self: Pin<&mut Self>, from: usize, to: usize) {
+        let len = self.items().len();
+        assert!(
+            from < len && to < len,
+            "move rows (are {from} and {to}) should be < len (is {len})"
+        );
+        if from == to {
+            return;
+        }
+
+        // Qt expects the row which the item is placed before, prior to the item being removed
+        let destination = if to > from { to + 1 } else { to };
+        if self
+            .as_mut()
+            .cxx_qt_begin_move_rows(to_row(from), to_row(from), to_row(destination))
+        {
+            let items = self.as_mut().cxx_qt_items_mut();
+            let item = items.remove(from);
+            items.insert(to, item);
+            self.cxx_qt_end_move_rows();
+        }
+    }
+
+    /// Replace the item at the given row, returning the previous item
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= len`.
+    fn set(self: Pin<&mut Self>, row: usize, item: Self::Item) -> Self::Item {
+        self.update(row, |current| core::mem::replace(current, item))
+    }
+
+    /// Modify the item at the given row with a closure and notify views that the data has changed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= len`.
+    fn update<R, F: FnOnce(&mut Self::Item) -> R>(mut self: Pin<&mut Self>, row: usize, f: F) -> R {
+        let len = self.items().len();
+        assert!(
+            row < len,
+            "update row (is {row}) should be < len (is {len})"
+        );
+
+        let result = f(&mut self.as_mut().cxx_qt_items_mut()[row]);
+        self.cxx_qt_rows_changed(to_row(row), to_row(row));
+        result
+    }
+
+    /// Replace all of the items in the list, returning the previous items
+    fn reset(self: Pin<&mut Self>, items: Vec<Self::Item>) -> Vec<Self::Item> {
+        self.reset_with(|current| core::mem::replace(current, items))
+    }
+
+    /// Modify the whole list with a closure, such as sorting it, and reset any views
+    fn reset_with<R, F: FnOnce(&mut Vec<Self::Item>) -> R>(mut self: Pin<&mut Self>, f: F) -> R {
+        self.as_mut().cxx_qt_begin_reset_model();
+        let result = f(self.as_mut().cxx_qt_items_mut());
+        self.cxx_qt_end_reset_model();
+        result
+    }
+
+    /// Remove all of the items in the list
+    fn clear(self: Pin<&mut Self>) {
+        self.reset(Vec::new());
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_row_count(&self) -> i32 {
+        i32::try_from(self.items().len()).unwrap_or(i32::MAX)
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_data(&self, row: i32, role: i32) -> QVariant {
+        let item = usize::try_from(row)
+            .ok()
+            .and_then(|row| self.items().get(row));
+        match (item, role_index(role, Self::Item::ROLE_NAMES.len())) {
+            (Some(item), Some(role)) => item.data(role),
+            _ => QVariant::default(),
+        }
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_set_data(self: Pin<&mut Self>, row: i32, role: i32, value: &QVariant) -> bool {
+        let Some(role) = role_index(role, Self::Item::ROLE_NAMES.len()) else {
+            return false;
+        };
+        usize::try_from(row)
+            .ok()
+            .and_then(|row| self.cxx_qt_items_mut().get_mut(row))
+            .is_some_and(|item| item.set_data(role, value))
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_role_names() -> Vec<String> {
+        role_names(Self::Item::ROLE_NAMES)
+    }
+}
+
+/// Convert a row of a model into a row of the Qt model
+pub(super) fn to_row(row: usize) -> i32 {
+    i32::try_from(row).expect("rows of a model should fit into an i32")
+}
+
+/// Convert a Qt role into an index of the role names of an item
+pub(super) fn role_index(role: i32, role_count: usize) -> Option<usize> {
+    let index = match role {
+        DISPLAY_ROLE | EDIT_ROLE => Some(0),
+        role => role
+            .checked_sub(USER_ROLE)
+            .and_then(|index| usize::try_from(index).ok()),
+    };
+    index.filter(|index| *index < role_count)
+}
+
+/// The role names of an item as owned strings, so they can be passed to C++
+pub(super) fn role_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| (*name).to_owned()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item(i32);
+
+    impl ListModelItem for Item {
+        const ROLE_NAMES: &'static [&'static str] = &["value", "label"];
+
+        fn data(&self, _role: usize) -> QVariant {
+            QVariant::default()
+        }
+    }
+
+    #[derive(Default)]
+    struct Model {
+        items: Vec<Item>,
+        calls: Vec<String>,
+    }
+
+    impl Model {
+        fn values(&self) -> Vec<i32> {
+            self.items.iter().map(|item| item.0).collect()
+        }
+
+        fn take_calls(&mut self) -> Vec<String> {
+            core::mem::take(&mut self.calls)
+        }
+    }
+
+    impl ListModel for Model {
+        type Item = Item;
+
+        fn cxx_qt_items(&self) -> &Vec<Item> {
+            &self.items
+        }
+        fn cxx_qt_items_mut(self: Pin<&mut Self>) -> &mut Vec<Item> {
+            &mut self.get_mut().items
+        }
+        fn cxx_qt_begin_insert_rows(self: Pin<&mut Self>, first: i32, last: i32) {
+            self.get_mut()
+                .calls
+                .push(format!("begin_insert {first} {last}"));
+        }
+        fn cxx_qt_end_insert_rows(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_insert".to_owned());
+        }
+        fn cxx_qt_begin_remove_rows(self: Pin<&mut Self>, first: i32, last: i32) {
+            self.get_mut()
+                .calls
+                .push(format!("begin_remove {first} {last}"));
+        }
+        fn cxx_qt_end_remove_rows(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_remove".to_owned());
+        }
+        fn cxx_qt_begin_move_rows(
+            self: Pin<&mut Self>,
+            first: i32,
+            last: i32,
+            destination: i32,
+        ) -> bool {
+            self.get_mut()
+                .calls
+                .push(format!("begin_move {first} {last} {destination}"));
+            true
+        }
+        fn cxx_qt_end_move_rows(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_move".to_owned());
+        }
+        fn cxx_qt_begin_reset_model(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("begin_reset".to_owned());
+        }
+        fn cxx_qt_end_reset_model(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_reset".to_owned());
+        }
+        fn cxx_qt_rows_changed(self: Pin<&mut Self>, first: i32, last: i32) {
+            self.get_mut().calls.push(format!("changed {first} {last}"));
+        }
+    }
+
+    #[test]
+    fn list_model_insert_and_remove() {
+        let mut model = Model::default();
+        Pin::new(&mut model).push(Item(1));
+        Pin::new(&mut model).insert(0, Item(0));
+        Pin::new(&mut model).extend([Item(2), Item(3)]);
+        Pin::new(&mut model).extend([]);
+        assert_eq!(model.values(), vec![0, 1, 2, 3]);
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                "begin_insert 0 0",
+                "end_insert",
+                "begin_insert 0 0",
+                "end_insert",
+                "begin_insert 2 3",
+                "end_insert"
+            ]
+        );
+
+        assert_eq!(Pin::new(&mut model).remove(1).0, 1);
+        assert_eq!(model.values(), vec![0, 2, 3]);
+        assert_eq!(model.take_calls(), vec!["begin_remove 1 1", "end_remove"]);
+        assert_eq!(model.cxx_qt_row_count(), 3);
+    }
+
+    #[test]
+    fn list_model_move_row() {
+        let mut model = Model::default();
+        Pin::new(&mut model).extend([Item(0), Item(1), Item(2)]);
+        model.take_calls();
+
+        Pin::new(&mut model).move_row(0, 2);
+        assert_eq!(model.values(), vec![1, 2, 0]);
+        Pin::new(&mut model).move_row(2, 0);
+        assert_eq!(model.values(), vec![0, 1, 2]);
+        Pin::new(&mut model).move_row(1, 1);
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                "begin_move 0 0 3",
+                "end_move",
+                "begin_move 2 2 0",
+                "end_move"
+            ]
+        );
+    }
+
+    #[test]
+    fn list_model_update_and_reset() {
+        let mut model = Model::default();
+        Pin::new(&mut model).extend([Item(0), Item(1)]);
+        model.take_calls();
+
+        Pin::new(&mut model).update(1, |item| item.0 += 10);
+        assert_eq!(Pin::new(&mut model).set(0, Item(5)).0, 0);
+        assert_eq!(model.values(), vec![5, 11]);
+        assert_eq!(model.take_calls(), vec!["changed 1 1", "changed 0 0"]);
+
+        Pin::new(&mut model).reset_with(|items| items.reverse());
+        assert_eq!(model.values(), vec![11, 5]);
+        Pin::new(&mut model).clear();
+        assert!(model.items().is_empty());
+        assert_eq!(
+            model.take_calls(),
+            vec!["begin_reset", "end_reset", "begin_reset", "end_reset"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion row (is 2) should be <= len (is 1)")]
+    fn list_model_insert_out_of_bounds() {
+        let mut model = Model::default();
+        Pin::new(&mut model).push(Item(0));
+        Pin::new(&mut model).insert(2, Item(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "removal row (is 1) should be < len (is 1)")]
+    fn list_model_remove_out_of_bounds() {
+        let mut model = Model::default();
+        Pin::new(&mut model).push(Item(0));
+        Pin::new(&mut model).remove(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "move rows (are 0 and 1) should be < len (is 1)")]
+    fn list_model_move_out_of_bounds() {
+        let mut model = Model::default();
+        Pin::new(&mut model).push(Item(0));
+        Pin::new(&mut model).move_row(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "update row (is 1) should be < len (is 1)")]
+    fn list_model_update_out_of_bounds() {
+        let mut model = Model::default();
+        Pin::new(&mut model).push(Item(0));
+        Pin::new(&mut model).update(1, |item| item.0 = 1);
+    }
+
+    #[test]
+    fn list_model_roles() {
+        assert_eq!(role_index(DISPLAY_ROLE, 2), Some(0));
+        assert_eq!(role_index(EDIT_ROLE, 2), Some(0));
+        assert_eq!(role_index(USER_ROLE + 1, 2), Some(1));
+        assert_eq!(role_index(USER_ROLE + 2, 2), None);
+        assert_eq!(role_index(1, 2), None);
+        assert_eq!(role_index(DISPLAY_ROLE, 0), None);
+        assert_eq!(Model::cxx_qt_role_names(), vec!["value", "label"]);
+    }
+
+    #[test]
+    fn list_model_role_index() {
+        assert_eq!(role_index(DISPLAY_ROLE, 2), Some(0));
+        assert_eq!(role_index(USER_ROLE + 1, 2), Some(1));
+        assert_eq!(role_index(USER_ROLE + 2, 2), None);
+        assert_eq!(role_index(USER_ROLE - 1, 2), None);
+        // A role which would overflow when subtracting the user role
+        assert_eq!(role_index(i32::MIN, 2), None);
+    }
+}
@@ -3,6 +3,15 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod listmodel;
+pub use listmodel::{ListModel, ListModelItem};
+
+mod treemodel;
+pub use treemodel::{Tree, TreeModel, TreeModelItem, TreeNodeId};
+
+mod qabstractitemmodel;
+pub use qabstractitemmodel::QAbstractItemModel;
+
 mod qbytearray;
 pub use qbytearray::{
     QByteArray, QByteArrayBase64Option, QByteArrayBase64Options, QByteArrayFromBase64Error,
@@ -43,6 +52,21 @@ pub use qmargins::QMargins;
 mod qmarginsf;
 pub use qmarginsf::QMarginsF;
 
+mod qmetaclassinfo;
+pub use qmetaclassinfo::QMetaClassInfo;
+
+mod qmetaenum;
+pub use qmetaenum::QMetaEnum;
+
+mod qmetamethod;
+pub use qmetamethod::{QMetaMethod, QMetaMethodAccess, QMetaMethodType};
+
+mod qmetaobject;
+pub use qmetaobject::QMetaObject;
+
+mod qmetaproperty;
+pub use qmetaproperty::QMetaProperty;
+
 mod qmetatype;
 pub use qmetatype::QMetaTypeType;
 
@@ -73,6 +97,9 @@ pub use qsize::QSize;
 mod qsizef;
 pub use qsizef::QSizeF;
 
+mod qsortfilterproxymodel;
+pub use qsortfilterproxymodel::{QSortFilterProxyModel, SortFilterProxyModel};
+
 mod qstring;
 pub use qstring::QString;
 
@@ -87,11 +114,14 @@ pub use qstringlist::QStringList;
 mod qt;
 pub use qt::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, DateFormat, FillRule,
-    GlobalColor, KeyboardModifier, KeyboardModifiers, LayoutDirection, MouseButton, MouseButtons,
-    Orientation, Orientations, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags,
-    TimeSpec, TransformationMode,
+    GlobalColor, ItemFlag, ItemFlags, KeyboardModifier, KeyboardModifiers, LayoutDirection,
+    MouseButton, MouseButtons, Orientation, Orientations, PenCapStyle, PenJoinStyle, PenStyle,
+    SizeMode, SortOrder, SplitBehaviorFlags, TimeSpec, TransformationMode,
 };
 
+mod qthread;
+pub use qthread::QThread;
+
 mod qtime;
 pub use qtime::QTime;
 
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        /// The `QAbstractItemModel` class provides the abstract interface for item model classes.
+        ///
+        /// This can be used as a `#[base]` of other bindings to models, so that they can be passed as the source of a [`QSortFilterProxyModel`](crate::QSortFilterProxyModel).
+        ///
+        /// Qt Documentation: [QAbstractItemModel](https://doc.qt.io/qt/qabstractitemmodel.html#details)
+        #[qobject]
+        type QAbstractItemModel;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// Returns the number of columns for the children of the given `parent`.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QAbstractItemModel, parent: &QModelIndex) -> i32;
+
+        /// Returns the data stored under the given `role` for the item referred to by the `index`.
+        fn data(self: &QAbstractItemModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        /// Returns `true` if `parent` has any children; otherwise returns `false`.
+        #[rust_name = "has_children"]
+        fn hasChildren(self: &QAbstractItemModel, parent: &QModelIndex) -> bool;
+
+        /// Returns the index of the item in the model specified by the given `row`, `column` and `parent` index.
+        fn index(
+            self: &QAbstractItemModel,
+            row: i32,
+            column: i32,
+            parent: &QModelIndex,
+        ) -> QModelIndex;
+
+        /// Returns the number of rows under the given `parent`.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QAbstractItemModel, parent: &QModelIndex) -> i32;
+
+        /// Sets the `role` data for the item at `index` to `value`.
+        ///
+        /// Returns `true` if successful; otherwise returns `false`.
+        #[rust_name = "set_data"]
+        fn setData(
+            self: Pin<&mut QAbstractItemModel>,
+            index: &QModelIndex,
+            value: &QVariant,
+            role: i32,
+        ) -> bool;
+    }
+}
+
+pub use ffi::QAbstractItemModel;
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QString;
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qmetaobject.h");
+
+        type QMetaClassInfo = super::QMetaClassInfo;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaclassinfo_name"]
+        fn qmetaclassinfoName(class_info: &QMetaClassInfo) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaclassinfo_value"]
+        fn qmetaclassinfoValue(class_info: &QMetaClassInfo) -> QString;
+    }
+}
+
+/// The `QMetaClassInfo` class provides additional information about a class, as declared with `Q_CLASSINFO`.
+///
+/// Qt Documentation: [QMetaClassInfo](https://doc.qt.io/qt/qmetaclassinfo.html#details)
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaClassInfo {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl QMetaClassInfo {
+    /// Returns the name of this item.
+    pub fn name(&self) -> QString {
+        ffi::qmetaclassinfo_name(self)
+    }
+
+    /// Returns the value of this item.
+    pub fn value(&self) -> QString {
+        ffi::qmetaclassinfo_value(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaClassInfo {
+    type Id = type_id!("QMetaClassInfo");
+    type Kind = cxx::kind::Trivial;
+}
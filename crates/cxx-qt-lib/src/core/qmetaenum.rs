@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QString;
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qmetaobject.h");
+
+        type QMetaEnum = super::QMetaEnum;
+
+        /// Returns `true` if this enumerator is used as a flag; otherwise returns `false`.
+        #[rust_name = "is_flag"]
+        fn isFlag(self: &QMetaEnum) -> bool;
+        /// Returns `true` if this enumerator is declared as a C++11 enum class; otherwise returns `false`.
+        #[rust_name = "is_scoped"]
+        fn isScoped(self: &QMetaEnum) -> bool;
+        /// Returns `true` if this enum is valid (has a name); otherwise returns `false`.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMetaEnum) -> bool;
+        /// Returns the number of keys.
+        #[rust_name = "key_count"]
+        fn keyCount(self: &QMetaEnum) -> i32;
+        /// Returns the value with the given `index`; or returns -1 if there is no such value.
+        fn value(self: &QMetaEnum, index: i32) -> i32;
+        /// Returns a byte array of '|'-separated keys that represents the given `value`.
+        #[rust_name = "value_to_keys"]
+        fn valueToKeys(self: &QMetaEnum, value: i32) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_enum_name"]
+        fn qmetaenumEnumName(meta_enum: &QMetaEnum) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key"]
+        fn qmetaenumKey(meta_enum: &QMetaEnum, index: i32) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key_to_value"]
+        fn qmetaenumKeyToValue(meta_enum: &QMetaEnum, key: &str, ok: &mut bool) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_keys_to_value"]
+        fn qmetaenumKeysToValue(meta_enum: &QMetaEnum, keys: &str, ok: &mut bool) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_name"]
+        fn qmetaenumName(meta_enum: &QMetaEnum) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_scope"]
+        fn qmetaenumScope(meta_enum: &QMetaEnum) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_value_to_key"]
+        fn qmetaenumValueToKey(meta_enum: &QMetaEnum, value: i32) -> QString;
+    }
+}
+
+/// The `QMetaEnum` class provides meta-data about an enumerator.
+///
+/// Qt Documentation: [QMetaEnum](https://doc.qt.io/qt/qmetaenum.html#details)
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaEnum {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl QMetaEnum {
+    /// Returns the enum name of the enumerator, which differs from [`name`](Self::name) for flags
+    /// where this is the name of the underlying enum.
+    pub fn enum_name(&self) -> QString {
+        ffi::qmetaenum_enum_name(self)
+    }
+
+    /// Returns the key with the given `index`, or an empty [`QString`] if no such key exists.
+    pub fn key(&self, index: i32) -> QString {
+        ffi::qmetaenum_key(self, index)
+    }
+
+    /// Returns the integer value of the given enumeration `key`, or [`None`] if `key` is not defined.
+    pub fn key_to_value(&self, key: &str) -> Option<i32> {
+        let mut ok = false;
+        let value = ffi::qmetaenum_key_to_value(self, key, &mut ok);
+        ok.then_some(value)
+    }
+
+    /// Returns the value derived from combining together the values of the '|'-separated `keys`,
+    /// or [`None`] if any of the keys is not defined.
+    pub fn keys_to_value(&self, keys: &str) -> Option<i32> {
+        let mut ok = false;
+        let value = ffi::qmetaenum_keys_to_value(self, keys, &mut ok);
+        ok.then_some(value)
+    }
+
+    /// Returns the name of the type (without the scope).
+    pub fn name(&self) -> QString {
+        ffi::qmetaenum_name(self)
+    }
+
+    /// Returns the scope this enumerator was declared in.
+    pub fn scope(&self) -> QString {
+        ffi::qmetaenum_scope(self)
+    }
+
+    /// Returns the string that is used as the name of the given enumeration `value`,
+    /// or an empty [`QString`] if `value` is not defined.
+    pub fn value_to_key(&self, value: i32) -> QString {
+        ffi::qmetaenum_value_to_key(self, value)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaEnum {
+    type Id = type_id!("QMetaEnum");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,173 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{ConnectionType, QList, QString, QVariant};
+use cxx::{type_id, ExternType};
+use cxx_qt::QObject;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the access level of a method, following the conventions used in C++.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QMetaMethodAccess {
+        /// The method is private.
+        Private,
+        /// The method is protected.
+        Protected,
+        /// The method is public.
+        Public,
+    }
+
+    /// This enum describes the type of a method.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QMetaMethodType {
+        /// The function is a plain member function.
+        Method,
+        /// The function is a signal.
+        Signal,
+        /// The function is a slot.
+        Slot,
+        /// The function is a constructor.
+        Constructor,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaMethodAccess;
+        type QMetaMethodType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/core/qlist/qlist_QByteArray.h");
+        type QList_QByteArray = crate::QList<crate::QByteArray>;
+        include!("cxx-qt-lib/core/qlist/qlist_QVariant.h");
+        type QList_QVariant = crate::QList<crate::QVariant>;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!("cxx-qt-lib/qt.h");
+        #[namespace = "Qt"]
+        type ConnectionType = crate::ConnectionType;
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+
+        type QMetaMethod = super::QMetaMethod;
+
+        /// Returns the access specification of this method (private, protected, or public).
+        ///
+        /// Note that signals are always public, but you should regard that as an implementation detail.
+        fn access(self: &QMetaMethod) -> QMetaMethodAccess;
+        /// Returns `true` if this method is valid (can be introspected and invoked), otherwise returns `false`.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMetaMethod) -> bool;
+        /// Returns this method's index.
+        #[rust_name = "method_index"]
+        fn methodIndex(self: &QMetaMethod) -> i32;
+        /// Returns the signature of this method, such as `"setValue(double)"`.
+        #[rust_name = "method_signature"]
+        fn methodSignature(self: &QMetaMethod) -> QByteArray;
+        /// Returns the type of this method (signal, slot, or method).
+        #[rust_name = "method_type"]
+        fn methodType(self: &QMetaMethod) -> QMetaMethodType;
+        /// Returns the name of this method.
+        fn name(self: &QMetaMethod) -> QByteArray;
+        /// Returns the number of parameters of this method.
+        #[rust_name = "parameter_count"]
+        fn parameterCount(self: &QMetaMethod) -> i32;
+        /// Returns a list of parameter names.
+        #[rust_name = "parameter_names"]
+        fn parameterNames(self: &QMetaMethod) -> QList_QByteArray;
+        /// Returns the type of the parameter at the given `index`.
+        ///
+        /// The return value is one of the types that are registered with `QMetaType`, or `QMetaType::UnknownType` if the type is not registered.
+        #[rust_name = "parameter_type"]
+        fn parameterType(self: &QMetaMethod, index: i32) -> i32;
+        /// Returns a list of parameter types.
+        #[rust_name = "parameter_types"]
+        fn parameterTypes(self: &QMetaMethod) -> QList_QByteArray;
+        /// Returns the return type of this method.
+        ///
+        /// The return value is one of the types that are registered with `QMetaType`, or `QMetaType::UnknownType` if the type is not registered.
+        #[rust_name = "return_type"]
+        fn returnType(self: &QMetaMethod) -> i32;
+        /// Returns the method revision if one was specified, otherwise returns 0.
+        fn revision(self: &QMetaMethod) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_invoke"]
+        fn qmetamethodInvoke(
+            method: &QMetaMethod,
+            object: Pin<&mut QObject>,
+            arguments: &QList_QVariant,
+            conn_type: ConnectionType,
+            return_value: &mut QVariant,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_type_name"]
+        fn qmetamethodTypeName(method: &QMetaMethod) -> QString;
+    }
+}
+
+pub use ffi::{QMetaMethodAccess, QMetaMethodType};
+
+/// The `QMetaMethod` class provides meta-data about a member function.
+///
+/// Qt Documentation: [QMetaMethod](https://doc.qt.io/qt/qmetamethod.html#details)
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaMethod {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl QMetaMethod {
+    /// Invokes this method on the `object` with the given `arguments`, which are converted to the types of the parameters.
+    ///
+    /// Returns the return value of the method, which is an invalid [`QVariant`] if the method does not return a value,
+    /// or [`None`] if the method could not be invoked.
+    ///
+    /// Note that with a [`ConnectionType::QueuedConnection`], or a [`ConnectionType::AutoConnection`] when the `object`
+    /// lives in another thread, the invocation is queued onto the event loop of the `object`.
+    /// The return value is then not available, so an invalid [`QVariant`] is returned once the invocation has been queued.
+    pub fn invoke(
+        &self,
+        object: Pin<&mut QObject>,
+        arguments: &[QVariant],
+        conn_type: ConnectionType,
+    ) -> Option<QVariant> {
+        let mut return_value = QVariant::default();
+        ffi::qmetamethod_invoke(
+            self,
+            object,
+            &QList::from(arguments),
+            conn_type,
+            &mut return_value,
+        )
+        .then_some(return_value)
+    }
+
+    /// Returns the return type name of this method.
+    pub fn type_name(&self) -> QString {
+        ffi::qmetamethod_type_name(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaMethod {
+    type Id = type_id!("QMetaMethod");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,308 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmetaobject.h"
+
+#include <array>
+
+#include <QtCore/QThread>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMetaClassInfo, QMetaEnum and QMetaMethod have a pointer to the QMetaObject
+// and either a handle or a pointer to the data.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v5.15.6-lts-lgpl#n54
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v6.2.4#n50
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QMetaClassInfo, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+});
+assert_alignment_and_size(QMetaEnum, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+});
+assert_alignment_and_size(QMetaMethod, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+});
+// QMetaProperty has a pointer to the QMetaObject, a pointer to the data and a
+// QMetaEnum.
+assert_alignment_and_size(QMetaProperty, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+  ::std::size_t a2;
+  ::std::size_t a3;
+});
+#else
+assert_alignment_and_size(QMetaClassInfo, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+});
+assert_alignment_and_size(QMetaEnum, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+});
+assert_alignment_and_size(QMetaMethod, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+});
+// QMetaProperty has a pointer to the QMetaObject, a handle, an index and a
+// QMetaEnum.
+assert_alignment_and_size(QMetaProperty, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+  ::std::int32_t a2;
+  ::std::size_t a3;
+  ::std::uint32_t a4;
+});
+#endif
+
+static_assert(::std::is_trivially_copyable<QMetaClassInfo>::value);
+static_assert(::std::is_trivially_copyable<QMetaEnum>::value);
+static_assert(::std::is_trivially_copyable<QMetaMethod>::value);
+static_assert(::std::is_trivially_copyable<QMetaProperty>::value);
+
+namespace {
+
+QByteArray
+toQByteArray(::rust::Str str)
+{
+  return QByteArray(str.data(), static_cast<int>(str.size()));
+}
+
+QByteArray
+toNormalizedSignature(::rust::Str str)
+{
+  return QMetaObject::normalizedSignature(toQByteArray(str).constData());
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qmetaclassinfoName(const QMetaClassInfo& classInfo)
+{
+  return QString::fromUtf8(classInfo.name());
+}
+
+QString
+qmetaclassinfoValue(const QMetaClassInfo& classInfo)
+{
+  return QString::fromUtf8(classInfo.value());
+}
+
+QString
+qmetaenumEnumName(const QMetaEnum& metaEnum)
+{
+  return QString::fromUtf8(metaEnum.enumName());
+}
+
+QString
+qmetaenumKey(const QMetaEnum& metaEnum, ::std::int32_t index)
+{
+  return QString::fromUtf8(metaEnum.key(index));
+}
+
+::std::int32_t
+qmetaenumKeyToValue(const QMetaEnum& metaEnum, ::rust::Str key, bool& ok)
+{
+  return metaEnum.keyToValue(toQByteArray(key).constData(), &ok);
+}
+
+::std::int32_t
+qmetaenumKeysToValue(const QMetaEnum& metaEnum, ::rust::Str keys, bool& ok)
+{
+  return metaEnum.keysToValue(toQByteArray(keys).constData(), &ok);
+}
+
+QString
+qmetaenumName(const QMetaEnum& metaEnum)
+{
+  return QString::fromUtf8(metaEnum.name());
+}
+
+QString
+qmetaenumScope(const QMetaEnum& metaEnum)
+{
+  return QString::fromUtf8(metaEnum.scope());
+}
+
+QString
+qmetaenumValueToKey(const QMetaEnum& metaEnum, ::std::int32_t value)
+{
+  return QString::fromUtf8(metaEnum.valueToKey(value));
+}
+
+bool
+qmetamethodInvoke(const QMetaMethod& method,
+                  QObject& object,
+                  const QList<QVariant>& arguments,
+                  ::Qt::ConnectionType type,
+                  QVariant& returnValue)
+{
+  constexpr int maxArguments = 10;
+  if (!method.isValid() || arguments.size() != method.parameterCount() ||
+      arguments.size() > maxArguments) {
+    return false;
+  }
+
+  // Convert the arguments to the types of the parameters, note that the
+  // generic arguments point into these so they need to outlive the invocation
+  const QList<QByteArray> parameterTypes = method.parameterTypes();
+  QList<QVariant> converted = arguments;
+  ::std::array<QGenericArgument, maxArguments> genericArguments;
+  for (int i = 0; i < converted.size(); ++i) {
+    const int typeId = method.parameterType(i);
+    QVariant& argument = converted[i];
+    if (typeId == QMetaType::QVariant) {
+      genericArguments[i] =
+        QGenericArgument(parameterTypes.at(i).constData(), &argument);
+      continue;
+    }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    const bool ok = argument.convert(QMetaType(typeId));
+#else
+    const bool ok = argument.convert(typeId);
+#endif
+    if (!ok) {
+      return false;
+    }
+
+    genericArguments[i] =
+      QGenericArgument(parameterTypes.at(i).constData(), argument.constData());
+  }
+
+  // The return value is only available when the method is invoked directly,
+  // as Qt refuses to queue an invocation which has a return argument
+  const bool synchronous =
+    type == ::Qt::DirectConnection || type == ::Qt::BlockingQueuedConnection ||
+    (type == ::Qt::AutoConnection &&
+     object.thread() == QThread::currentThread());
+
+  // Create storage for the return value of the method
+  const int returnType = method.returnType();
+  QGenericReturnArgument returnArgument;
+  if (synchronous && returnType == QMetaType::QVariant) {
+    returnArgument = QGenericReturnArgument(method.typeName(), &returnValue);
+  } else if (synchronous && returnType != QMetaType::Void &&
+             returnType != QMetaType::UnknownType) {
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    returnValue = QVariant(QMetaType(returnType), nullptr);
+#else
+    returnValue = QVariant(returnType, nullptr);
+#endif
+    returnArgument =
+      QGenericReturnArgument(method.typeName(), returnValue.data());
+  }
+
+  return method.invoke(&object,
+                       type,
+                       returnArgument,
+                       genericArguments[0],
+                       genericArguments[1],
+                       genericArguments[2],
+                       genericArguments[3],
+                       genericArguments[4],
+                       genericArguments[5],
+                       genericArguments[6],
+                       genericArguments[7],
+                       genericArguments[8],
+                       genericArguments[9]);
+}
+
+QString
+qmetamethodTypeName(const QMetaMethod& method)
+{
+  return QString::fromUtf8(method.typeName());
+}
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject)
+{
+  return QString::fromUtf8(metaObject.className());
+}
+
+::std::int32_t
+qmetaobjectIndexOfClassInfo(const QMetaObject& metaObject, ::rust::Str name)
+{
+  return metaObject.indexOfClassInfo(toQByteArray(name).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfEnumerator(const QMetaObject& metaObject, ::rust::Str name)
+{
+  return metaObject.indexOfEnumerator(toQByteArray(name).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfMethod(const QMetaObject& metaObject, ::rust::Str method)
+{
+  return metaObject.indexOfMethod(toNormalizedSignature(method).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfProperty(const QMetaObject& metaObject, ::rust::Str name)
+{
+  return metaObject.indexOfProperty(toQByteArray(name).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfSignal(const QMetaObject& metaObject, ::rust::Str signal)
+{
+  return metaObject.indexOfSignal(toNormalizedSignature(signal).constData());
+}
+
+::std::int32_t
+qmetaobjectIndexOfSlot(const QMetaObject& metaObject, ::rust::Str slot)
+{
+  return metaObject.indexOfSlot(toNormalizedSignature(slot).constData());
+}
+
+bool
+qmetaobjectInherits(const QMetaObject& metaObject,
+                    const QMetaObject& superClass)
+{
+  return metaObject.inherits(&superClass);
+}
+
+QString
+qmetapropertyName(const QMetaProperty& property)
+{
+  return QString::fromUtf8(property.name());
+}
+
+QVariant
+qmetapropertyRead(const QMetaProperty& property, const QObject& object)
+{
+  return property.read(&object);
+}
+
+bool
+qmetapropertyReset(const QMetaProperty& property, QObject& object)
+{
+  return property.reset(&object);
+}
+
+QString
+qmetapropertyTypeName(const QMetaProperty& property)
+{
+  return QString::fromUtf8(property.typeName());
+}
+
+bool
+qmetapropertyWrite(const QMetaProperty& property,
+                   QObject& object,
+                   const QVariant& value)
+{
+  return property.write(&object, value);
+}
+
+}
+}
@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        type QMetaClassInfo = crate::QMetaClassInfo;
+        type QMetaEnum = crate::QMetaEnum;
+        type QMetaMethod = crate::QMetaMethod;
+        type QMetaProperty = crate::QMetaProperty;
+
+        include!("cxx-qt-lib/qmetaobject.h");
+        /// The `QMetaObject` class contains meta-information about Qt objects.
+        ///
+        /// It can be retrieved for any `QObject` with [`QObjectExt::meta_object`](crate::QObjectExt::meta_object).
+        ///
+        /// Qt Documentation: [QMetaObject](https://doc.qt.io/qt/qmetaobject.html#details)
+        type QMetaObject;
+
+        /// Returns the meta-data for the item of class information with the given `index`.
+        #[rust_name = "class_info"]
+        fn classInfo(self: &QMetaObject, index: i32) -> QMetaClassInfo;
+        /// Returns the number of items of class information in this class.
+        #[rust_name = "class_info_count"]
+        fn classInfoCount(self: &QMetaObject) -> i32;
+        /// Returns the class information offset for this class; i.e. the index position of this class's first class information item.
+        #[rust_name = "class_info_offset"]
+        fn classInfoOffset(self: &QMetaObject) -> i32;
+        /// Returns the meta-data for the enumerator with the given `index`.
+        fn enumerator(self: &QMetaObject, index: i32) -> QMetaEnum;
+        /// Returns the number of enumerators in this class.
+        #[rust_name = "enumerator_count"]
+        fn enumeratorCount(self: &QMetaObject) -> i32;
+        /// Returns the enumerator offset for this class; i.e. the index position of this class's first enumerator.
+        #[rust_name = "enumerator_offset"]
+        fn enumeratorOffset(self: &QMetaObject) -> i32;
+        /// Returns the meta-data for the method with the given `index`.
+        fn method(self: &QMetaObject, index: i32) -> QMetaMethod;
+        /// Returns the number of methods in this class, including the number of methods provided by each base class.
+        /// These include signals and slots as well as normal member functions.
+        #[rust_name = "method_count"]
+        fn methodCount(self: &QMetaObject) -> i32;
+        /// Returns the method offset for this class; i.e. the index position of this class's first member function.
+        #[rust_name = "method_offset"]
+        fn methodOffset(self: &QMetaObject) -> i32;
+        /// Returns the meta-data for the property with the given `index`. If no such property exists, an invalid [`QMetaProperty`] is returned.
+        fn property(self: &QMetaObject, index: i32) -> QMetaProperty;
+        /// Returns the number of properties in this class, including the number of properties provided by each base class.
+        #[rust_name = "property_count"]
+        fn propertyCount(self: &QMetaObject) -> i32;
+        /// Returns the property offset for this class; i.e. the index position of this class's first property.
+        #[rust_name = "property_offset"]
+        fn propertyOffset(self: &QMetaObject) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "super_class_ptr"]
+        fn superClass(self: &QMetaObject) -> *const QMetaObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_class_name"]
+        fn qmetaobjectClassName(meta_object: &QMetaObject) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_class_info"]
+        fn qmetaobjectIndexOfClassInfo(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_enumerator"]
+        fn qmetaobjectIndexOfEnumerator(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_method"]
+        fn qmetaobjectIndexOfMethod(meta_object: &QMetaObject, method: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_property"]
+        fn qmetaobjectIndexOfProperty(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_signal"]
+        fn qmetaobjectIndexOfSignal(meta_object: &QMetaObject, signal: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_slot"]
+        fn qmetaobjectIndexOfSlot(meta_object: &QMetaObject, slot: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_inherits"]
+        fn qmetaobjectInherits(meta_object: &QMetaObject, super_class: &QMetaObject) -> bool;
+    }
+}
+
+pub use ffi::QMetaObject;
+use ffi::{QMetaClassInfo, QMetaEnum, QMetaMethod, QMetaProperty, QString};
+
+impl QMetaObject {
+    /// Returns the name of the class.
+    pub fn class_name(&self) -> QString {
+        ffi::qmetaobject_class_name(self)
+    }
+
+    /// Finds the item of class information `name` and returns its index, or [`None`] if it was not found.
+    pub fn index_of_class_info(&self, name: &str) -> Option<i32> {
+        Self::valid_index(ffi::qmetaobject_index_of_class_info(self, name))
+    }
+
+    /// Finds the enumerator `name` and returns its index, or [`None`] if it was not found.
+    pub fn index_of_enumerator(&self, name: &str) -> Option<i32> {
+        Self::valid_index(ffi::qmetaobject_index_of_enumerator(self, name))
+    }
+
+    /// Finds the method with the signature `method`, such as `"setValue(int)"`, and returns its index,
+    /// or [`None`] if it was not found.
+    ///
+    /// The signature is normalized before it is searched for.
+    pub fn index_of_method(&self, method: &str) -> Option<i32> {
+        Self::valid_index(ffi::qmetaobject_index_of_method(self, method))
+    }
+
+    /// Finds the property `name` and returns its index, or [`None`] if it was not found.
+    pub fn index_of_property(&self, name: &str) -> Option<i32> {
+        Self::valid_index(ffi::qmetaobject_index_of_property(self, name))
+    }
+
+    /// Finds the signal with the signature `signal`, such as `"valueChanged(int)"`, and returns its index,
+    /// or [`None`] if it was not found.
+    ///
+    /// The signature is normalized before it is searched for.
+    pub fn index_of_signal(&self, signal: &str) -> Option<i32> {
+        Self::valid_index(ffi::qmetaobject_index_of_signal(self, signal))
+    }
+
+    /// Finds the slot with the signature `slot`, such as `"setValue(int)"`, and returns its index,
+    /// or [`None`] if it was not found.
+    ///
+    /// The signature is normalized before it is searched for.
+    pub fn index_of_slot(&self, slot: &str) -> Option<i32> {
+        Self::valid_index(ffi::qmetaobject_index_of_slot(self, slot))
+    }
+
+    /// Returns `true` if the class described by this `QMetaObject` inherits the type described by `meta_object`; otherwise returns `false`.
+    ///
+    /// A type is considered to inherit itself.
+    pub fn inherits(&self, meta_object: &QMetaObject) -> bool {
+        ffi::qmetaobject_inherits(self, meta_object)
+    }
+
+    /// Returns the meta-object of the superclass, or [`None`] if there is no such object.
+    pub fn super_class(&self) -> Option<&QMetaObject> {
+        // SAFETY: meta-objects outlive the meta-objects of their subclasses
+        unsafe { self.super_class_ptr().as_ref() }
+    }
+
+    /// Returns an iterator over the items of class information of this class, including those of each base class.
+    pub fn class_infos(&self) -> impl Iterator<Item = QMetaClassInfo> + '_ {
+        (0..self.class_info_count()).map(|index| self.class_info(index))
+    }
+
+    /// Returns an iterator over the enumerators of this class, including those of each base class.
+    pub fn enumerators(&self) -> impl Iterator<Item = QMetaEnum> + '_ {
+        (0..self.enumerator_count()).map(|index| self.enumerator(index))
+    }
+
+    /// Returns an iterator over the methods of this class, including those of each base class.
+    pub fn methods(&self) -> impl Iterator<Item = QMetaMethod> + '_ {
+        (0..self.method_count()).map(|index| self.method(index))
+    }
+
+    /// Returns an iterator over the properties of this class, including those of each base class.
+    pub fn properties(&self) -> impl Iterator<Item = QMetaProperty> + '_ {
+        (0..self.property_count()).map(|index| self.property(index))
+    }
+
+    fn valid_index(index: i32) -> Option<i32> {
+        (index >= 0).then_some(index)
+    }
+}
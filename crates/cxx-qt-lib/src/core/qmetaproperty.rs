@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QVariant};
+use cxx::{type_id, ExternType};
+use cxx_qt::QObject;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaEnum = crate::QMetaEnum;
+        type QMetaMethod = crate::QMetaMethod;
+
+        type QMetaProperty = super::QMetaProperty;
+
+        /// Returns the enumerator if this property's type is an enumerator type; otherwise the returned value is undefined.
+        fn enumerator(self: &QMetaProperty) -> QMetaEnum;
+        /// Returns `true` if the property has a corresponding change notify signal; otherwise returns `false`.
+        #[rust_name = "has_notify_signal"]
+        fn hasNotifySignal(self: &QMetaProperty) -> bool;
+        /// Returns `true` if the property is constant; otherwise returns `false`.
+        ///
+        /// A property is constant if the `Q_PROPERTY`'s `CONSTANT` attribute is set.
+        #[rust_name = "is_constant"]
+        fn isConstant(self: &QMetaProperty) -> bool;
+        /// Returns `true` if the property's type is an enumeration value; otherwise returns `false`.
+        #[rust_name = "is_enum_type"]
+        fn isEnumType(self: &QMetaProperty) -> bool;
+        /// Returns `true` if the property is final; otherwise returns `false`.
+        ///
+        /// A property is final if the `Q_PROPERTY`'s `FINAL` attribute is set.
+        #[rust_name = "is_final"]
+        fn isFinal(self: &QMetaProperty) -> bool;
+        /// Returns `true` if the property's type is an enumeration value that is used as a flag; otherwise returns `false`.
+        #[rust_name = "is_flag_type"]
+        fn isFlagType(self: &QMetaProperty) -> bool;
+        /// Returns `true` if this property is readable; otherwise returns `false`.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QMetaProperty) -> bool;
+        /// Returns `true` if this property can be reset to a default value; otherwise returns `false`.
+        #[rust_name = "is_resettable"]
+        fn isResettable(self: &QMetaProperty) -> bool;
+        /// Returns `true` if this property is valid (readable); otherwise returns `false`.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMetaProperty) -> bool;
+        /// Returns `true` if this property is writable; otherwise returns `false`.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QMetaProperty) -> bool;
+        /// Returns the [`QMetaMethod`](crate::QMetaMethod) instance of the property change notifying signal if one was specified, otherwise returns an invalid `QMetaMethod`.
+        #[rust_name = "notify_signal"]
+        fn notifySignal(self: &QMetaProperty) -> QMetaMethod;
+        /// Returns the index of the property change notifying signal if one was specified, otherwise returns -1.
+        #[rust_name = "notify_signal_index"]
+        fn notifySignalIndex(self: &QMetaProperty) -> i32;
+        /// Returns this property's index.
+        #[rust_name = "property_index"]
+        fn propertyIndex(self: &QMetaProperty) -> i32;
+        /// Returns the property revision if one was specified, otherwise returns 0.
+        fn revision(self: &QMetaProperty) -> i32;
+        /// Returns this property's user type.
+        ///
+        /// The return value is one of the values that are registered with `QMetaType`, or `QMetaType::UnknownType` if the type is not registered.
+        #[rust_name = "user_type"]
+        fn userType(self: &QMetaProperty) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_name"]
+        fn qmetapropertyName(property: &QMetaProperty) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_read"]
+        fn qmetapropertyRead(property: &QMetaProperty, object: &QObject) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_reset"]
+        fn qmetapropertyReset(property: &QMetaProperty, object: Pin<&mut QObject>) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_type_name"]
+        fn qmetapropertyTypeName(property: &QMetaProperty) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_write"]
+        fn qmetapropertyWrite(
+            property: &QMetaProperty,
+            object: Pin<&mut QObject>,
+            value: &QVariant,
+        ) -> bool;
+    }
+}
+
+/// The `QMetaProperty` class provides meta-data about a property.
+///
+/// Qt Documentation: [QMetaProperty](https://doc.qt.io/qt/qmetaproperty.html#details)
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaProperty {
+    _mobj: MaybeUninit<usize>,
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _handle: MaybeUninit<u32>,
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _idx: MaybeUninit<i32>,
+    #[cfg(cxxqt_qt_version_major = "6")]
+    _data: MaybeUninit<usize>,
+    _menum: MaybeUninit<[usize; 2]>,
+}
+
+impl QMetaProperty {
+    /// Returns this property's name.
+    pub fn name(&self) -> QString {
+        ffi::qmetaproperty_name(self)
+    }
+
+    /// Reads the property's value from the given `object`.
+    ///
+    /// Returns the value if it was able to read it; otherwise returns an invalid [`QVariant`].
+    pub fn read(&self, object: &QObject) -> QVariant {
+        ffi::qmetaproperty_read(self, object)
+    }
+
+    /// Resets the property for the given `object` with a reset method.
+    ///
+    /// Returns `true` if the reset worked; otherwise returns `false`.
+    pub fn reset(&self, object: Pin<&mut QObject>) -> bool {
+        ffi::qmetaproperty_reset(self, object)
+    }
+
+    /// Returns the name of this property's type.
+    pub fn type_name(&self) -> QString {
+        ffi::qmetaproperty_type_name(self)
+    }
+
+    /// Writes `value` as the property's value to the given `object`.
+    ///
+    /// Returns `true` if the write succeeded; otherwise returns `false`.
+    pub fn write(&self, object: Pin<&mut QObject>, value: &QVariant) -> bool {
+        ffi::qmetaproperty_write(self, object, value)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaProperty {
+    type Id = type_id!("QMetaProperty");
+    type Kind = cxx::kind::Trivial;
+}
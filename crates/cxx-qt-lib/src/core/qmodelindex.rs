@@ -11,13 +11,17 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qmodelindex.h");
         include!("cxx-qt-lib/qstring.h");
+        include!("cxx-qt-lib/qvariant.h");
 
         type QModelIndex = super::QModelIndex;
         type QString = crate::QString;
+        type QVariant = crate::QVariant;
         type quintptr = crate::quintptr;
 
         /// Returns the column this model index refers to.
         fn column(self: &QModelIndex) -> i32;
+        /// Returns the data for the given `role` for the item referred to by the index.
+        fn data(self: &QModelIndex, role: i32) -> QVariant;
         /// Returns `true` if this model index is valid; otherwise returns `false`.
         ///
         /// A valid index belongs to a model, and has non-negative row and column numbers.
@@ -0,0 +1,222 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qobject.h"
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtCore/QMetaMethod>
+#include <QtCore/QVariant>
+
+#include "private/src/core/qobject.cxx.h"
+
+namespace {
+
+// A receiver which forwards the arguments of any signal to a Rust closure
+//
+// This does not use Q_OBJECT, instead the method indexes after the last method
+// of QObject are handled in qt_metacall and are used as the connected slots.
+//
+// Qt does not notify the receiver when its connection is disconnected, so the
+// connection which is returned is from the destroyed signal of the receiver.
+// Disconnecting it calls disconnectNotify, which deletes the receiver and with
+// it the closure.
+//
+// Disconnecting the signal from the sender directly does not delete the
+// receiver, it is then only deleted once the sender is destroyed.
+class QObjectDynamicSignalReceiver : public QObject
+{
+public:
+  QObjectDynamicSignalReceiver(
+    const QMetaMethod& signal,
+    ::rust::Box<::rust::cxxqtlib1::QObjectDynamicSignalClosure> closure,
+    bool singleShot)
+    : m_signal(signal)
+    , m_closure(::std::move(closure))
+    , m_singleShot(singleShot)
+  {
+  }
+
+  // The slot which is connected to the signal of the sender
+  static int slotIndex() { return QObject::staticMetaObject.methodCount(); }
+
+  // The slot which is connected to the destroyed signal of the receiver
+  static int handleSlotIndex() { return slotIndex() + 1; }
+
+  void setConnections(const ::QMetaObject::Connection& connection,
+                      const ::QMetaObject::Connection& handle)
+  {
+    m_connection = connection;
+    m_handle = handle;
+  }
+
+  int qt_metacall(QMetaObject::Call call, int id, void** args) override
+  {
+    id = QObject::qt_metacall(call, id, args);
+    if (id < 0 || call != QMetaObject::InvokeMetaMethod) {
+      return id;
+    }
+
+    if (id == 0) {
+      if (!m_singleShot) {
+        invoke(args);
+      } else if (QObject::disconnect(m_connection)) {
+        // A queued invocation could still arrive after the first one
+        invoke(args);
+        QObject::disconnect(m_handle);
+      }
+    }
+
+    return id - 2;
+  }
+
+protected:
+  void disconnectNotify(const QMetaMethod&) override
+  {
+    // The handle has been disconnected, so disconnect from the sender too
+    QObject::disconnect(m_connection);
+    deleteLater();
+  }
+
+private:
+  void invoke(void** args)
+  {
+    QList<QVariant> arguments;
+    arguments.reserve(m_signal.parameterCount());
+    for (int i = 0; i < m_signal.parameterCount(); ++i) {
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+      arguments.append(QVariant(m_signal.parameterMetaType(i), args[i + 1]));
+#else
+      arguments.append(QVariant(m_signal.parameterType(i), args[i + 1]));
+#endif
+    }
+
+    m_closure->call(arguments);
+  }
+
+  QMetaMethod m_signal;
+  ::rust::Box<::rust::cxxqtlib1::QObjectDynamicSignalClosure> m_closure;
+  bool m_singleShot;
+  ::QMetaObject::Connection m_connection;
+  ::QMetaObject::Connection m_handle;
+};
+
+int
+indexOfSignal(const QMetaObject& metaObject, const QByteArray& signal)
+{
+  // A full signature can be used to select a specific overload
+  if (signal.contains('(')) {
+    return metaObject.indexOfSignal(
+      QMetaObject::normalizedSignature(signal.constData()).constData());
+  }
+
+  for (int i = 0; i < metaObject.methodCount(); ++i) {
+    const QMetaMethod method = metaObject.method(i);
+    if (method.methodType() == QMetaMethod::Signal && method.name() == signal) {
+      return i;
+    }
+  }
+
+  return -1;
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qobjectChild(const QObject& obj, ::rust::isize index)
+{
+  return obj.children().at(static_cast<qsizetype>(index));
+}
+
+::rust::isize
+qobjectChildCount(const QObject& obj)
+{
+  return static_cast<::rust::isize>(obj.children().size());
+}
+
+bool
+qobjectMoveToThread(QObject& obj, QThread* thread)
+{
+  // Before Qt 6.7 moveToThread does not return whether it succeeded
+  obj.moveToThread(thread);
+  return obj.thread() == thread;
+}
+
+QVariant
+qobjectProperty(const QObject& obj, ::rust::Str name)
+{
+  const QByteArray propertyName(name.data(), static_cast<int>(name.size()));
+  return obj.property(propertyName.constData());
+}
+
+bool
+qobjectSetProperty(QObject& obj, ::rust::Str name, const QVariant& value)
+{
+  const QByteArray propertyName(name.data(), static_cast<int>(name.size()));
+  return obj.setProperty(propertyName.constData(), value);
+}
+
+::QMetaObject::Connection
+qobjectConnectByName(QObject& sender,
+                     ::rust::Str signal,
+                     ::rust::Box<QObjectDynamicSignalClosure> closure,
+                     ::Qt::ConnectionType type)
+{
+  const QByteArray signalName(signal.data(), static_cast<int>(signal.size()));
+  const QMetaObject* metaObject = sender.metaObject();
+  const int signalIndex = indexOfSignal(*metaObject, signalName);
+  if (signalIndex < 0) {
+    qWarning("QObject::connect: No such signal %s::%s",
+             metaObject->className(),
+             signalName.constData());
+    return ::QMetaObject::Connection();
+  }
+
+  constexpr int singleShotConnection = 0x100;
+  auto* receiver = new QObjectDynamicSignalReceiver(
+    metaObject->method(signalIndex),
+    ::std::move(closure),
+    type & singleShotConnection);
+  receiver->moveToThread(sender.thread());
+
+  // The flags are handled by the receiver as each connection has a new receiver
+  const int connectionType =
+    type & ~(singleShotConnection | ::Qt::UniqueConnection);
+  const auto connection = ::QMetaObject::connect(&sender,
+                                                 signalIndex,
+                                                 receiver,
+                                                 receiver->slotIndex(),
+                                                 connectionType);
+  if (!connection) {
+    receiver->deleteLater();
+    return connection;
+  }
+
+  const int destroyedIndex =
+    QObject::staticMetaObject.indexOfSignal("destroyed(QObject*)");
+  const auto handle = ::QMetaObject::connect(receiver,
+                                             destroyedIndex,
+                                             receiver,
+                                             receiver->handleSlotIndex(),
+                                             ::Qt::DirectConnection);
+  receiver->setConnections(connection, handle);
+
+  // Delete the receiver once the sender is destroyed
+  QObject::connect(
+    &sender,
+    &QObject::destroyed,
+    receiver,
+    [handle]() { QObject::disconnect(handle); },
+    ::Qt::DirectConnection);
+
+  return handle;
+}
+
+}
+}
@@ -3,7 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt::casting::Upcast;
+use crate::{
+    ConnectionType, QByteArray, QList, QMetaObject, QMetaObjectConnection, QThread, QVariant,
+};
+use cxx_qt::casting::{Downcast, Upcast};
 pub use cxx_qt::QObject;
 use std::pin::Pin;
 use std::ptr;
@@ -13,6 +16,21 @@ pub mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = crate::QThread;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!("cxx-qt-lib/core/qlist/qlist_QByteArray.h");
+        type QList_QByteArray = crate::QList<crate::QByteArray>;
+        include!("cxx-qt-lib/core/qlist/qlist_QVariant.h");
+        type QList_QVariant = crate::QList<crate::QVariant>;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "Qt"]
+        type ConnectionType = crate::ConnectionType;
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
     }
 
     unsafe extern "C++" {
@@ -25,6 +43,15 @@ pub mod ffi {
         #[rust_name = "signals_blocked"]
         pub fn signalsBlocked(&self) -> bool;
 
+        #[rust_name = "delete_later"]
+        pub unsafe fn deleteLater(self: Pin<&mut Self>);
+
+        #[rust_name = "dynamic_property_names"]
+        pub fn dynamicPropertyNames(&self) -> QList_QByteArray;
+
+        #[rust_name = "meta_object"]
+        pub fn metaObject(&self) -> *const QMetaObject;
+
         #[rust_name = "set_object_name"]
         pub fn setObjectName(self: Pin<&mut Self>, name: &QString);
 
@@ -35,6 +62,55 @@ pub mod ffi {
 
         #[rust_name = "set_parent"]
         pub unsafe fn setParent(self: Pin<&mut Self>, parent: *mut QObjectExternal);
+
+        pub fn thread(&self) -> *mut QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_child"]
+        fn qobjectChild(obj: &QObjectExternal, index: isize) -> *mut QObjectExternal;
+        #[doc(hidden)]
+        #[rust_name = "qobject_child_count"]
+        fn qobjectChildCount(obj: &QObjectExternal) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qobject_move_to_thread"]
+        unsafe fn qobjectMoveToThread(obj: Pin<&mut QObjectExternal>, thread: *mut QThread)
+            -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qobject_property"]
+        fn qobjectProperty(obj: &QObjectExternal, name: &str) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_property"]
+        fn qobjectSetProperty(obj: Pin<&mut QObjectExternal>, name: &str, value: &QVariant)
+            -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qobject_connect_by_name"]
+        fn qobjectConnectByName(
+            sender: Pin<&mut QObjectExternal>,
+            signal: &str,
+            closure: Box<QObjectDynamicSignalClosure>,
+            conn_type: ConnectionType,
+        ) -> QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QObjectDynamicSignalClosure;
+
+        fn call(self: &mut QObjectDynamicSignalClosure, arguments: &QList_QVariant);
+    }
+}
+
+/// The closure of a signal connected by name, which receives the signal arguments as variants
+pub struct QObjectDynamicSignalClosure {
+    closure: Box<dyn FnMut(Vec<QVariant>) + Send>,
+}
+
+impl QObjectDynamicSignalClosure {
+    fn call(&mut self, arguments: &QList<QVariant>) {
+        (self.closure)(arguments.into())
     }
 }
 
@@ -57,6 +133,10 @@ pub trait QObjectExt {
     /// Signals are not blocked by default.
     fn signals_blocked(&self) -> bool;
 
+    /// Returns the meta-object of this object, which can be used to introspect its
+    /// properties, methods, signals, enums and class info at runtime.
+    fn meta_object(&self) -> &QMetaObject;
+
     /// Sets the object's name to `name`.
     fn set_object_name(self: Pin<&mut Self>, name: &QString);
 
@@ -71,6 +151,95 @@ pub trait QObjectExt {
 
     /// Makes the object parentless.
     fn unset_parent(self: Pin<&mut Self>);
+
+    /// Returns the child objects of this object.
+    fn children(&self) -> Vec<&QObject>;
+
+    /// Returns the first child of this object which can be downcast to `C` and is called `name`,
+    /// or [`None`] if there is no such object. An empty `name` matches all objects.
+    ///
+    /// The search is performed recursively, direct children are checked before their children.
+    fn find_child<C: Upcast<QObject>>(&self, name: &str) -> Option<&C>;
+
+    /// Returns all children of this object which can be downcast to `C` and are called `name`,
+    /// or an empty [`Vec`] if there are no such objects. An empty `name` matches all objects.
+    ///
+    /// The search is performed recursively.
+    fn find_children<C: Upcast<QObject>>(&self, name: &str) -> Vec<&C>;
+
+    /// Returns the value of the object's `name` property.
+    ///
+    /// If no such property exists, the returned variant is invalid.
+    ///
+    /// Information about all available properties is provided through the [`meta_object`](Self::meta_object)
+    /// and [`dynamic_property_names`](Self::dynamic_property_names).
+    fn property(&self, name: &str) -> QVariant;
+
+    /// Sets the value of the object's `name` property to `value`.
+    ///
+    /// If the property is defined in the class using `Q_PROPERTY` then `true` is returned on success and `false` otherwise.
+    /// If the property is not defined using `Q_PROPERTY`, and therefore not listed in the meta-object,
+    /// it is added as a dynamic property and `false` is returned.
+    ///
+    /// Dynamic properties can be removed again by setting an invalid [`QVariant`].
+    fn set_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool;
+
+    /// Returns the names of all properties that were dynamically added to the object using [`set_property`](Self::set_property).
+    fn dynamic_property_names(&self) -> QList<QByteArray>;
+
+    /// Schedules this object for deletion.
+    ///
+    /// The object will be deleted when control returns to the event loop.
+    ///
+    /// # Safety
+    ///
+    /// The object must not be owned by Rust, for example by a [`cxx::UniquePtr`], as it would then be deleted twice.
+    /// The object must not be used after it has been deleted.
+    unsafe fn delete_later(self: Pin<&mut Self>);
+
+    /// Returns the thread in which the object lives.
+    fn thread(&self) -> *mut QThread;
+
+    /// Changes the thread affinity for this object and its children.
+    /// The object cannot be moved if it has a parent. Event processing will continue in the `thread`.
+    ///
+    /// Returns `true` if the object was moved to `thread`, otherwise returns `false`.
+    ///
+    /// # Safety
+    ///
+    /// `thread` must be null or a valid pointer to a [`QThread`].
+    /// After the move, the object and its children are accessed from `thread`,
+    /// so any Rust data they contain must be safe to be sent to it.
+    unsafe fn move_to_thread(self: Pin<&mut Self>, thread: *mut QThread) -> bool;
+
+    /// Connects the signal of this object with the given name to the closure,
+    /// which receives the arguments of the signal as a [`Vec`] of [`QVariant`].
+    ///
+    /// The signal is resolved at runtime using the [`QMetaObject`](https://doc.qt.io/qt/qmetaobject.html) of the object,
+    /// `signal` is either the name of the signal, such as `"valueChanged"`, or the signature of the signal,
+    /// such as `"valueChanged(int)"`, which is required to select a specific overload.
+    ///
+    /// If the signal cannot be found an invalid [`QMetaObjectConnection`] is returned.
+    ///
+    /// The closure is dropped once the connection is disconnected, a [`ConnectionType::SingleShotConnection`]
+    /// has been invoked, or the object is destroyed.
+    ///
+    /// Note that Qt does not notify a receiver when it is disconnected from a signal, so the returned connection
+    /// is a handle which also disconnects the signal, rather than the connection to the signal itself.
+    /// Disconnecting the signal in C++ with `QObject::disconnect` on the object, instead of with the returned
+    /// connection, stops the closure from being called but only drops it once the object is destroyed.
+    /// If every connection of the object is disconnected, for example with `QObject::disconnect()`,
+    /// then the closure is never dropped.
+    ///
+    /// Note that [`ConnectionType::UniqueConnection`] has no effect, as every connection uses a new receiver.
+    fn connect_by_name<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> QMetaObjectConnection
+    where
+        F: FnMut(Vec<QVariant>) + Send + 'static;
 }
 
 /// Used to convert the QObject type from the library type to the C++ type, as a pin
@@ -87,6 +256,33 @@ fn uncast(obj_extern: *mut QObjectExternal) -> *mut QObject {
     obj_extern.cast()
 }
 
+fn matches_name(obj: &QObject, name: &QString) -> bool {
+    name.is_empty() || obj.object_name() == *name
+}
+
+fn find_child<'a, C: Upcast<QObject>>(obj: &'a QObject, name: &QString) -> Option<&'a C> {
+    let children = obj.children();
+    children
+        .iter()
+        .copied()
+        .filter(|child| matches_name(child, name))
+        .find_map(|child| child.downcast::<C>())
+        .or_else(|| {
+            children
+                .iter()
+                .find_map(|child| find_child::<C>(child, name))
+        })
+}
+
+fn find_children<'a, C: Upcast<QObject>>(obj: &'a QObject, name: &QString, found: &mut Vec<&'a C>) {
+    for child in obj.children() {
+        if matches_name(child, name) {
+            found.extend(child.downcast::<C>());
+        }
+        find_children(child, name, found);
+    }
+}
+
 impl<T> QObjectExt for T
 where
     T: Upcast<QObject>,
@@ -99,6 +295,11 @@ where
         cast(self.upcast()).signals_blocked()
     }
 
+    fn meta_object(&self) -> &QMetaObject {
+        // SAFETY: a QObject always has a meta-object which lives at least as long as the object
+        unsafe { &*cast(self.upcast()).meta_object() }
+    }
+
     fn set_object_name(self: Pin<&mut Self>, name: &QString) {
         cast_pin(self.upcast_pin()).set_object_name(name)
     }
@@ -119,4 +320,65 @@ where
     fn unset_parent(self: Pin<&mut Self>) {
         unsafe { cast_pin(self.upcast_pin()).set_parent(ptr::null_mut()) }
     }
+
+    fn children(&self) -> Vec<&QObject> {
+        let obj = cast(self.upcast());
+        (0..ffi::qobject_child_count(obj))
+            // SAFETY: the children are owned by this object, so live at least as long as it
+            .map(|index| unsafe { &*uncast(ffi::qobject_child(obj, index)) })
+            .collect()
+    }
+
+    fn find_child<C: Upcast<QObject>>(&self, name: &str) -> Option<&C> {
+        find_child(self.upcast(), &QString::from(name))
+    }
+
+    fn find_children<C: Upcast<QObject>>(&self, name: &str) -> Vec<&C> {
+        let mut found = Vec::new();
+        find_children(self.upcast(), &QString::from(name), &mut found);
+        found
+    }
+
+    fn property(&self, name: &str) -> QVariant {
+        ffi::qobject_property(cast(self.upcast()), name)
+    }
+
+    fn set_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool {
+        ffi::qobject_set_property(cast_pin(self.upcast_pin()), name, value)
+    }
+
+    fn dynamic_property_names(&self) -> QList<QByteArray> {
+        cast(self.upcast()).dynamic_property_names()
+    }
+
+    unsafe fn delete_later(self: Pin<&mut Self>) {
+        cast_pin(self.upcast_pin()).delete_later()
+    }
+
+    fn thread(&self) -> *mut QThread {
+        cast(self.upcast()).thread()
+    }
+
+    unsafe fn move_to_thread(self: Pin<&mut Self>, thread: *mut QThread) -> bool {
+        ffi::qobject_move_to_thread(cast_pin(self.upcast_pin()), thread)
+    }
+
+    fn connect_by_name<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> QMetaObjectConnection
+    where
+        F: FnMut(Vec<QVariant>) + Send + 'static,
+    {
+        ffi::qobject_connect_by_name(
+            cast_pin(self.upcast_pin()),
+            signal,
+            Box::new(QObjectDynamicSignalClosure {
+                closure: Box::new(closure),
+            }),
+            conn_type,
+        )
+    }
 }
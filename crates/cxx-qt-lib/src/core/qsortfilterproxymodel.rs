@@ -0,0 +1,312 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QAbstractItemModel, QModelIndex};
+use core::pin::Pin;
+use cxx_qt::{casting::Upcast, impl_transitive_cast, CxxQtType, QObject};
+
+#[cxx_qt::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type CaseSensitivity = crate::CaseSensitivity;
+        type SortOrder = crate::SortOrder;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = crate::QAbstractItemModel;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qsortfilterproxymodel.h");
+        /// The `QSortFilterProxyModel` class provides support for sorting and filtering data passed between another model and a view.
+        ///
+        /// This can be used as a `#[base]` of a QObject in a bridge, so that `filterAcceptsRow` and `lessThan` can be overridden with `#[cxx_override]`.
+        /// For closures use a [`SortFilterProxyModel`](crate::SortFilterProxyModel) instead.
+        ///
+        /// Qt Documentation: [QSortFilterProxyModel](https://doc.qt.io/qt/qsortfilterproxymodel.html#details)
+        #[qobject]
+        #[base = QAbstractItemModel]
+        type QSortFilterProxyModel;
+    }
+
+    unsafe extern "C++" {
+        /// Returns true if the proxy model is dynamically sorted and filtered whenever the contents of the source model change.
+        #[rust_name = "dynamic_sort_filter"]
+        fn dynamicSortFilter(self: &QSortFilterProxyModel) -> bool;
+
+        /// Returns the case sensitivity of the filter.
+        #[rust_name = "filter_case_sensitivity"]
+        fn filterCaseSensitivity(self: &QSortFilterProxyModel) -> CaseSensitivity;
+
+        /// Returns the column where the key used to filter the contents of the source model is read from.
+        ///
+        /// A value of -1 means that the filter reads from all columns.
+        #[rust_name = "filter_key_column"]
+        fn filterKeyColumn(self: &QSortFilterProxyModel) -> i32;
+
+        /// Returns the item role that is used to query the source model's data when filtering items.
+        #[rust_name = "filter_role"]
+        fn filterRole(self: &QSortFilterProxyModel) -> i32;
+
+        /// Invalidates the current sorting and filtering.
+        fn invalidate(self: Pin<&mut QSortFilterProxyModel>);
+
+        /// Returns true if the filter is recursive, so that a parent is accepted when any of its children are accepted.
+        #[rust_name = "is_recursive_filtering_enabled"]
+        fn isRecursiveFilteringEnabled(self: &QSortFilterProxyModel) -> bool;
+
+        /// Returns the model index in the proxy model that corresponds to the `source_index` from the source model.
+        #[rust_name = "map_from_source"]
+        fn mapFromSource(self: &QSortFilterProxyModel, source_index: &QModelIndex) -> QModelIndex;
+
+        /// Returns the source model index corresponding to the given `proxy_index` from the sorting filter model.
+        #[rust_name = "map_to_source"]
+        fn mapToSource(self: &QSortFilterProxyModel, proxy_index: &QModelIndex) -> QModelIndex;
+
+        /// Sets whether the proxy model is dynamically sorted and filtered whenever the contents of the source model change.
+        #[rust_name = "set_dynamic_sort_filter"]
+        fn setDynamicSortFilter(self: Pin<&mut QSortFilterProxyModel>, enable: bool);
+
+        /// Sets the case sensitivity of the filter.
+        #[rust_name = "set_filter_case_sensitivity"]
+        fn setFilterCaseSensitivity(self: Pin<&mut QSortFilterProxyModel>, cs: CaseSensitivity);
+
+        /// Sets the fixed string used to filter the contents of the source model.
+        #[rust_name = "set_filter_fixed_string"]
+        fn setFilterFixedString(self: Pin<&mut QSortFilterProxyModel>, pattern: &QString);
+
+        /// Sets the column where the key used to filter the contents of the source model is read from.
+        #[rust_name = "set_filter_key_column"]
+        fn setFilterKeyColumn(self: Pin<&mut QSortFilterProxyModel>, column: i32);
+
+        /// Sets the item role that is used to query the source model's data when filtering items.
+        #[rust_name = "set_filter_role"]
+        fn setFilterRole(self: Pin<&mut QSortFilterProxyModel>, role: i32);
+
+        /// Sets the wildcard expression used to filter the contents of the source model.
+        #[rust_name = "set_filter_wildcard"]
+        fn setFilterWildcard(self: Pin<&mut QSortFilterProxyModel>, pattern: &QString);
+
+        /// Sets whether the filter is recursive, so that a parent is accepted when any of its children are accepted.
+        #[rust_name = "set_recursive_filtering_enabled"]
+        fn setRecursiveFilteringEnabled(self: Pin<&mut QSortFilterProxyModel>, recursive: bool);
+
+        /// Sets the case sensitivity setting used for comparing strings when sorting.
+        #[rust_name = "set_sort_case_sensitivity"]
+        fn setSortCaseSensitivity(self: Pin<&mut QSortFilterProxyModel>, cs: CaseSensitivity);
+
+        /// Sets the item role that is used to query the source model's data when sorting items.
+        #[rust_name = "set_sort_role"]
+        fn setSortRole(self: Pin<&mut QSortFilterProxyModel>, role: i32);
+
+        /// Sets the given `source_model` to be processed by the proxy model.
+        ///
+        /// # Safety
+        ///
+        /// `source_model` must be null or a valid pointer to a model.
+        #[rust_name = "set_source_model_raw"]
+        unsafe fn setSourceModel(
+            self: Pin<&mut QSortFilterProxyModel>,
+            source_model: *mut QAbstractItemModel,
+        );
+
+        /// Sorts the model by `column` in the given `order`.
+        ///
+        /// If `column` is less than zero the model will be sorted by the source model row.
+        fn sort(self: Pin<&mut QSortFilterProxyModel>, column: i32, order: SortOrder);
+
+        /// Returns the case sensitivity setting used for comparing strings when sorting.
+        #[rust_name = "sort_case_sensitivity"]
+        fn sortCaseSensitivity(self: &QSortFilterProxyModel) -> CaseSensitivity;
+
+        /// Returns the column currently used for sorting, or -1 if the model is not sorted.
+        #[rust_name = "sort_column"]
+        fn sortColumn(self: &QSortFilterProxyModel) -> i32;
+
+        /// Returns the order currently used for sorting.
+        #[rust_name = "sort_order"]
+        fn sortOrder(self: &QSortFilterProxyModel) -> SortOrder;
+
+        /// Returns the item role that is used to query the source model's data when sorting items.
+        #[rust_name = "sort_role"]
+        fn sortRole(self: &QSortFilterProxyModel) -> i32;
+
+        /// Returns the model that contains the data that is available through the proxy model.
+        #[rust_name = "source_model"]
+        fn sourceModel(self: &QSortFilterProxyModel) -> *mut QAbstractItemModel;
+    }
+
+    extern "RustQt" {
+        /// A `QSortFilterProxyModel` where filtering and sorting are implemented with Rust closures.
+        #[qobject]
+        #[base = QSortFilterProxyModel]
+        #[namespace = "rust::cxxqtlib1"]
+        type SortFilterProxyModel = super::SortFilterProxyModelRust;
+    }
+
+    extern "RustQt" {
+        #[doc(hidden)]
+        #[cxx_override]
+        #[cxx_name = "filterAcceptsRow"]
+        fn filter_accepts_row(
+            self: &SortFilterProxyModel,
+            source_row: i32,
+            source_parent: &QModelIndex,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[cxx_override]
+        #[cxx_name = "lessThan"]
+        fn less_than(
+            self: &SortFilterProxyModel,
+            source_left: &QModelIndex,
+            source_right: &QModelIndex,
+        ) -> bool;
+    }
+
+    unsafe extern "RustQt" {
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "filterAcceptsRow"]
+        fn base_filter_accepts_row(
+            self: &SortFilterProxyModel,
+            source_row: i32,
+            source_parent: &QModelIndex,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "lessThan"]
+        fn base_less_than(
+            self: &SortFilterProxyModel,
+            source_left: &QModelIndex,
+            source_right: &QModelIndex,
+        ) -> bool;
+
+        /// Invalidates the current filtering, use this when the filter closure depends on state which has changed.
+        #[inherit]
+        #[cxx_name = "invalidateFilter"]
+        fn invalidate_filter(self: Pin<&mut SortFilterProxyModel>);
+
+        /// Invalidates the current filtering of rows only, which is cheaper than [`invalidate_filter`](Self::invalidate_filter) when columns are not filtered.
+        ///
+        /// This function was introduced in Qt 6.0.
+        #[cfg(cxxqt_qt_version_major = "6")]
+        #[inherit]
+        #[cxx_name = "invalidateRowsFilter"]
+        fn invalidate_rows_filter(self: Pin<&mut SortFilterProxyModel>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "sortfilterproxymodel_new"]
+        fn make_unique() -> UniquePtr<SortFilterProxyModel>;
+    }
+
+    impl UniquePtr<SortFilterProxyModel> {}
+}
+
+pub use ffi::{QSortFilterProxyModel, SortFilterProxyModel};
+
+impl_transitive_cast!(QSortFilterProxyModel, QAbstractItemModel, QObject);
+impl_transitive_cast!(
+    SortFilterProxyModel,
+    QSortFilterProxyModel,
+    QAbstractItemModel,
+    QObject
+);
+
+impl QSortFilterProxyModel {
+    /// Sets the given `source_model` to be processed by the proxy model.
+    ///
+    /// The proxy model does not take ownership of the source model,
+    /// if the source model is destroyed the proxy model is left without a source model.
+    pub fn set_source_model<M: Upcast<QAbstractItemModel>>(
+        self: Pin<&mut Self>,
+        source_model: Pin<&mut M>,
+    ) {
+        // SAFETY: the source model is a valid model and Qt tracks its destruction
+        unsafe {
+            self.set_source_model_raw(
+                Upcast::<QAbstractItemModel>::upcast_pin(source_model).get_unchecked_mut(),
+            );
+        }
+    }
+}
+
+type FilterAcceptsRowFn = dyn Fn(&SortFilterProxyModel, i32, &QModelIndex) -> bool;
+type LessThanFn = dyn Fn(&SortFilterProxyModel, &QModelIndex, &QModelIndex) -> bool;
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct SortFilterProxyModelRust {
+    filter_accepts_row: Option<Box<FilterAcceptsRowFn>>,
+    less_than: Option<Box<LessThanFn>>,
+}
+
+impl SortFilterProxyModel {
+    /// Create a new `SortFilterProxyModel` which behaves like a `QSortFilterProxyModel` until closures are set.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::sortfilterproxymodel_new()
+    }
+
+    /// Set the closure which decides if the item in `source_row` of the `source_parent` should be included in the model.
+    ///
+    /// The filter is invalidated so that the closure is applied to the existing rows.
+    pub fn set_filter_accepts_row<F>(mut self: Pin<&mut Self>, filter_accepts_row: F)
+    where
+        F: Fn(&Self, i32, &QModelIndex) -> bool + 'static,
+    {
+        self.as_mut().rust_mut().filter_accepts_row = Some(Box::new(filter_accepts_row));
+        self.invalidate_filter();
+    }
+
+    /// Remove the filter closure, so that the filter of the `QSortFilterProxyModel` is used.
+    pub fn clear_filter_accepts_row(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().filter_accepts_row = None;
+        self.invalidate_filter();
+    }
+
+    /// Set the closure which returns true if the value of the item referred to by `source_left` is less than the value of the item referred to by `source_right`.
+    ///
+    /// The model is sorted again if it is already sorted.
+    pub fn set_less_than<F>(mut self: Pin<&mut Self>, less_than: F)
+    where
+        F: Fn(&Self, &QModelIndex, &QModelIndex) -> bool + 'static,
+    {
+        self.as_mut().rust_mut().less_than = Some(Box::new(less_than));
+        Upcast::<QSortFilterProxyModel>::upcast_pin(self).invalidate();
+    }
+
+    /// Remove the less than closure, so that the sorting of the `QSortFilterProxyModel` is used.
+    pub fn clear_less_than(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().less_than = None;
+        Upcast::<QSortFilterProxyModel>::upcast_pin(self).invalidate();
+    }
+
+    fn filter_accepts_row(&self, source_row: i32, source_parent: &QModelIndex) -> bool {
+        match &self.rust().filter_accepts_row {
+            Some(filter_accepts_row) => filter_accepts_row(self, source_row, source_parent),
+            None => self.base_filter_accepts_row(source_row, source_parent),
+        }
+    }
+
+    fn less_than(&self, source_left: &QModelIndex, source_right: &QModelIndex) -> bool {
+        match &self.rust().less_than {
+            Some(less_than) => less_than(self, source_left, source_right),
+            None => self.base_less_than(source_left, source_right),
+        }
+    }
+}
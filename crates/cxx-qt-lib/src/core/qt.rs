@@ -283,6 +283,32 @@ mod ffi {
         ExtraButton24 = 0x04000000,
     }
 
+    /// This enum describes the properties of an item in a model.
+    #[derive(Debug)]
+    #[repr(u32)]
+    enum ItemFlag {
+        /// It does not have any properties set.
+        NoItemFlags = 0,
+        /// It can be selected.
+        ItemIsSelectable = 1,
+        /// It can be edited.
+        ItemIsEditable = 2,
+        /// It can be dragged.
+        ItemIsDragEnabled = 4,
+        /// It can be used as a drop target.
+        ItemIsDropEnabled = 8,
+        /// It can be checked or unchecked by the user.
+        ItemIsUserCheckable = 16,
+        /// The user can interact with the item.
+        ItemIsEnabled = 32,
+        /// The item's state depends on the state of its children.
+        ItemIsAutoTristate = 64,
+        /// The item never has child items.
+        ItemNeverHasChildren = 128,
+        /// The user can cycle through three separate states.
+        ItemIsUserTristate = 256,
+    }
+
     #[derive(Debug)]
     #[repr(u32)]
     /// This type is used to signify an object's orientation.
@@ -293,6 +319,15 @@ mod ffi {
         Vertical = 0x2,
     }
 
+    /// This enum describes how the items in a widget are sorted.
+    #[repr(i32)]
+    enum SortOrder {
+        /// The items are sorted ascending e.g. starts with 'AAA' ends with 'ZZZ' in Latin-1 locales
+        AscendingOrder,
+        /// The items are sorted descending e.g. starts with 'ZZZ' ends with 'AAA' in Latin-1 locales
+        DescendingOrder,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
         type AspectRatioMode;
@@ -313,13 +348,15 @@ mod ffi {
         type KeyboardModifier;
         type Orientation;
         type GlobalColor;
+        type ItemFlag;
+        type SortOrder;
     }
 }
 
 pub use ffi::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, GlobalColor,
-    KeyboardModifier, LayoutDirection, MouseButton, Orientation, PenCapStyle, PenJoinStyle,
-    PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec, TransformationMode,
+    ItemFlag, KeyboardModifier, LayoutDirection, MouseButton, Orientation, PenCapStyle,
+    PenJoinStyle, PenStyle, SizeMode, SortOrder, SplitBehaviorFlags, TimeSpec, TransformationMode,
 };
 
 // Reexport ConnectionType from cxx-qt
@@ -331,7 +368,10 @@ pub type MouseButtons = QFlags<MouseButton>;
 pub type KeyboardModifiers = QFlags<KeyboardModifier>;
 /// [`QFlags`] of [`Orientation`].
 pub type Orientations = QFlags<Orientation>;
+/// [`QFlags`] of [`ItemFlag`].
+pub type ItemFlags = QFlags<ItemFlag>;
 
 unsafe_impl_qflag!(MouseButton, "Qt::MouseButtons", u32);
 unsafe_impl_qflag!(KeyboardModifier, "Qt::KeyboardModifiers", u32);
 unsafe_impl_qflag!(Orientation, "Qt::Orientations", u32);
+unsafe_impl_qflag!(ItemFlag, "Qt::ItemFlags", u32);
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qthread.h");
+        /// The `QThread` class provides a platform-independent way to manage threads.
+        ///
+        /// This is returned by [`QObjectExt::thread`](crate::QObjectExt::thread) and can be passed to
+        /// [`QObjectExt::move_to_thread`](crate::QObjectExt::move_to_thread).
+        ///
+        /// Qt Documentation: [QThread](https://doc.qt.io/qt/qthread.html#details)
+        #[qobject]
+        type QThread;
+
+        /// This signal is emitted from the associated thread when it starts executing, before the run() function is called.
+        #[qsignal]
+        fn started(self: Pin<&mut QThread>);
+
+        /// This signal is emitted from the associated thread right before it finishes executing.
+        #[qsignal]
+        fn finished(self: Pin<&mut QThread>);
+    }
+
+    unsafe extern "C++" {
+        /// Returns `true` if the thread is finished; otherwise returns `false`.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Returns `true` if the thread is running; otherwise returns `false`.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        ///
+        /// This function does nothing if the thread does not have an event loop.
+        fn quit(self: Pin<&mut QThread>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_current_thread"]
+        fn qthreadCurrentThread() -> *mut QThread;
+    }
+}
+
+pub use ffi::QThread;
+
+impl QThread {
+    /// Returns a pointer to the `QThread` which manages the currently executing thread.
+    pub fn current_thread() -> *mut QThread {
+        ffi::qthread_current_thread()
+    }
+}
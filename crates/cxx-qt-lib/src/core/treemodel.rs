@@ -0,0 +1,803 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use super::listmodel::{role_index, role_names, to_row};
+use crate::{ItemFlag, ItemFlags, QModelIndex, QVariant};
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use std::collections::HashMap;
+
+/// The identifier of a node in a [Tree]
+///
+/// This is stored as the internal id of the [QModelIndex] of the node,
+/// an identifier stays the same for the lifetime of the node and is never reused within a [Tree].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TreeNodeId(NonZeroUsize);
+
+impl TreeNodeId {
+    /// The internal id of the [QModelIndex] for an optional node, where zero is the root
+    fn to_internal_id(id: Option<Self>) -> usize {
+        id.map_or(0, |id| id.0.get())
+    }
+
+    /// The node of the internal id of a [QModelIndex], where zero is the root
+    fn from_internal_id(id: usize) -> Option<Self> {
+        NonZeroUsize::new(id).map(Self)
+    }
+}
+
+struct TreeNode<T> {
+    item: T,
+    parent: Option<TreeNodeId>,
+    children: Vec<TreeNodeId>,
+}
+
+/// A tree of items, where each node is addressed by a [TreeNodeId]
+///
+/// A parent of `None` refers to the invisible root of the tree, which contains the top level nodes.
+pub struct Tree<T> {
+    nodes: HashMap<TreeNodeId, TreeNode<T>>,
+    roots: Vec<TreeNodeId>,
+    last_id: usize,
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            roots: Vec::new(),
+            last_id: 0,
+        }
+    }
+}
+
+impl<T> Tree<T> {
+    /// Construct an empty tree
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of nodes in the tree
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the tree has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns true if the node is in the tree
+    pub fn contains(&self, id: TreeNodeId) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    /// The item of the given node
+    pub fn get(&self, id: TreeNodeId) -> Option<&T> {
+        self.nodes.get(&id).map(|node| &node.item)
+    }
+
+    /// The mutable item of the given node
+    pub fn get_mut(&mut self, id: TreeNodeId) -> Option<&mut T> {
+        self.nodes.get_mut(&id).map(|node| &mut node.item)
+    }
+
+    /// The parent of the given node, this is `None` for top level or unknown nodes
+    pub fn parent(&self, id: TreeNodeId) -> Option<TreeNodeId> {
+        self.nodes.get(&id).and_then(|node| node.parent)
+    }
+
+    /// The children of the given parent, in order
+    pub fn children(&self, parent: Option<TreeNodeId>) -> &[TreeNodeId] {
+        match parent {
+            Some(parent) => self
+                .nodes
+                .get(&parent)
+                .map_or(&[], |node| node.children.as_slice()),
+            None => &self.roots,
+        }
+    }
+
+    /// The row of the given node within the children of its parent
+    pub fn row(&self, id: TreeNodeId) -> Option<usize> {
+        let node = self.nodes.get(&id)?;
+        self.children(node.parent)
+            .iter()
+            .position(|child| *child == id)
+    }
+
+    /// Insert an item as a child of the parent at the given row, returning the new node
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent is not in the tree or `row` is greater than the number of children.
+    pub fn insert(&mut self, parent: Option<TreeNodeId>, row: usize, item: T) -> TreeNodeId {
+        self.last_id += 1;
+        let id = TreeNodeId::from_internal_id(self.last_id)
+            .expect("the identifier of a new node should not be zero");
+
+        let children = self
+            .children_mut(parent)
+            .expect("parent should be in the tree");
+        let len = children.len();
+        assert!(
+            row <= len,
+            "insertion row (is {row}) should be <= number of children (is {len})"
+        );
+        children.insert(row, id);
+
+        self.nodes.insert(
+            id,
+            TreeNode {
+                item,
+                parent,
+                children: Vec::new(),
+            },
+        );
+        id
+    }
+
+    /// Append an item as the last child of the parent, returning the new node
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent is not in the tree.
+    pub fn push(&mut self, parent: Option<TreeNodeId>, item: T) -> TreeNodeId {
+        let row = self.children(parent).len();
+        self.insert(parent, row, item)
+    }
+
+    /// Remove the node and all of its descendants from the tree, returning the item of the node
+    pub fn remove(&mut self, id: TreeNodeId) -> Option<T> {
+        let node = self.nodes.remove(&id)?;
+        if let Some(siblings) = self.children_mut(node.parent) {
+            siblings.retain(|sibling| *sibling != id);
+        }
+
+        let mut descendants = node.children;
+        while let Some(descendant) = descendants.pop() {
+            if let Some(descendant) = self.nodes.remove(&descendant) {
+                descendants.extend(descendant.children);
+            }
+        }
+
+        Some(node.item)
+    }
+
+    /// Move the node and its descendants so that it is a child of the parent at the given row
+    ///
+    /// The row is the position of the node once it has been moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or the parent is not in the tree, the parent is the node or one of its
+    /// descendants, or `row` is greater than the number of other children of the parent.
+    pub fn move_node(&mut self, id: TreeNodeId, parent: Option<TreeNodeId>, row: usize) {
+        let from_parent = self.check_move(id, parent, row).0;
+        if let Some(siblings) = self.children_mut(from_parent) {
+            siblings.retain(|sibling| *sibling != id);
+        }
+        if let Some(children) = self.children_mut(parent) {
+            children.insert(row, id);
+        }
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.parent = parent;
+        }
+    }
+
+    /// Check that the node can be moved, returning the current parent and row of the node
+    fn check_move(
+        &self,
+        id: TreeNodeId,
+        parent: Option<TreeNodeId>,
+        row: usize,
+    ) -> (Option<TreeNodeId>, usize) {
+        let from_row = self.row(id).expect("node should be in the tree");
+        let from_parent = self.parent(id);
+
+        let mut ancestor = parent;
+        while let Some(current) = ancestor {
+            assert!(self.contains(current), "parent should be in the tree");
+            assert!(
+                current != id,
+                "node should not be moved into itself or its descendants"
+            );
+            ancestor = self.parent(current);
+        }
+
+        let mut len = self.children(parent).len();
+        if from_parent == parent {
+            len -= 1;
+        }
+        assert!(
+            row <= len,
+            "move row (is {row}) should be <= number of other children (is {len})"
+        );
+        (from_parent, from_row)
+    }
+
+    fn children_mut(&mut self, parent: Option<TreeNodeId>) -> Option<&mut Vec<TreeNodeId>> {
+        match parent {
+            Some(parent) => self.nodes.get_mut(&parent).map(|node| &mut node.children),
+            None => Some(&mut self.roots),
+        }
+    }
+}
+
+/// An item which is stored in a [TreeModel]
+///
+/// As with a [ListModelItem](crate::ListModelItem) each of the [ROLE_NAMES](TreeModelItem::ROLE_NAMES)
+/// is a role of the model starting at `Qt::UserRole`, with `Qt::DisplayRole` and `Qt::EditRole`
+/// mapped to the first role name.
+pub trait TreeModelItem {
+    /// The names of the roles of the item, as used in QML delegates
+    const ROLE_NAMES: &'static [&'static str];
+
+    /// The number of columns of the model
+    const COLUMN_COUNT: usize = 1;
+
+    /// Retrieve the data for the given column and index into [ROLE_NAMES](TreeModelItem::ROLE_NAMES)
+    fn data(&self, column: usize, role: usize) -> QVariant;
+
+    /// Set the data for the given column and index into [ROLE_NAMES](TreeModelItem::ROLE_NAMES)
+    ///
+    /// Returns true if the data was set, by default items are read-only and this returns false.
+    fn set_data(&mut self, _column: usize, _role: usize, _value: &QVariant) -> bool {
+        false
+    }
+
+    /// The flags of the given column, by default items are selectable and enabled
+    fn flags(&self, _column: usize) -> ItemFlags {
+        ItemFlag::ItemIsSelectable | ItemFlag::ItemIsEnabled
+    }
+}
+
+/// A QAbstractItemModel which is backed by a [Tree] of [TreeModelItem]s
+///
+/// This is implemented by CXX-Qt for a QObject with a `#[tree_model(T, field)]` attribute.
+/// The methods of this trait modify the tree and notify any views of the change,
+/// so that the correct `begin` and `end` methods of the model are always called.
+pub trait TreeModel {
+    /// The type of the items in the tree
+    type Item: TreeModelItem;
+
+    #[doc(hidden)]
+    fn cxx_qt_tree(&self) -> &Tree<Self::Item>;
+    #[doc(hidden)]
+    fn cxx_qt_tree_mut(self: Pin<&mut Self>) -> &mut Tree<Self::Item>;
+    #[doc(hidden)]
+    fn cxx_qt_create_index(&self, id: usize, column: i32) -> QModelIndex;
+    #[doc(hidden)]
+    fn cxx_qt_begin_insert_rows(self: Pin<&mut Self>, parent: usize, first: i32, last: i32);
+    #[doc(hidden)]
+    fn cxx_qt_end_insert_rows(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_begin_remove_rows(self: Pin<&mut Self>, parent: usize, first: i32, last: i32);
+    #[doc(hidden)]
+    fn cxx_qt_end_remove_rows(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_begin_move_rows(
+        self: Pin<&mut Self>,
+        source_parent: usize,
+        first: i32,
+        last: i32,
+        destination_parent: usize,
+        destination: i32,
+    ) -> bool;
+    #[doc(hidden)]
+    fn cxx_qt_end_move_rows(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_begin_reset_model(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_end_reset_model(self: Pin<&mut Self>);
+    #[doc(hidden)]
+    fn cxx_qt_node_changed(self: Pin<&mut Self>, id: usize);
+
+    /// The tree of items
+    fn tree(&self) -> &Tree<Self::Item> {
+        self.cxx_qt_tree()
+    }
+
+    /// The [QModelIndex] of the given node and column, this is invalid if the node is not in the tree
+    ///
+    /// This can be stored as a [QPersistentModelIndex](crate::QPersistentModelIndex) which is updated as the tree changes.
+    fn index(&self, id: TreeNodeId, column: usize) -> QModelIndex {
+        if self.tree().contains(id) {
+            self.cxx_qt_create_index(TreeNodeId::to_internal_id(Some(id)), to_row(column))
+        } else {
+            QModelIndex::default()
+        }
+    }
+
+    /// The node of the given [QModelIndex], if it is a valid index of a node in the tree
+    fn node_id(&self, index: &QModelIndex) -> Option<TreeNodeId> {
+        if !index.is_valid() {
+            return None;
+        }
+        TreeNodeId::from_internal_id(index.internal_id()).filter(|id| self.tree().contains(*id))
+    }
+
+    /// Insert an item as a child of the parent at the given row, returning the new node
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent is not in the tree or `row` is greater than the number of children.
+    fn insert(
+        mut self: Pin<&mut Self>,
+        parent: Option<TreeNodeId>,
+        row: usize,
+        item: Self::Item,
+    ) -> TreeNodeId {
+        if let Some(parent) = parent {
+            assert!(self.tree().contains(parent), "parent should be in the tree");
+        }
+        let len = self.tree().children(parent).len();
+        assert!(
+            row <= len,
+            "insertion row (is {row}) should be <= number of children (is {len})"
+        );
+
+        self.as_mut().cxx_qt_begin_insert_rows(
+            TreeNodeId::to_internal_id(parent),
+            to_row(row),
+            to_row(row),
+        );
+        let id = self.as_mut().cxx_qt_tree_mut().insert(parent, row, item);
+        self.cxx_qt_end_insert_rows();
+        id
+    }
+
+    /// Append an item as the last child of the parent, returning the new node
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent is not in the tree.
+    fn push(self: Pin<&mut Self>, parent: Option<TreeNodeId>, item: Self::Item) -> TreeNodeId {
+        let row = self.tree().children(parent).len();
+        self.insert(parent, row, item)
+    }
+
+    /// Remove the node and all of its descendants, returning the item of the node
+    fn remove(mut self: Pin<&mut Self>, id: TreeNodeId) -> Option<Self::Item> {
+        let row = self.tree().row(id)?;
+        let parent = self.tree().parent(id);
+
+        self.as_mut().cxx_qt_begin_remove_rows(
+            TreeNodeId::to_internal_id(parent),
+            to_row(row),
+            to_row(row),
+        );
+        let item = self.as_mut().cxx_qt_tree_mut().remove(id);
+        self.cxx_qt_end_remove_rows();
+        item
+    }
+
+    /// Move the node and its descendants so that it is a child of the parent at the given row
+    ///
+    /// The row is the position of the node once it has been moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or the parent is not in the tree, the parent is the node or one of its
+    /// descendants, or `row` is greater than the number of other children of the parent.
+    fn move_node(mut self: Pin<&mut Self>, id: TreeNodeId, parent: Option<TreeNodeId>, row: usize) {
+        let (from_parent, from_row) = self.tree().check_move(id, parent, row);
+        if from_parent == parent && from_row == row {
+            return;
+        }
+
+        // Qt expects the row which the node is placed before, prior to the node being removed
+        let destination = if from_parent == parent && row > from_row {
+            row + 1
+        } else {
+            row
+        };
+        if self.as_mut().cxx_qt_begin_move_rows(
+            TreeNodeId::to_internal_id(from_parent),
+            to_row(from_row),
+            to_row(from_row),
+            TreeNodeId::to_internal_id(parent),
+            to_row(destination),
+        ) {
+            self.as_mut().cxx_qt_tree_mut().move_node(id, parent, row);
+            self.cxx_qt_end_move_rows();
+        }
+    }
+
+    /// Replace the item of the given node, returning the previous item
+    fn set(self: Pin<&mut Self>, id: TreeNodeId, item: Self::Item) -> Option<Self::Item> {
+        self.update(id, |current| core::mem::replace(current, item))
+    }
+
+    /// Modify the item of the given node with a closure and notify views that the data has changed
+    fn update<R, F: FnOnce(&mut Self::Item) -> R>(
+        mut self: Pin<&mut Self>,
+        id: TreeNodeId,
+        f: F,
+    ) -> Option<R> {
+        let result = f(self.as_mut().cxx_qt_tree_mut().get_mut(id)?);
+        self.cxx_qt_node_changed(TreeNodeId::to_internal_id(Some(id)));
+        Some(result)
+    }
+
+    /// Replace the whole tree, returning the previous tree
+    fn reset(self: Pin<&mut Self>, tree: Tree<Self::Item>) -> Tree<Self::Item> {
+        self.reset_with(|current| core::mem::replace(current, tree))
+    }
+
+    /// Modify the whole tree with a closure and reset any views
+    fn reset_with<R, F: FnOnce(&mut Tree<Self::Item>) -> R>(mut self: Pin<&mut Self>, f: F) -> R {
+        self.as_mut().cxx_qt_begin_reset_model();
+        let result = f(self.as_mut().cxx_qt_tree_mut());
+        self.cxx_qt_end_reset_model();
+        result
+    }
+
+    /// Remove all of the nodes in the tree
+    fn clear(self: Pin<&mut Self>) {
+        self.reset(Tree::new());
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_child(&self, parent: usize, row: i32) -> usize {
+        let parent = TreeNodeId::from_internal_id(parent);
+        let child = usize::try_from(row)
+            .ok()
+            .and_then(|row| self.tree().children(parent).get(row).copied());
+        TreeNodeId::to_internal_id(child)
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_parent(&self, id: usize) -> usize {
+        let parent = TreeNodeId::from_internal_id(id).and_then(|id| self.tree().parent(id));
+        TreeNodeId::to_internal_id(parent)
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_row(&self, id: usize) -> i32 {
+        TreeNodeId::from_internal_id(id)
+            .and_then(|id| self.tree().row(id))
+            .map_or(-1, to_row)
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_row_count(&self, parent: usize) -> i32 {
+        let parent = TreeNodeId::from_internal_id(parent);
+        i32::try_from(self.tree().children(parent).len()).unwrap_or(i32::MAX)
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_column_count(&self) -> i32 {
+        to_row(Self::Item::COLUMN_COUNT)
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_data(&self, id: usize, column: i32, role: i32) -> QVariant {
+        let item = TreeNodeId::from_internal_id(id).and_then(|id| self.tree().get(id));
+        match (
+            item,
+            usize::try_from(column).ok(),
+            role_index(role, Self::Item::ROLE_NAMES.len()),
+        ) {
+            (Some(item), Some(column), Some(role)) => item.data(column, role),
+            _ => QVariant::default(),
+        }
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_set_data(
+        self: Pin<&mut Self>,
+        id: usize,
+        column: i32,
+        role: i32,
+        value: &QVariant,
+    ) -> bool {
+        let (Some(column), Some(role)) = (
+            usize::try_from(column).ok(),
+            role_index(role, Self::Item::ROLE_NAMES.len()),
+        ) else {
+            return false;
+        };
+        TreeNodeId::from_internal_id(id)
+            .and_then(|id| self.cxx_qt_tree_mut().get_mut(id))
+            .is_some_and(|item| item.set_data(column, role, value))
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_flags(&self, id: usize, column: i32) -> i32 {
+        let item = TreeNodeId::from_internal_id(id).and_then(|id| self.tree().get(id));
+        match (item, usize::try_from(column).ok()) {
+            (Some(item), Some(column)) => item.flags(column).to_int() as i32,
+            _ => 0,
+        }
+    }
+
+    #[doc(hidden)]
+    fn cxx_qt_role_names() -> Vec<String> {
+        role_names(Self::Item::ROLE_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item(i32);
+
+    impl TreeModelItem for Item {
+        const ROLE_NAMES: &'static [&'static str] = &["value"];
+
+        fn data(&self, _column: usize, _role: usize) -> QVariant {
+            QVariant::default()
+        }
+    }
+
+    #[derive(Default)]
+    struct Model {
+        tree: Tree<Item>,
+        calls: Vec<String>,
+    }
+
+    impl Model {
+        fn values(&self, parent: Option<TreeNodeId>) -> Vec<i32> {
+            self.tree
+                .children(parent)
+                .iter()
+                .map(|id| self.tree.get(*id).unwrap().0)
+                .collect()
+        }
+
+        fn take_calls(&mut self) -> Vec<String> {
+            core::mem::take(&mut self.calls)
+        }
+    }
+
+    /// The internal id of a node as it is passed to the model
+    fn internal_id(id: TreeNodeId) -> usize {
+        TreeNodeId::to_internal_id(Some(id))
+    }
+
+    impl TreeModel for Model {
+        type Item = Item;
+
+        fn cxx_qt_tree(&self) -> &Tree<Item> {
+            &self.tree
+        }
+        fn cxx_qt_tree_mut(self: Pin<&mut Self>) -> &mut Tree<Item> {
+            &mut self.get_mut().tree
+        }
+        fn cxx_qt_create_index(&self, _id: usize, _column: i32) -> QModelIndex {
+            QModelIndex::default()
+        }
+        fn cxx_qt_begin_insert_rows(self: Pin<&mut Self>, parent: usize, first: i32, last: i32) {
+            self.get_mut()
+                .calls
+                .push(format!("begin_insert {parent} {first} {last}"));
+        }
+        fn cxx_qt_end_insert_rows(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_insert".to_owned());
+        }
+        fn cxx_qt_begin_remove_rows(self: Pin<&mut Self>, parent: usize, first: i32, last: i32) {
+            self.get_mut()
+                .calls
+                .push(format!("begin_remove {parent} {first} {last}"));
+        }
+        fn cxx_qt_end_remove_rows(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_remove".to_owned());
+        }
+        fn cxx_qt_begin_move_rows(
+            self: Pin<&mut Self>,
+            source_parent: usize,
+            first: i32,
+            last: i32,
+            destination_parent: usize,
+            destination: i32,
+        ) -> bool {
+            self.get_mut().calls.push(format!(
+                "begin_move {source_parent} {first} {last} {destination_parent} {destination}"
+            ));
+            true
+        }
+        fn cxx_qt_end_move_rows(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_move".to_owned());
+        }
+        fn cxx_qt_begin_reset_model(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("begin_reset".to_owned());
+        }
+        fn cxx_qt_end_reset_model(self: Pin<&mut Self>) {
+            self.get_mut().calls.push("end_reset".to_owned());
+        }
+        fn cxx_qt_node_changed(self: Pin<&mut Self>, id: usize) {
+            self.get_mut().calls.push(format!("changed {id}"));
+        }
+    }
+
+    #[test]
+    fn tree_insert_and_remove() {
+        let mut tree = Tree::new();
+        let a = tree.push(None, "a");
+        let b = tree.push(None, "b");
+        let a1 = tree.push(Some(a), "a1");
+        let a0 = tree.insert(Some(a), 0, "a0");
+        let a0x = tree.push(Some(a0), "a0x");
+
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.children(None), &[a, b]);
+        assert_eq!(tree.children(Some(a)), &[a0, a1]);
+        assert_eq!(tree.parent(a1), Some(a));
+        assert_eq!(tree.parent(a), None);
+        assert_eq!(tree.row(a1), Some(1));
+        assert_eq!(tree.row(b), Some(1));
+
+        assert_eq!(tree.remove(a0), Some("a0"));
+        assert!(!tree.contains(a0x));
+        assert_eq!(tree.children(Some(a)), &[a1]);
+        assert_eq!(tree.len(), 3);
+
+        // Identifiers are not reused
+        let c = tree.push(None, "c");
+        assert!(c != a0 && c != a0x);
+        assert_eq!(tree.get(c), Some(&"c"));
+        assert_eq!(tree.remove(a0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tree_insert_unknown_parent() {
+        let mut tree = Tree::new();
+        let a = tree.push(None, 1);
+        tree.remove(a);
+        tree.push(Some(a), 2);
+    }
+
+    #[test]
+    fn tree_move_node() {
+        let mut tree = Tree::new();
+        let a = tree.push(None, "a");
+        let b = tree.push(None, "b");
+        let a0 = tree.push(Some(a), "a0");
+        let a1 = tree.push(Some(a), "a1");
+
+        tree.move_node(a0, Some(a), 1);
+        assert_eq!(tree.children(Some(a)), &[a1, a0]);
+
+        tree.move_node(a1, Some(b), 0);
+        assert_eq!(tree.children(Some(a)), &[a0]);
+        assert_eq!(tree.children(Some(b)), &[a1]);
+        assert_eq!(tree.parent(a1), Some(b));
+
+        tree.move_node(b, None, 0);
+        assert_eq!(tree.children(None), &[b, a]);
+        assert_eq!(tree.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tree_move_into_descendant() {
+        let mut tree = Tree::new();
+        let a = tree.push(None, 1);
+        let a0 = tree.push(Some(a), 2);
+        tree.move_node(a, Some(a0), 0);
+    }
+
+    #[test]
+    fn tree_model_insert_and_remove() {
+        let mut model = Model::default();
+        let a = Pin::new(&mut model).push(None, Item(0));
+        let a1 = Pin::new(&mut model).push(Some(a), Item(2));
+        let a0 = Pin::new(&mut model).insert(Some(a), 0, Item(1));
+        assert_eq!(model.values(None), vec![0]);
+        assert_eq!(model.values(Some(a)), vec![1, 2]);
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                "begin_insert 0 0 0".to_owned(),
+                "end_insert".to_owned(),
+                format!("begin_insert {} 0 0", internal_id(a)),
+                "end_insert".to_owned(),
+                format!("begin_insert {} 0 0", internal_id(a)),
+                "end_insert".to_owned(),
+            ]
+        );
+
+        // The rows are removed from the parent of the node
+        assert_eq!(Pin::new(&mut model).remove(a1).map(|item| item.0), Some(2));
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                format!("begin_remove {} 1 1", internal_id(a)),
+                "end_remove".to_owned()
+            ]
+        );
+
+        // Removing a node removes its descendants without notifying them separately
+        assert_eq!(Pin::new(&mut model).remove(a).map(|item| item.0), Some(0));
+        assert!(!model.tree().contains(a0));
+        assert_eq!(model.take_calls(), vec!["begin_remove 0 0 0", "end_remove"]);
+
+        // Unknown nodes are not removed
+        assert!(Pin::new(&mut model).remove(a).is_none());
+        assert!(model.take_calls().is_empty());
+    }
+
+    #[test]
+    fn tree_model_move_node() {
+        let mut model = Model::default();
+        let a = Pin::new(&mut model).push(None, Item(0));
+        let b = Pin::new(&mut model).push(None, Item(1));
+        let a0 = Pin::new(&mut model).push(Some(a), Item(2));
+        Pin::new(&mut model).push(Some(a), Item(3));
+        model.take_calls();
+
+        // Moving down within the same parent places the node before the row after the destination
+        Pin::new(&mut model).move_node(a0, Some(a), 1);
+        assert_eq!(model.values(Some(a)), vec![3, 2]);
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                format!("begin_move {0} 0 0 {0} 2", internal_id(a)),
+                "end_move".to_owned()
+            ]
+        );
+
+        // Moving to another parent
+        Pin::new(&mut model).move_node(a0, Some(b), 0);
+        assert_eq!(model.values(Some(a)), vec![3]);
+        assert_eq!(model.values(Some(b)), vec![2]);
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                format!("begin_move {} 1 1 {} 0", internal_id(a), internal_id(b)),
+                "end_move".to_owned()
+            ]
+        );
+
+        // Moving up to the root
+        Pin::new(&mut model).move_node(a0, None, 0);
+        assert_eq!(model.values(None), vec![2, 0, 1]);
+        assert_eq!(
+            model.take_calls(),
+            vec![
+                format!("begin_move {} 0 0 0 0", internal_id(b)),
+                "end_move".to_owned()
+            ]
+        );
+
+        // Moving a node to its current position does nothing
+        Pin::new(&mut model).move_node(a0, None, 0);
+        assert!(model.take_calls().is_empty());
+    }
+
+    #[test]
+    fn tree_model_update_and_reset() {
+        let mut model = Model::default();
+        let a = Pin::new(&mut model).push(None, Item(0));
+        model.take_calls();
+
+        assert_eq!(
+            Pin::new(&mut model).set(a, Item(1)).map(|item| item.0),
+            Some(0)
+        );
+        assert_eq!(
+            model.take_calls(),
+            vec![format!("changed {}", internal_id(a))]
+        );
+
+        Pin::new(&mut model).clear();
+        assert!(model.tree().is_empty());
+        assert_eq!(model.take_calls(), vec!["begin_reset", "end_reset"]);
+        assert!(Pin::new(&mut model).set(a, Item(2)).is_none());
+        assert!(model.take_calls().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn tree_model_move_into_descendant() {
+        let mut model = Model::default();
+        let a = Pin::new(&mut model).push(None, Item(0));
+        let a0 = Pin::new(&mut model).push(Some(a), Item(1));
+        Pin::new(&mut model).move_node(a, Some(a0), 0);
+    }
+}
@@ -8,8 +8,8 @@ use cxx_qt_build::CxxQtBuilder;
 fn main() {
     CxxQtBuilder::new()
         .crate_include_root(Some("include".to_owned()))
-        .files(["src/connection.rs", "src/qobject.rs"])
-        .cpp_files(["src/connection.cpp"])
+        .files(["src/connection.rs", "src/qobject.rs", "src/qpointer.rs"])
+        .cpp_files(["src/connection.cpp", "src/qpointer.cpp"])
         .initializer(qt_build_utils::Initializer {
             file: Some("src/init.cpp".into()),
             ..qt_build_utils::Initializer::default_signature("init_cxx_qt_core")
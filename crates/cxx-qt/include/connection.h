@@ -6,6 +6,9 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <memory>
+#include <utility>
+
 #include <QtCore/QObject>
 #include <QtCore/Qt>
 
@@ -26,6 +29,9 @@ namespace cxxqt1 {
 
 using QMetaObjectConnection = ::QMetaObject::Connection;
 
+::QMetaObject::Connection
+qmetaobjectconnectionClone(const ::QMetaObject::Connection& connection);
+
 ::QMetaObject::Connection
 qmetaobjectconnectionDefault();
 
@@ -35,5 +41,46 @@ qmetaobjectconnectionDisconnect(const ::QMetaObject::Connection& connection);
 void
 qmetaobjectconnectionDrop(::QMetaObject::Connection& connection);
 
+bool
+qmetaobjectconnectionIsConnected(const ::QMetaObject::Connection& connection);
+
+// Connect a signal to a functor, the same as QObject::connect, but with
+// support for Qt::SingleShotConnection on Qt 5
+template<typename Sender, typename Signal, typename Functor>
+::QMetaObject::Connection
+qobjectConnect(Sender sender,
+               Signal signal,
+               const ::QObject* context,
+               Functor&& functor,
+               ::Qt::ConnectionType type)
+{
+#if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+  // Qt::SingleShotConnection does not exist in Qt 5 so emulate it by
+  // disconnecting before the first invocation of the functor
+  constexpr int singleShotConnection = 0x100;
+  if (type & singleShotConnection) {
+    auto connection = ::std::make_shared<::QMetaObject::Connection>();
+    *connection = ::QObject::connect(
+      sender,
+      signal,
+      context,
+      [connection, functor = ::std::forward<Functor>(functor)](
+        auto&&... args) mutable {
+        // A queued invocation could still arrive after the first one
+        if (!::QObject::disconnect(*connection)) {
+          return;
+        }
+
+        functor(::std::forward<decltype(args)>(args)...);
+      },
+      static_cast<::Qt::ConnectionType>(type & ~singleShotConnection));
+    return *connection;
+  }
+#endif
+
+  return ::QObject::connect(
+    sender, signal, context, ::std::forward<Functor>(functor), type);
+}
+
 }
 }
@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <type_traits>
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<::QPointer<::QObject>> : ::std::true_type
+{};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqt1 {
+
+// The Rust QPointer<T> and QBox<T> store a QPointer<QObject> and downcast
+// it to T, so that the C++ template does not need to be instantiated per type
+using QObjectPointer = ::QPointer<::QObject>;
+
+QObjectPointer
+qobjectpointerClone(const QObjectPointer& pointer);
+
+QObject*
+qobjectpointerData(const QObjectPointer& pointer);
+
+QObjectPointer
+qobjectpointerDefault();
+
+void
+qobjectpointerDeleteUnparented(const QObjectPointer& pointer);
+
+void
+qobjectpointerDrop(QObjectPointer& pointer);
+
+QObjectPointer
+qobjectpointerNew(QObject* object);
+
+}
+}
@@ -6,15 +6,22 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <atomic>
 #include <cstdint>
+#include <functional>
 #include <iostream>
 #include <memory>
 #include <mutex>
+#include <optional>
 #include <shared_mutex>
 #include <stdexcept>
+#include <unordered_map>
 
+#include <QtCore/QCoreApplication>
 #include <QtCore/QDebug>
+#include <QtCore/QEvent>
 #include <QtCore/QMetaObject>
+#include <QtCore/QThread>
 
 #include "rust/cxx.h"
 
@@ -30,8 +37,201 @@ public:
   {
   }
 
+  T* get() const { return ptr; }
+
+  // Reset the pointer once the object is being destroyed, then drop the
+  // values which are retained for the lifetime of the object
+  void reset()
+  {
+    {
+      const auto guard = ::std::unique_lock(mutex);
+      ptr = nullptr;
+    }
+
+    decltype(retained) values;
+    {
+      const auto retainedGuard = ::std::lock_guard(retainedMutex);
+      values.swap(retained);
+    }
+    // The values are dropped without holding a lock, as dropping them can run
+    // Rust code which queues onto this object
+  }
+
+  // Reserve a place in the queue, unless there are already max closures pending
+  bool tryReservePending(::std::size_t max)
+  {
+    auto current = pending.load();
+    do {
+      if (current >= max) {
+        return false;
+      }
+    } while (!pending.compare_exchange_weak(current, current + 1));
+    return true;
+  }
+
   T* ptr;
   ::std::shared_mutex mutex;
+
+  // The number of queued closures which have not run yet
+  ::std::atomic<::std::size_t> pending = 0;
+
+  // The latest closure for each coalescing key which has not run yet
+  ::std::mutex coalescedMutex;
+  ::std::unordered_map<::std::uint64_t, ::std::function<void(T&)>> coalesced;
+
+  // Values which are kept alive until they are released or the object is
+  // destroyed, such as the cancellation of a spawned future
+  ::std::mutex retainedMutex;
+  ::std::unordered_map<::std::uint64_t, ::std::shared_ptr<void>> retained;
+  ::std::uint64_t nextRetainedId = 1;
+};
+
+// The events which are posted by CxxQtThread with a priority
+//
+// QMetaObject::invokeMethod does not support priorities, so these events are
+// posted to the CxxQtThreadEventDispatcher of the thread instead.
+class CxxQtThreadEventBase : public QEvent
+{
+public:
+  static QEvent::Type eventType()
+  {
+    static const auto type =
+      static_cast<QEvent::Type>(QEvent::registerEventType());
+    return type;
+  }
+
+  CxxQtThreadEventBase()
+    : QEvent(eventType())
+  {
+  }
+
+  // Run the lambda, this is only called once the event has been delivered
+  virtual void run() = 0;
+};
+
+// Runs the events posted by CxxQtThread for the objects living in a thread
+class CxxQtThreadEventDispatcher final : public QObject
+{
+public:
+  // Post the event to the dispatcher of the thread, which takes ownership
+  //
+  // Returns false if the thread has finished, in which case the caller keeps
+  // ownership of the event.
+  static bool post(QThread* thread, QEvent* event, int priority)
+  {
+    // The lock is held while posting so that the dispatcher cannot be deleted
+    // by its thread finishing in the meantime
+    const auto guard = ::std::lock_guard(dispatchersMutex);
+    auto dispatcher = dispatchers.find(thread);
+    if (dispatcher == dispatchers.end()) {
+      if (QCoreApplication::instance() == nullptr || thread->isFinished()) {
+        return false;
+      }
+
+      // The dispatcher is created in this thread without a parent, so it can
+      // be pushed to the target thread
+      auto created = new CxxQtThreadEventDispatcher();
+      created->moveToThread(thread);
+      dispatcher = dispatchers.emplace(thread, created).first;
+
+      // Delete the dispatcher with its thread, which discards the events which
+      // have not been delivered yet
+      QObject::connect(
+        thread,
+        &QThread::finished,
+        created,
+        [thread]() { remove(thread); },
+        Qt::DirectConnection);
+      QObject::connect(
+        thread,
+        &QObject::destroyed,
+        created,
+        [thread]() { remove(thread); },
+        Qt::DirectConnection);
+    }
+
+    QCoreApplication::postEvent(dispatcher->second, event, priority);
+    return true;
+  }
+
+  bool event(QEvent* event) override
+  {
+    if (event->type() == CxxQtThreadEventBase::eventType()) {
+      static_cast<CxxQtThreadEventBase*>(event)->run();
+      return true;
+    }
+
+    return QObject::event(event);
+  }
+
+private:
+  CxxQtThreadEventDispatcher() = default;
+
+  static void remove(QThread* thread)
+  {
+    const auto guard = ::std::lock_guard(dispatchersMutex);
+    const auto dispatcher = dispatchers.find(thread);
+    if (dispatcher != dispatchers.end()) {
+      delete dispatcher->second;
+      dispatchers.erase(dispatcher);
+    }
+  }
+
+  static inline ::std::mutex dispatchersMutex;
+  static inline ::std::unordered_map<QThread*, CxxQtThreadEventDispatcher*>
+    dispatchers;
+};
+
+// An event which runs the lambda for an object once it has been delivered
+//
+// The lambda is given whether it should run the closure, so that it can
+// release the closure when the event is discarded without being delivered.
+template<typename T, typename F>
+class CxxQtThreadEvent final : public CxxQtThreadEventBase
+{
+public:
+  CxxQtThreadEvent(::std::shared_ptr<CxxQtGuardedPointer<T>> obj, F lambda)
+    : m_obj(::std::move(obj))
+    , m_lambda(::std::move(lambda))
+  {
+  }
+
+  ~CxxQtThreadEvent() override
+  {
+    if (m_lambda) {
+      (*m_lambda)(false);
+    }
+  }
+
+  // Drop the lambda without running it, as the event was never posted
+  void dismiss() { m_lambda.reset(); }
+
+  void run() override
+  {
+    {
+      const auto guard = ::std::shared_lock(m_obj->mutex);
+      const auto ptr = m_obj->get();
+      // Follow the object if it has been moved to another thread since the
+      // event was posted
+      if (ptr != nullptr && ptr->thread() != QThread::currentThread()) {
+        auto event = new CxxQtThreadEvent(m_obj, ::std::move(*m_lambda));
+        m_lambda.reset();
+        if (!CxxQtThreadEventDispatcher::post(
+              ptr->thread(), event, Qt::HighEventPriority)) {
+          delete event;
+        }
+        return;
+      }
+    }
+
+    auto lambda = ::std::move(*m_lambda);
+    m_lambda.reset();
+    lambda(true);
+  }
+
+private:
+  ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
+  ::std::optional<F> m_lambda;
 };
 
 template<typename T>
@@ -50,47 +250,204 @@ public:
   bool isDestroyed() const
   {
     const auto guard = ::std::shared_lock(m_obj->mutex);
-    return m_obj->ptr == nullptr;
+    return m_obj->get() == nullptr;
+  }
+
+  bool isCurrentThread() const
+  {
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    const auto ptr = m_obj->get();
+    return ptr != nullptr && ptr->thread() == QThread::currentThread();
   }
 
   template<typename A>
   ::std::uint8_t queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-                       ::rust::Box<A> arg) const
+                       ::rust::Box<A> arg,
+                       bool coalesce,
+                       ::std::uint64_t coalesceKey,
+                       ::std::size_t maxPending,
+                       bool highPriority) const
   {
     const static ::std::uint8_t sObjectDestroyed = 1;
     const static ::std::uint8_t sInvokeMethodFailed = 2;
+    const static ::std::uint8_t sQueueFull = 3;
 
     // Ensure that we can read the pointer and it's not being written to
     const auto guard = ::std::shared_lock(m_obj->mutex);
-    if (!m_obj->ptr) {
+    const auto ptr = m_obj->get();
+    if (!ptr) {
       return sObjectDestroyed;
     }
 
+    if (coalesce) {
+      // std::function requires a copyable closure, so share the argument
+      auto sharedArg = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+      ::std::function<void(T&)> call = [func = ::std::move(func),
+                                        sharedArg = ::std::move(sharedArg)](
+                                         T& self) {
+        func(self, ::std::move(*sharedArg));
+      };
+
+      {
+        const auto coalescedGuard = ::std::lock_guard(m_obj->coalescedMutex);
+        // Replace the closure which is already pending for this key
+        const auto existing = m_obj->coalesced.find(coalesceKey);
+        if (existing != m_obj->coalesced.end()) {
+          existing->second = ::std::move(call);
+          return 0;
+        }
+
+        if (!m_obj->tryReservePending(maxPending)) {
+          return sQueueFull;
+        }
+        m_obj->coalesced.emplace(coalesceKey, ::std::move(call));
+      }
+
+      // Construct the lambda which runs the latest closure for the key
+      auto obj = m_obj;
+      auto lambda = [obj = ::std::move(obj), coalesceKey](bool deliver) {
+        ::std::function<void(T&)> call;
+        {
+          const auto coalescedGuard = ::std::lock_guard(obj->coalescedMutex);
+          const auto existing = obj->coalesced.find(coalesceKey);
+          if (existing != obj->coalesced.end()) {
+            call = ::std::move(existing->second);
+            obj->coalesced.erase(existing);
+          }
+        }
+
+        if (deliver) {
+          run(*obj, [&call](T& self) {
+            if (call) {
+              call(self);
+            }
+          });
+        } else {
+          obj->pending--;
+        }
+      };
+
+      // Post without holding the coalesced lock, as a discarded event takes it
+      if (!post(m_obj, ptr, ::std::move(lambda), highPriority)) {
+        {
+          const auto coalescedGuard = ::std::lock_guard(m_obj->coalescedMutex);
+          m_obj->coalesced.erase(coalesceKey);
+        }
+        m_obj->pending--;
+        return sInvokeMethodFailed;
+      }
+
+      return 0;
+    }
+
+    if (!m_obj->tryReservePending(maxPending)) {
+      return sQueueFull;
+    }
+
     // Construct the lambda
     auto obj = m_obj;
     auto lambda = [obj = ::std::move(obj),
                    func = ::std::move(func),
-                   arg = ::std::move(arg)]() mutable {
-      // Ensure that we can read the pointer and it's not being written to
-      const auto guard = ::std::shared_lock(obj->mutex);
-      if (obj->ptr) {
-        func(*obj->ptr, ::std::move(arg));
+                   arg = ::std::move(arg)](bool deliver) mutable {
+      if (deliver) {
+        run(*obj,
+            [&func, &arg](T& self) mutable { func(self, ::std::move(arg)); });
       } else {
-        qWarning()
-          << "Could not call the function pointer as object has been destroyed";
+        // The argument is dropped with the lambda
+        obj->pending--;
       }
     };
 
     // Add the lambda to the queue
-    if (!QMetaObject::invokeMethod(
-          m_obj->ptr, ::std::move(lambda), Qt::QueuedConnection)) {
+    if (!post(m_obj, ptr, ::std::move(lambda), highPriority)) {
+      m_obj->pending--;
       return sInvokeMethodFailed;
     }
 
     return 0;
   }
 
+  // Keep the value alive until it is released or the object is destroyed
+  //
+  // Returns the identifier of the value, or zero if the object has already
+  // been destroyed in which case the value is dropped.
+  template<typename A>
+  ::std::uint64_t retain(::rust::Box<A> value) const
+  {
+    // Ensure that the object is not destroyed while the value is retained
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    if (!m_obj->get()) {
+      return 0;
+    }
+
+    const auto retainedGuard = ::std::lock_guard(m_obj->retainedMutex);
+    const auto id = m_obj->nextRetainedId++;
+    m_obj->retained.emplace(
+      id, ::std::make_shared<::rust::Box<A>>(::std::move(value)));
+    return id;
+  }
+
+  // Drop the value with the given identifier, if it is still retained
+  void release(::std::uint64_t id) const
+  {
+    ::std::shared_ptr<void> value;
+    {
+      const auto retainedGuard = ::std::lock_guard(m_obj->retainedMutex);
+      const auto existing = m_obj->retained.find(id);
+      if (existing == m_obj->retained.end()) {
+        return;
+      }
+      value = ::std::move(existing->second);
+      m_obj->retained.erase(existing);
+    }
+    // The value is dropped without holding a lock
+  }
+
 private:
+  // Run a queued closure, unless the object has been destroyed
+  template<typename F>
+  static void run(CxxQtGuardedPointer<T>& obj, F call)
+  {
+    obj.pending--;
+
+    // Ensure that we can read the pointer and it's not being written to
+    const auto guard = ::std::shared_lock(obj.mutex);
+    if (const auto ptr = obj.get()) {
+      call(*ptr);
+    } else {
+      qWarning()
+        << "Could not call the function pointer as object has been destroyed";
+    }
+  }
+
+  // Post the lambda to the event loop of the receiver
+  //
+  // The lambda is only called with true once it is delivered, or with false if
+  // it is discarded afterwards. If posting fails the lambda is not called.
+  template<typename F>
+  static bool post(const ::std::shared_ptr<CxxQtGuardedPointer<T>>& obj,
+                   QObject* receiver,
+                   F lambda,
+                   bool highPriority)
+  {
+    if (highPriority) {
+      auto event = new CxxQtThreadEvent<T, F>(obj, ::std::move(lambda));
+      if (CxxQtThreadEventDispatcher::post(
+            receiver->thread(), event, Qt::HighEventPriority)) {
+        return true;
+      }
+
+      event->dismiss();
+      delete event;
+      return false;
+    }
+
+    return QMetaObject::invokeMethod(
+      receiver,
+      [lambda = ::std::move(lambda)]() mutable { lambda(true); },
+      Qt::QueuedConnection);
+  }
+
   ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
 };
 
@@ -112,9 +469,32 @@ template<typename A, typename T>
 ::std::uint8_t
 cxxQtThreadQueue(const CxxQtThread<T>& cxxQtThread,
                  ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-                 ::rust::Box<A> arg)
+                 ::rust::Box<A> arg,
+                 bool coalesce,
+                 ::std::uint64_t coalesceKey,
+                 ::std::size_t maxPending,
+                 bool highPriority)
+{
+  return cxxQtThread.queue(::std::move(func),
+                           ::std::move(arg),
+                           coalesce,
+                           coalesceKey,
+                           maxPending,
+                           highPriority);
+}
+
+template<typename A, typename T>
+::std::uint64_t
+cxxQtThreadRetain(const CxxQtThread<T>& cxxQtThread, ::rust::Box<A> value)
 {
-  return cxxQtThread.queue(::std::move(func), ::std::move(arg));
+  return cxxQtThread.retain(::std::move(value));
+}
+
+template<typename T>
+void
+cxxQtThreadRelease(const CxxQtThread<T>& cxxQtThread, ::std::uint64_t id)
+{
+  cxxQtThread.release(id);
 }
 
 template<typename T>
@@ -124,6 +504,13 @@ cxxQtThreadIsDestroyed(const CxxQtThread<T>& cxxQtThread)
   return cxxQtThread.isDestroyed();
 }
 
+template<typename T>
+bool
+cxxQtThreadIsCurrentThread(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isCurrentThread();
+}
+
 } // namespace cxxqt1
 } // namespace rust
 
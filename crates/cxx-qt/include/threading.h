@@ -9,6 +9,10 @@
 
 #include <memory>
 #include <mutex>
+#include <type_traits>
+#include <unordered_map>
+
+#include <QtCore/QObject>
 
 #include <cxx-qt/thread.h>
 
@@ -23,11 +27,7 @@ public:
   {
   }
 
-  virtual ~CxxQtThreading()
-  {
-    const auto guard = ::std::unique_lock(m_cxxQtThreadObj->mutex);
-    m_cxxQtThreadObj->ptr = nullptr;
-  }
+  virtual ~CxxQtThreading() { m_cxxQtThreadObj->reset(); }
 
   CxxQtThread<T> qtThread() const { return CxxQtThread<T>(m_cxxQtThreadObj); }
 
@@ -35,6 +35,44 @@ private:
   ::std::shared_ptr<CxxQtGuardedPointer<T>> m_cxxQtThreadObj;
 };
 
+// QObjects from extern "C++Qt" blocks do not inherit from CxxQtThreading, so
+// the guarded pointer of each object is stored here instead. The guarded
+// pointer is shared by every CxxQtThread of the object and is reset once the
+// object is destroyed.
+template<typename T>
+class CxxQtExternThreading final
+{
+public:
+  static ::std::shared_ptr<CxxQtGuardedPointer<T>> guard(T* obj)
+  {
+    const auto guard = ::std::lock_guard(s_mutex);
+    const auto existing = s_guards.find(obj);
+    if (existing != s_guards.end()) {
+      return existing->second;
+    }
+
+    auto guarded = ::std::make_shared<CxxQtGuardedPointer<T>>(obj);
+    s_guards.emplace(obj, guarded);
+
+    // Take the unique lock of the guarded pointer while the object is being
+    // destroyed, so that no queue call can use the object after this point
+    QObject::connect(obj, &QObject::destroyed, [obj, guarded]() {
+      guarded->reset();
+
+      const auto guard = ::std::lock_guard(s_mutex);
+      s_guards.erase(obj);
+    });
+
+    return guarded;
+  }
+
+private:
+  static inline ::std::mutex s_mutex;
+  static inline ::std::unordered_map<const T*,
+                                     ::std::shared_ptr<CxxQtGuardedPointer<T>>>
+    s_guards;
+};
+
 // Note: Use auto syntax here, because otherwise it is not possible to fully
 // qualify this function when it is declared as as friend.
 //
@@ -55,7 +93,12 @@ template<typename T>
 auto
 qtThread(const T& qobject) -> CxxQtThread<T>
 {
-  return static_cast<const CxxQtThreading<T>&>(qobject).qtThread();
+  if constexpr (::std::is_base_of_v<CxxQtThreading<T>, T>) {
+    return static_cast<const CxxQtThreading<T>&>(qobject).qtThread();
+  } else {
+    return CxxQtThread<T>(
+      CxxQtExternThreading<T>::guard(const_cast<T*>(&qobject)));
+  }
 }
 
 }
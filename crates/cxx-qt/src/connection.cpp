@@ -6,6 +6,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include "cxx-qt/connection.h"
 
+#include <cstdint>
 #include <type_traits>
 
 // ::QMetaObject::Connection is the size of one pointer
@@ -24,9 +25,24 @@ static_assert(
 static_assert(
   !::std::is_trivially_destructible<::QMetaObject::Connection>::value);
 
+// ::Qt::ConnectionType is passed to Rust as an i32 with the values of the enum
+static_assert(sizeof(::Qt::ConnectionType) == sizeof(::std::int32_t),
+              "unexpected ::Qt::ConnectionType size!");
+static_assert(::Qt::AutoConnection == 0);
+static_assert(::Qt::DirectConnection == 1);
+static_assert(::Qt::QueuedConnection == 2);
+static_assert(::Qt::BlockingQueuedConnection == 3);
+static_assert(::Qt::UniqueConnection == 0x80);
+
 namespace rust {
 namespace cxxqt1 {
 
+::QMetaObject::Connection
+qmetaobjectconnectionClone(const ::QMetaObject::Connection& connection)
+{
+  return connection;
+}
+
 ::QMetaObject::Connection
 qmetaobjectconnectionDefault()
 {
@@ -45,5 +61,11 @@ qmetaobjectconnectionDrop(::QMetaObject::Connection& connection)
   connection.~QMetaObjectConnection();
 }
 
+bool
+qmetaobjectconnectionIsConnected(const ::QMetaObject::Connection& connection)
+{
+  return static_cast<bool>(connection);
+}
+
 }
 }
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx::{type_id, ExternType};
 use std::mem::MaybeUninit;
+use std::ops::{BitOr, BitOrAssign};
 
 #[cxx::bridge]
 mod ffi {
@@ -18,6 +19,10 @@ mod ffi {
         #[rust_name = "qmetaobjectconnection_default"]
         fn qmetaobjectconnectionDefault() -> QMetaObjectConnection;
 
+        #[doc(hidden)]
+        #[rust_name = "qmetaobjectconnection_clone"]
+        fn qmetaobjectconnectionClone(connection: &QMetaObjectConnection) -> QMetaObjectConnection;
+
         #[doc(hidden)]
         #[rust_name = "qmetaobjectconnection_disconnect"]
         fn qmetaobjectconnectionDisconnect(connection: &QMetaObjectConnection) -> bool;
@@ -25,33 +30,10 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qmetaobjectconnection_drop"]
         fn qmetaobjectconnectionDrop(connection: &mut QMetaObjectConnection);
-    }
 
-    /// This enum describes the types of connection that can be used with signals.
-    ///
-    /// Note that [UniqueConnection](https://doc.qt.io/qt/qt.html#ConnectionType-enum) is not supported.
-    #[namespace = "Qt"]
-    #[repr(i32)]
-    enum ConnectionType {
-        /// If the receiver lives in the thread that emits the signal, [`DirectConnection`](Self::DirectConnection) is used.
-        /// Otherwise, [`QueuedConnection`](Self::QueuedConnection) is used. The connection type is determined when the signal is emitted.
-        AutoConnection,
-        /// The slot is invoked immediately when the signal is emitted.
-        /// The slot is executed in the signalling thread.
-        DirectConnection,
-        /// The slot is invoked when control returns to the event loop of the receiver's thread.
-        /// The slot is executed in the receiver's thread.
-        QueuedConnection,
-        /// Same as [`QueuedConnection`](Self::QueuedConnection), except that the signalling thread blocks until the slot returns.
-        /// This connection must not be used if the receiver lives in the signalling thread, or else the application will deadlock.
-        BlockingQueuedConnection,
-    }
-
-    // We need to tell CXX that the type already exists, otherwise the following error ocucrs
-    // "scoped/unscoped mismatch in enum"
-    #[namespace = "Qt"]
-    unsafe extern "C++" {
-        type ConnectionType;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobjectconnection_is_connected"]
+        fn qmetaobjectconnectionIsConnected(connection: &QMetaObjectConnection) -> bool;
     }
 }
 
@@ -82,6 +64,15 @@ impl Drop for QMetaObjectConnection {
     }
 }
 
+impl Clone for QMetaObjectConnection {
+    /// Creates a copy of the handle to the connection.
+    ///
+    /// Note that the connection itself is not duplicated.
+    fn clone(&self) -> Self {
+        ffi::qmetaobjectconnection_clone(self)
+    }
+}
+
 impl QMetaObjectConnection {
     /// Disconnect a connection.
     ///
@@ -89,6 +80,11 @@ impl QMetaObjectConnection {
     pub fn disconnect(&self) -> bool {
         ffi::qmetaobjectconnection_disconnect(self)
     }
+
+    /// Returns `true` if the connection is valid and has not been disconnected.
+    pub fn is_connected(&self) -> bool {
+        ffi::qmetaobjectconnection_is_connected(self)
+    }
 }
 
 // Safety:
@@ -99,4 +95,157 @@ unsafe impl ExternType for QMetaObjectConnection {
     type Kind = cxx::kind::Trivial;
 }
 
-pub use ffi::ConnectionType;
+/// This type describes the types of connection that can be used with signals.
+///
+/// The flags [`UniqueConnection`](Self::UniqueConnection) and [`SingleShotConnection`](Self::SingleShotConnection)
+/// can be combined with any one of the connection types using a bitwise OR.
+///
+/// ```ignore
+/// let conn_type = ConnectionType::QueuedConnection | ConnectionType::SingleShotConnection;
+/// ```
+///
+/// Qt Documentation: [Qt::ConnectionType](https://doc.qt.io/qt/qt.html#ConnectionType-enum)
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConnectionType(i32);
+
+#[allow(non_upper_case_globals)]
+impl ConnectionType {
+    /// If the receiver lives in the thread that emits the signal, [`DirectConnection`](Self::DirectConnection) is used.
+    /// Otherwise, [`QueuedConnection`](Self::QueuedConnection) is used. The connection type is determined when the signal is emitted.
+    pub const AutoConnection: Self = Self(0);
+    /// The slot is invoked immediately when the signal is emitted.
+    /// The slot is executed in the signalling thread.
+    pub const DirectConnection: Self = Self(1);
+    /// The slot is invoked when control returns to the event loop of the receiver's thread.
+    /// The slot is executed in the receiver's thread.
+    pub const QueuedConnection: Self = Self(2);
+    /// Same as [`QueuedConnection`](Self::QueuedConnection), except that the signalling thread blocks until the slot returns.
+    /// This connection must not be used if the receiver lives in the signalling thread, or else the application will deadlock.
+    pub const BlockingQueuedConnection: Self = Self(3);
+    /// This is a flag that can be combined with any one of the connection types, using a bitwise OR.
+    /// When set, the connection fails and an invalid [`QMetaObjectConnection`](crate::QMetaObjectConnection) is returned
+    /// if the same closure is already connected to the same signal of the same object.
+    ///
+    /// Note that unlike Qt, which ignores this flag for functors, closures are compared by their type.
+    /// So this can only be used with functions and closures which do not capture any state,
+    /// connecting a function pointer or a closure which captures state with this flag panics.
+    pub const UniqueConnection: Self = Self(0x80);
+    /// This is a flag that can be combined with any one of the connection types, using a bitwise OR.
+    /// When set, the closure is disconnected after it has been invoked once.
+    ///
+    /// Note that this is native from Qt 6 and is emulated by disconnecting before the first invocation on Qt 5.
+    pub const SingleShotConnection: Self = Self(0x100);
+
+    /// Returns the connection type without any of the [`UniqueConnection`](Self::UniqueConnection)
+    /// or [`SingleShotConnection`](Self::SingleShotConnection) flags.
+    pub fn base(self) -> Self {
+        Self(self.0 & !(Self::UniqueConnection.0 | Self::SingleShotConnection.0))
+    }
+
+    /// Returns the connection type without the given flags.
+    pub fn without(self, flags: Self) -> Self {
+        Self(self.0 & !flags.0)
+    }
+
+    /// Returns `true` if the [`UniqueConnection`](Self::UniqueConnection) flag is set.
+    pub fn is_unique(self) -> bool {
+        self.0 & Self::UniqueConnection.0 != 0
+    }
+
+    /// Returns `true` if the [`SingleShotConnection`](Self::SingleShotConnection) flag is set.
+    pub fn is_single_shot(self) -> bool {
+        self.0 & Self::SingleShotConnection.0 != 0
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for ConnectionType {
+    type Id = type_id!("Qt::ConnectionType");
+    type Kind = cxx::kind::Trivial;
+}
+
+impl BitOr for ConnectionType {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ConnectionType {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitor() {
+        let conn_type = ConnectionType::QueuedConnection | ConnectionType::UniqueConnection;
+        assert_eq!(
+            conn_type.0,
+            ConnectionType::QueuedConnection.0 | ConnectionType::UniqueConnection.0
+        );
+
+        let mut conn_type = ConnectionType::DirectConnection;
+        conn_type |= ConnectionType::SingleShotConnection;
+        assert_eq!(
+            conn_type.0,
+            ConnectionType::DirectConnection.0 | ConnectionType::SingleShotConnection.0
+        );
+    }
+
+    #[test]
+    fn base() {
+        let conn_type = ConnectionType::BlockingQueuedConnection
+            | ConnectionType::UniqueConnection
+            | ConnectionType::SingleShotConnection;
+        assert_eq!(conn_type.base(), ConnectionType::BlockingQueuedConnection);
+        assert_eq!(
+            ConnectionType::AutoConnection.base(),
+            ConnectionType::AutoConnection
+        );
+    }
+
+    #[test]
+    fn without() {
+        let conn_type = ConnectionType::QueuedConnection
+            | ConnectionType::UniqueConnection
+            | ConnectionType::SingleShotConnection;
+        assert_eq!(
+            conn_type.without(ConnectionType::UniqueConnection),
+            ConnectionType::QueuedConnection | ConnectionType::SingleShotConnection
+        );
+        assert_eq!(
+            ConnectionType::QueuedConnection.without(ConnectionType::UniqueConnection),
+            ConnectionType::QueuedConnection
+        );
+    }
+
+    #[test]
+    fn is_unique() {
+        assert!((ConnectionType::AutoConnection | ConnectionType::UniqueConnection).is_unique());
+        assert!(!ConnectionType::AutoConnection.is_unique());
+        assert!(
+            !(ConnectionType::AutoConnection | ConnectionType::SingleShotConnection).is_unique()
+        );
+    }
+
+    #[test]
+    fn is_single_shot() {
+        assert!(
+            (ConnectionType::QueuedConnection | ConnectionType::SingleShotConnection)
+                .is_single_shot()
+        );
+        assert!(!ConnectionType::QueuedConnection.is_single_shot());
+        assert!(
+            !(ConnectionType::QueuedConnection | ConnectionType::UniqueConnection).is_single_shot()
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    task::{Context, Waker},
+};
+use std::{
+    sync::{Arc, Mutex, Weak},
+    task::Wake,
+};
+
+use crate::{CxxQtThread, Threading, ThreadingQueueError};
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A future which is polled on the Qt event loop of the QObject of a [CxxQtThread]
+struct Task<T>
+where
+    T: Threading,
+{
+    /// The future, which is None once it has completed
+    future: Mutex<Option<BoxedFuture>>,
+    /// Whether a poll of the future is already in the queue of the event loop
+    queued: AtomicBool,
+    /// Whether the QObject has been destroyed, so the future must not be polled again
+    cancelled: AtomicBool,
+    /// The identifier of the [CancelOnDrop] which is retained by the QObject
+    retained: AtomicU64,
+    thread: CxxQtThread<T>,
+}
+
+/// Cancels the task once it is dropped
+///
+/// This is retained by the QObject, so that the future is dropped when the QObject is
+/// destroyed instead of being kept alive by any wakers which are still held elsewhere.
+struct CancelOnDrop<T>(Weak<Task<T>>)
+where
+    T: Threading;
+
+impl<T> Drop for CancelOnDrop<T>
+where
+    T: Threading,
+{
+    fn drop(&mut self) {
+        if let Some(task) = self.0.upgrade() {
+            task.cancel();
+        }
+    }
+}
+
+impl<T> Task<T>
+where
+    T: Threading,
+{
+    /// Drop the future and ensure that it is not polled again
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+
+        // If the future is being polled then it is dropped once the poll has finished
+        if let Ok(mut future) = self.future.try_lock() {
+            *future = None;
+        }
+    }
+}
+
+impl<T> Task<T>
+where
+    T: Threading + 'static,
+{
+    /// Queue a poll of the future onto the Qt event loop, unless one is already queued
+    fn schedule(self: Arc<Self>) -> Result<(), ThreadingQueueError> {
+        if self.queued.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+
+        let task = self.clone();
+        self.thread.queue(move |_| task.poll())
+    }
+
+    fn poll(self: Arc<Self>) {
+        self.queued.store(false, Ordering::Release);
+
+        let waker = Waker::from(self.clone());
+        let mut context = Context::from_waker(&waker);
+
+        // If the lock is poisoned then the future has panicked, so there is nothing left to poll
+        let Ok(mut future) = self.future.lock() else {
+            return;
+        };
+        if self.cancelled.load(Ordering::Acquire) {
+            *future = None;
+            return;
+        }
+
+        let Some(pending) = future.as_mut() else {
+            return;
+        };
+        let ready = pending.as_mut().poll(&mut context).is_ready();
+        // The QObject could have been destroyed while the future was being polled
+        if ready || self.cancelled.load(Ordering::Acquire) {
+            *future = None;
+        }
+        drop(future);
+
+        if ready {
+            // The task has completed, so the QObject no longer needs to cancel it
+            T::release(&self.thread, self.retained.load(Ordering::Acquire));
+        }
+    }
+}
+
+impl<T> Wake for Task<T>
+where
+    T: Threading + 'static,
+{
+    fn wake(self: Arc<Self>) {
+        // If the poll cannot be queued then the future would never be polled again
+        if self.clone().schedule().is_err() {
+            self.cancel();
+        }
+    }
+}
+
+impl<T> CxxQtThread<T>
+where
+    T: Threading + 'static,
+{
+    /// Spawn the given future onto the Qt event loop for this QObject
+    ///
+    /// The future is polled on the thread the QObject lives in, so awaiting timers,
+    /// channels or signals does not block the thread. Whenever the future is woken
+    /// a poll is queued in the same way as [queue](CxxQtThread::queue).
+    ///
+    /// Once the QObject is destroyed the future is no longer polled and is dropped,
+    /// even if a waker for the future is still held elsewhere.
+    ///
+    /// As the future cannot hold a reference to the QObject, use [queue](CxxQtThread::queue)
+    /// from within the future to update the QObject.
+    ///
+    /// ```rust,ignore
+    /// let thread = qobject.qt_thread();
+    /// let (sender, receiver) = async_channel::unbounded();
+    /// thread.spawn({
+    ///     let thread = thread.clone();
+    ///     async move {
+    ///         while let Ok(value) = receiver.recv().await {
+    ///             thread.queue(move |qobject| qobject.set_value(value)).ok();
+    ///         }
+    ///     }
+    /// })?;
+    /// ```
+    pub fn spawn<F>(&self, future: F) -> Result<(), ThreadingQueueError>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            queued: AtomicBool::new(false),
+            cancelled: AtomicBool::new(false),
+            retained: AtomicU64::new(0),
+            thread: self.clone(),
+        });
+
+        // Let the QObject cancel the task when it is destroyed
+        let id = T::retain(self, CancelOnDrop(Arc::downgrade(&task)))
+            .ok_or(ThreadingQueueError::ObjectDestroyed)?;
+        task.retained.store(id, Ordering::Release);
+
+        task.clone().schedule().inspect_err(|_| {
+            T::release(self, id);
+        })
+    }
+}
@@ -15,9 +15,12 @@ use std::{fs::File, io::Write, path::Path};
 pub mod casting;
 mod connection;
 mod connectionguard;
+mod executor;
 mod qobject;
+mod qpointer;
 #[doc(hidden)]
 pub mod signalhandler;
+mod signalstream;
 mod threading;
 
 /// A procedural macro which generates a QObject for a struct inside a module.
@@ -114,10 +117,12 @@ pub use cxx_qt_macro::init_qml_module;
 /// ```
 pub use cxx_qt_macro::qobject;
 pub use qobject::QObject;
+pub use qpointer::{QBox, QPointer};
 
 pub use connection::{ConnectionType, QMetaObjectConnection};
 pub use connectionguard::QMetaObjectConnectionGuard;
-pub use threading::{CxxQtThread, ThreadingQueueError};
+pub use signalstream::{QSignalOnce, QSignalStream};
+pub use threading::{CxxQtThread, ThreadingQueueError, ThreadingQueueOptions};
 
 // Export static assertions that can then be used in cxx-qt-gen generation
 //
@@ -148,6 +153,9 @@ pub trait CxxQtType {
 /// A [CxxQtThread] represents a reference to the Qt thread that the QObject lives in.
 /// When a new Rust thread is started (e.g. in an invokable) the [CxxQtThread] can be moved into the thread to later update the QObject in a thread safe manner.
 ///
+/// This trait can also be implemented for `#[qobject]` types in `extern "C++Qt"` blocks,
+/// in which case the [CxxQtThread] tracks the destruction of the QObject with its `destroyed` signal.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -204,11 +212,26 @@ pub trait Threading: Sized {
     fn is_destroyed(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
 
     #[doc(hidden)]
-    fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), ThreadingQueueError>
+    fn is_current_thread(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+
+    #[doc(hidden)]
+    fn queue<F>(
+        cxx_qt_thread: &CxxQtThread<Self>,
+        f: F,
+        options: ThreadingQueueOptions,
+    ) -> Result<(), ThreadingQueueError>
     where
         F: FnOnce(core::pin::Pin<&mut Self>),
         F: Send + 'static;
 
+    #[doc(hidden)]
+    fn retain<V>(cxx_qt_thread: &CxxQtThread<Self>, value: V) -> Option<u64>
+    where
+        V: Send + 'static;
+
+    #[doc(hidden)]
+    fn release(cxx_qt_thread: &CxxQtThread<Self>, id: u64);
+
     #[doc(hidden)]
     fn threading_clone(cxx_qt_thread: &CxxQtThread<Self>) -> CxxQtThread<Self>;
 
@@ -446,6 +469,7 @@ pub fn write_headers(directory: impl AsRef<Path>) {
     // Note ensure that the build script is consistent with files that are copied
     for (file_contents, file_name) in [
         (include_str!("../include/connection.h"), "connection.h"),
+        (include_str!("../include/qpointer.h"), "qpointer.h"),
         (
             include_str!("../include/signalhandler.h"),
             "signalhandler.h",
@@ -0,0 +1,77 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt/qpointer.h"
+
+#include <QtCore/QThread>
+
+// ::QPointer<::QObject> is the size of two pointers
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v5.15.6-lts-lgpl#n56
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v6.2.4#n22
+static_assert(alignof(::QPointer<::QObject>) <= (alignof(::std::size_t[2])),
+              "unexpectedly large ::QPointer<::QObject> alignment!");
+static_assert(sizeof(::QPointer<::QObject>) == (sizeof(::std::size_t[2])),
+              "unexpected ::QPointer<::QObject> size!");
+
+static_assert(
+  !::std::is_trivially_copy_assignable<::QPointer<::QObject>>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<::QPointer<::QObject>>::value);
+static_assert(!::std::is_trivially_destructible<::QPointer<::QObject>>::value);
+
+namespace rust {
+namespace cxxqt1 {
+
+QObjectPointer
+qobjectpointerClone(const QObjectPointer& pointer)
+{
+  return pointer;
+}
+
+QObject*
+qobjectpointerData(const QObjectPointer& pointer)
+{
+  return pointer.data();
+}
+
+QObjectPointer
+qobjectpointerDefault()
+{
+  return QObjectPointer();
+}
+
+void
+qobjectpointerDeleteUnparented(const QObjectPointer& pointer)
+{
+  QObject* object = pointer.data();
+  // Objects with a parent are owned by the parent
+  if (object == nullptr || object->parent() != nullptr) {
+    return;
+  }
+
+  // An object can only be deleted directly from the thread it lives in
+  if (object->thread() == QThread::currentThread()) {
+    delete object;
+  } else {
+    object->deleteLater();
+  }
+}
+
+void
+qobjectpointerDrop(QObjectPointer& pointer)
+{
+  pointer.~QObjectPointer();
+}
+
+QObjectPointer
+qobjectpointerNew(QObject* object)
+{
+  return QObjectPointer(object);
+}
+
+}
+}
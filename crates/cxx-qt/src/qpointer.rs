@@ -0,0 +1,343 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::casting::{Downcast, Upcast};
+use crate::QObject;
+use cxx::{type_id, ExternType, UniquePtr};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::pin::Pin;
+use std::ptr;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/qpointer.h");
+
+        #[doc(hidden)]
+        type QObjectPointer = crate::qpointer::QObjectPointer;
+        #[namespace = ""]
+        type QObject = crate::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_clone"]
+        fn qobjectpointerClone(pointer: &QObjectPointer) -> QObjectPointer;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_data"]
+        fn qobjectpointerData(pointer: &QObjectPointer) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_default"]
+        fn qobjectpointerDefault() -> QObjectPointer;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_delete_unparented"]
+        fn qobjectpointerDeleteUnparented(pointer: &QObjectPointer);
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_drop"]
+        fn qobjectpointerDrop(pointer: &mut QObjectPointer);
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_new"]
+        unsafe fn qobjectpointerNew(object: *mut QObject) -> QObjectPointer;
+    }
+}
+
+/// A `QPointer<QObject>`, which is downcast to the type of a [`QPointer`] or [`QBox`]
+#[repr(C)]
+pub struct QObjectPointer {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl Default for QObjectPointer {
+    fn default() -> Self {
+        ffi::qobjectpointer_default()
+    }
+}
+
+impl Drop for QObjectPointer {
+    fn drop(&mut self) {
+        ffi::qobjectpointer_drop(self);
+    }
+}
+
+impl Clone for QObjectPointer {
+    fn clone(&self) -> Self {
+        ffi::qobjectpointer_clone(self)
+    }
+}
+
+impl QObjectPointer {
+    /// # Safety
+    ///
+    /// `object` must be null or a valid pointer to a `QObject`.
+    unsafe fn new<T: Upcast<QObject>>(object: *mut T) -> Self {
+        if object.is_null() {
+            return Self::default();
+        }
+
+        ffi::qobjectpointer_new(T::upcast_ptr(object).cast_mut())
+    }
+
+    fn get<T: Upcast<QObject>>(&self) -> Option<&T> {
+        // SAFETY: the QPointer is null once the object has been destroyed
+        unsafe { ffi::qobjectpointer_data(self).as_ref() }?.downcast::<T>()
+    }
+
+    fn get_mut<T: Upcast<QObject>>(&mut self) -> Option<Pin<&mut T>> {
+        // SAFETY: the QPointer is null once the object has been destroyed
+        let object = unsafe { ffi::qobjectpointer_data(self).as_mut() }?;
+        unsafe { Pin::new_unchecked(object) }.downcast_pin::<T>()
+    }
+
+    fn as_ptr<T: Upcast<QObject>>(&self) -> *mut T {
+        self.get::<T>()
+            .map_or(ptr::null_mut(), |object| ptr::from_ref(object).cast_mut())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QObjectPointer {
+    type Id = type_id!("rust::cxxqt1::QObjectPointer");
+    type Kind = cxx::kind::Trivial;
+}
+
+/// A guarded pointer to a `QObject`, which becomes null when the object is destroyed.
+///
+/// This is useful to refer to an object which is owned elsewhere, such as by QML or the parent of the object,
+/// without risking a dangling pointer.
+///
+/// As the `QPointer` does not own the object, accessing the object with [`get`](Self::get) or [`get_mut`](Self::get_mut)
+/// is unsafe, as the object could be destroyed or accessed elsewhere while it is borrowed.
+/// Use a [`QBox`] to own the object instead.
+///
+/// Note that as with a [`QPointer`](https://doc.qt.io/qt/qpointer.html) in C++,
+/// the object is not accessible from the destructor of a subclass of `QObject`, as the object is partially destroyed.
+///
+/// Qt Documentation: [QPointer](https://doc.qt.io/qt/qpointer.html#details)
+pub struct QPointer<T> {
+    pointer: QObjectPointer,
+    _phantom: PhantomData<*mut T>,
+}
+
+impl<T> Default for QPointer<T> {
+    /// Constructs a null `QPointer`.
+    fn default() -> Self {
+        Self {
+            pointer: QObjectPointer::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for QPointer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            pointer: self.pointer.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Upcast<QObject>> QPointer<T> {
+    /// Constructs a `QPointer` which tracks the given object.
+    pub fn new(object: Pin<&mut T>) -> Self {
+        unsafe { Self::from_raw(object.get_unchecked_mut()) }
+    }
+
+    /// Constructs a `QPointer` from a raw pointer, which is null if the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// `object` must be null or a valid pointer to a `QObject`.
+    pub unsafe fn from_raw(object: *mut T) -> Self {
+        Self {
+            pointer: QObjectPointer::new(object),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the object, or [`None`] if the object has been destroyed or the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// The `QPointer` does not own the object, so the object must not be destroyed
+    /// or mutated elsewhere while the returned reference is alive,
+    /// for example by its parent, by QML, or by another thread.
+    pub unsafe fn get(&self) -> Option<&T> {
+        self.pointer.get()
+    }
+
+    /// Returns a pinned mutable reference to the object, or [`None`] if the object has been destroyed or the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// The `QPointer` does not own the object and other `QPointer`s may refer to the same object,
+    /// so the object must not be destroyed or accessed elsewhere while the returned reference is alive,
+    /// for example by its parent, by QML, by a clone of this `QPointer`, or by another thread.
+    pub unsafe fn get_mut(&mut self) -> Option<Pin<&mut T>> {
+        self.pointer.get_mut()
+    }
+
+    /// Returns `true` if the object has been destroyed or the pointer is null.
+    pub fn is_null(&self) -> bool {
+        self.as_ptr().is_null()
+    }
+
+    /// Returns a raw pointer to the object, which is null if the object has been destroyed or the pointer is null.
+    pub fn as_ptr(&self) -> *mut T {
+        self.pointer.as_ptr()
+    }
+}
+
+impl<T: Upcast<QObject>> From<Pin<&mut T>> for QPointer<T> {
+    fn from(object: Pin<&mut T>) -> Self {
+        Self::new(object)
+    }
+}
+
+impl<T: Upcast<QObject>> PartialEq for QPointer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+impl<T: Upcast<QObject>> Eq for QPointer<T> {}
+
+impl<T: Upcast<QObject>> fmt::Debug for QPointer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QPointer").field(&self.as_ptr()).finish()
+    }
+}
+
+/// An owning pointer to a `QObject`, which respects the parent and child ownership of Qt.
+///
+/// When the `QBox` is dropped the object is deleted, unless it has a parent, as then the parent owns the object.
+/// As with a [`QPointer`] the `QBox` becomes null when the object is destroyed elsewhere, for example by its parent.
+///
+/// If the object lives in another thread it is deleted later by the event loop of that thread.
+///
+/// As the object can still be destroyed by its parent, or accessed through a [`QPointer`] or by C++,
+/// accessing the object with [`get`](Self::get) or [`get_mut`](Self::get_mut) is unsafe, as with a [`QPointer`].
+///
+/// A `QBox` can be used as the type of a `#[qproperty]`, the QObject then owns the object of the property.
+pub struct QBox<T> {
+    pointer: QObjectPointer,
+    _phantom: PhantomData<*mut T>,
+}
+
+impl<T> Default for QBox<T> {
+    /// Constructs a null `QBox`.
+    fn default() -> Self {
+        Self {
+            pointer: QObjectPointer::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for QBox<T> {
+    fn drop(&mut self) {
+        ffi::qobjectpointer_delete_unparented(&self.pointer);
+    }
+}
+
+impl<T: Upcast<QObject> + cxx::memory::UniquePtrTarget> QBox<T> {
+    /// Constructs a `QBox` which takes ownership of the object.
+    pub fn new(object: UniquePtr<T>) -> Self {
+        unsafe { Self::from_raw(object.into_raw()) }
+    }
+}
+
+impl<T: Upcast<QObject>> QBox<T> {
+    /// Constructs a `QBox` from a raw pointer, which takes ownership of the object.
+    ///
+    /// # Safety
+    ///
+    /// `object` must be null or a valid pointer to a `QObject`,
+    /// which is not owned by anything else other than its parent.
+    pub unsafe fn from_raw(object: *mut T) -> Self {
+        Self {
+            pointer: QObjectPointer::new(object),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Releases ownership of the object and returns a raw pointer to it,
+    /// which is null if the object has been destroyed or the pointer is null.
+    pub fn into_raw(self) -> *mut T {
+        let mut this = ManuallyDrop::new(self);
+        let object = this.as_ptr();
+        // Drop the QPointer without deleting the object
+        unsafe { ptr::drop_in_place(&mut this.pointer) };
+        object
+    }
+
+    /// Returns a reference to the object, or [`None`] if the object has been destroyed or the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// If the object has a parent, the parent also owns the object,
+    /// so the object must not be destroyed or mutated elsewhere while the returned reference is alive,
+    /// for example by its parent, through a [`QPointer`] to the object, or by another thread.
+    pub unsafe fn get(&self) -> Option<&T> {
+        self.pointer.get()
+    }
+
+    /// Returns a pinned mutable reference to the object, or [`None`] if the object has been destroyed or the pointer is null.
+    ///
+    /// # Safety
+    ///
+    /// If the object has a parent, the parent also owns the object,
+    /// so the object must not be destroyed or accessed elsewhere while the returned reference is alive,
+    /// for example by its parent, through a [`QPointer`] to the object, or by another thread.
+    pub unsafe fn get_mut(&mut self) -> Option<Pin<&mut T>> {
+        self.pointer.get_mut()
+    }
+
+    /// Returns `true` if the object has been destroyed or the pointer is null.
+    pub fn is_null(&self) -> bool {
+        self.as_ptr().is_null()
+    }
+
+    /// Returns a raw pointer to the object, which is null if the object has been destroyed or the pointer is null.
+    pub fn as_ptr(&self) -> *mut T {
+        self.pointer.as_ptr()
+    }
+
+    /// Returns a [`QPointer`] to the object, which does not own the object.
+    pub fn to_qpointer(&self) -> QPointer<T> {
+        QPointer {
+            pointer: self.pointer.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Upcast<QObject> + cxx::memory::UniquePtrTarget> From<UniquePtr<T>> for QBox<T> {
+    fn from(object: UniquePtr<T>) -> Self {
+        Self::new(object)
+    }
+}
+
+impl<T: Upcast<QObject>> PartialEq for QBox<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+impl<T: Upcast<QObject>> Eq for QBox<T> {}
+
+impl<T: Upcast<QObject>> fmt::Debug for QBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QBox").field(&self.as_ptr()).finish()
+    }
+}
@@ -4,7 +4,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::{ConnectionType, QMetaObjectConnection};
 use cxx::ExternType;
+use std::{
+    any::TypeId,
+    collections::HashSet,
+    ffi::c_void,
+    sync::{Mutex, MutexGuard},
+};
 
 /// A trait which describes the closure to be used with [CxxQtSignalHandler].
 #[doc(hidden)]
@@ -52,3 +59,134 @@ where
     type Kind = cxx::kind::Trivial;
     type Id = T::Id;
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct UniqueConnection {
+    sender: usize,
+    signal: TypeId,
+    closure: TypeId,
+}
+
+// Connections which were made with the UniqueConnection flag and are still connected
+static UNIQUE_CONNECTIONS: Mutex<Option<HashSet<UniqueConnection>>> = Mutex::new(None);
+
+fn unique_connections() -> MutexGuard<'static, Option<HashSet<UniqueConnection>>> {
+    UNIQUE_CONNECTIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The closure of a connection, which is moved into the signal handler
+///
+/// Qt drops the signal handler when the connection is disconnected, a single shot connection
+/// has been invoked, or the sender is destroyed. If the connection was made with the
+/// UniqueConnection flag, it is then removed from the unique connections.
+#[doc(hidden)]
+pub struct ConnectionClosure<F> {
+    closure: F,
+    unique: Option<UniqueConnection>,
+}
+
+impl<F> ConnectionClosure<F> {
+    /// A mutable reference to the inner closure
+    pub fn get_mut(&mut self) -> &mut F {
+        &mut self.closure
+    }
+}
+
+impl<F> Drop for ConnectionClosure<F> {
+    fn drop(&mut self) {
+        if let Some(unique) = self.unique.take() {
+            if let Some(connections) = unique_connections().as_mut() {
+                connections.remove(&unique);
+            }
+        }
+    }
+}
+
+/// Connect the closure `F` to the signal described by `T` of the given sender
+///
+/// Qt ignores UniqueConnection for functors, so instead closures are identified by their type.
+/// This only identifies closures which do not capture any state, as closures of the same type
+/// with different captures and function pointers of the same signature cannot be told apart,
+/// so these are always connected.
+///
+/// If an identical closure is still connected to the same signal of the same sender,
+/// an invalid connection is returned and `connect` is not called.
+///
+/// # Panics
+///
+/// Panics if [ConnectionType::UniqueConnection] is set for a closure which captures state
+/// or a function pointer, as these cannot be identified.
+pub fn connect<T, F>(
+    sender: *const c_void,
+    closure: F,
+    conn_type: ConnectionType,
+    connect: impl FnOnce(ConnectionType, ConnectionClosure<F>) -> QMetaObjectConnection,
+) -> QMetaObjectConnection
+where
+    T: CxxQtSignalHandlerClosure + 'static,
+    F: 'static,
+{
+    // Qt does not support UniqueConnection for functors, so don't pass the flag through
+    let base_conn_type = conn_type.without(ConnectionType::UniqueConnection);
+    if !conn_type.is_unique() {
+        return connect(
+            base_conn_type,
+            ConnectionClosure {
+                closure,
+                unique: None,
+            },
+        );
+    }
+
+    assert!(
+        core::mem::size_of::<F>() == 0,
+        "UniqueConnection can only be used with functions and closures which do not capture any state"
+    );
+
+    let unique = UniqueConnection {
+        sender: sender as usize,
+        signal: TypeId::of::<T>(),
+        closure: TypeId::of::<F>(),
+    };
+    if !unique_connections()
+        .get_or_insert_with(HashSet::new)
+        .insert(unique)
+    {
+        return QMetaObjectConnection::default();
+    }
+
+    // The lock is not held while connecting, as the closure is dropped if connecting fails
+    connect(
+        base_conn_type,
+        ConnectionClosure {
+            closure,
+            unique: Some(unique),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestClosure;
+
+    impl CxxQtSignalHandlerClosure for TestClosure {
+        type Id = ();
+        type FnType = dyn FnMut() + Send;
+    }
+
+    #[test]
+    #[should_panic(expected = "UniqueConnection can only be used")]
+    fn connect_unique_capturing_closure() {
+        let captured = 1;
+        connect::<TestClosure, _>(
+            core::ptr::null(),
+            move || captured,
+            ConnectionType::AutoConnection | ConnectionType::UniqueConnection,
+            |_, _| unreachable!("a closure which captures state cannot be unique"),
+        );
+    }
+}
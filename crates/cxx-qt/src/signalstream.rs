@@ -0,0 +1,318 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use futures_core::{FusedFuture, FusedStream, Stream};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::QMetaObjectConnectionGuard;
+
+/// The state which is shared between a [QSignalStream] and the connection to the signal
+struct Shared<T> {
+    /// Emissions of the signal which have not been received by the stream yet
+    queue: VecDeque<T>,
+    /// The waker of the task which is waiting for the next emission
+    waker: Option<Waker>,
+    /// Whether the connection has been dropped, for example as the QObject was destroyed
+    closed: bool,
+}
+
+fn lock<T>(shared: &Mutex<Shared<T>>) -> MutexGuard<'_, Shared<T>> {
+    // The lock is never held while calling user code, so it cannot be poisoned in a way
+    // that leaves the state inconsistent
+    shared
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The sending half of a [QSignalStream] which is moved into the closure connected to the signal
+#[doc(hidden)]
+pub struct QSignalSender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> QSignalSender<T> {
+    /// Queue an emission of the signal and wake the stream
+    pub fn send(&self, value: T) {
+        let waker = {
+            let mut shared = lock(&self.shared);
+            shared.queue.push_back(value);
+            shared.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for QSignalSender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = lock(&self.shared);
+            shared.closed = true;
+            shared.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A [Stream] of the arguments of each emission of a signal.
+///
+/// This is created with the generated `<signal>_stream` method of a QObject.
+/// The arguments are owned, so arguments which are passed by reference are cloned.
+///
+/// The signal is disconnected when the stream is dropped,
+/// and the stream ends once the connection is dropped, for example when the QObject is destroyed.
+#[must_use = "streams do nothing unless polled"]
+pub struct QSignalStream<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+    guard: Option<QMetaObjectConnectionGuard>,
+}
+
+impl<T> QSignalStream<T> {
+    /// Create a stream, where `connect` connects a closure which sends the arguments of the signal
+    #[doc(hidden)]
+    pub fn connect<F>(connect: F) -> Self
+    where
+        F: FnOnce(QSignalSender<T>) -> QMetaObjectConnectionGuard,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            queue: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }));
+        let guard = connect(QSignalSender {
+            shared: shared.clone(),
+        });
+
+        Self {
+            shared,
+            guard: Some(guard),
+        }
+    }
+
+    /// Disconnect from the signal, any emissions which have already been received can still be read from the stream
+    pub fn close(&mut self) {
+        // Dropping the guard disconnects the signal
+        self.guard = None;
+    }
+
+    /// Create a future which resolves to the arguments of the next emission of the signal
+    ///
+    /// The signal is disconnected once the future has resolved or is dropped.
+    pub fn once(self) -> QSignalOnce<T> {
+        QSignalOnce { stream: Some(self) }
+    }
+}
+
+impl<T> Stream for QSignalStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut shared = lock(&self.shared);
+        if let Some(value) = shared.queue.pop_front() {
+            Poll::Ready(Some(value))
+        } else if shared.closed {
+            Poll::Ready(None)
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> FusedStream for QSignalStream<T> {
+    fn is_terminated(&self) -> bool {
+        let shared = lock(&self.shared);
+        shared.closed && shared.queue.is_empty()
+    }
+}
+
+/// A [Future] which resolves to the arguments of the next emission of a signal.
+///
+/// This is created with the generated `<signal>_once` method of a QObject.
+/// It resolves to `None` if the connection is dropped before the signal is emitted,
+/// for example when the QObject is destroyed.
+///
+/// The signal is disconnected once the future has resolved or is dropped.
+#[must_use = "futures do nothing unless polled"]
+pub struct QSignalOnce<T> {
+    stream: Option<QSignalStream<T>>,
+}
+
+impl<T> Future for QSignalOnce<T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let Some(stream) = self.stream.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let output = core::task::ready!(Pin::new(stream).poll_next(cx));
+        // Disconnect from the signal as soon as it has been emitted
+        self.stream = None;
+        Poll::Ready(output)
+    }
+}
+
+impl<T> FusedFuture for QSignalOnce<T> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+
+    /// Create a stream which is not connected to a signal, along with its sender
+    fn channel<T>() -> (QSignalSender<T>, QSignalStream<T>) {
+        let stream = QSignalStream {
+            shared: Arc::new(Mutex::new(Shared {
+                queue: VecDeque::new(),
+                waker: None,
+                closed: false,
+            })),
+            guard: None,
+        };
+        let sender = QSignalSender {
+            shared: stream.shared.clone(),
+        };
+        (sender, stream)
+    }
+
+    #[derive(Default)]
+    struct CountingWaker {
+        wakes: AtomicUsize,
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wakes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn poll_next<T>(stream: &mut QSignalStream<T>, waker: &Waker) -> Poll<Option<T>> {
+        Pin::new(stream).poll_next(&mut Context::from_waker(waker))
+    }
+
+    fn poll_once<T>(once: &mut QSignalOnce<T>, waker: &Waker) -> Poll<Option<T>> {
+        Pin::new(once).poll(&mut Context::from_waker(waker))
+    }
+
+    #[test]
+    fn stream_yields_emissions_in_order() {
+        let (sender, mut stream) = channel();
+        let waker = Waker::noop();
+
+        assert_eq!(poll_next(&mut stream, waker), Poll::Pending);
+        sender.send(1);
+        sender.send(2);
+        assert_eq!(poll_next(&mut stream, waker), Poll::Ready(Some(1)));
+        assert_eq!(poll_next(&mut stream, waker), Poll::Ready(Some(2)));
+        assert_eq!(poll_next(&mut stream, waker), Poll::Pending);
+        assert!(!stream.is_terminated());
+    }
+
+    #[test]
+    fn stream_send_wakes_task() {
+        let (sender, mut stream) = channel();
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counter.clone());
+
+        assert_eq!(poll_next(&mut stream, &waker), Poll::Pending);
+        sender.send(());
+        assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+
+        // The waker is only used once, until the stream is polled again
+        sender.send(());
+        assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn stream_ends_once_disconnected() {
+        let (sender, mut stream) = channel();
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counter.clone());
+
+        assert_eq!(poll_next(&mut stream, &waker), Poll::Pending);
+        sender.send(1);
+        drop(sender);
+        assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+
+        // Emissions which were received before the disconnect are still yielded
+        assert!(!stream.is_terminated());
+        assert_eq!(poll_next(&mut stream, &waker), Poll::Ready(Some(1)));
+        assert!(stream.is_terminated());
+        assert_eq!(poll_next(&mut stream, &waker), Poll::Ready(None));
+    }
+
+    #[test]
+    fn stream_drop_sender_wakes_task() {
+        let (sender, mut stream) = channel::<()>();
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counter.clone());
+
+        assert_eq!(poll_next(&mut stream, &waker), Poll::Pending);
+        drop(sender);
+        assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+        assert_eq!(poll_next(&mut stream, &waker), Poll::Ready(None));
+    }
+
+    #[test]
+    fn once_resolves_to_next_emission() {
+        let (sender, stream) = channel();
+        let mut once = stream.once();
+        let waker = Waker::noop();
+
+        assert_eq!(poll_once(&mut once, waker), Poll::Pending);
+        assert!(!once.is_terminated());
+
+        sender.send((1, String::from("first")));
+        sender.send((2, String::from("second")));
+        assert_eq!(
+            poll_once(&mut once, waker),
+            Poll::Ready(Some((1, String::from("first"))))
+        );
+        assert!(once.is_terminated());
+
+        // The stream is dropped once resolved, so later emissions are not received
+        assert_eq!(poll_once(&mut once, waker), Poll::Ready(None));
+    }
+
+    #[test]
+    fn once_resolves_to_none_when_disconnected() {
+        let (sender, stream) = channel::<i32>();
+        let mut once = stream.once();
+
+        drop(sender);
+        assert_eq!(poll_once(&mut once, Waker::noop()), Poll::Ready(None));
+        assert!(once.is_terminated());
+    }
+
+    #[test]
+    fn close_keeps_received_emissions() {
+        let (sender, mut stream) = channel();
+        sender.send(1);
+
+        stream.close();
+        assert!(stream.guard.is_none());
+        assert_eq!(poll_next(&mut stream, Waker::noop()), Poll::Ready(Some(1)));
+    }
+}
@@ -3,8 +3,19 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
 use cxx::ExternType;
+use std::{
+    sync::{Arc, Mutex},
+    task::Wake,
+};
 use thiserror::Error;
 
 use crate::Threading;
@@ -19,6 +30,15 @@ pub enum ThreadingQueueError {
     /// Threading failed calling invokeMethod on the object
     #[error("Cannot queue function pointer as invokeMethod on object failed")]
     InvokeMethodFailed,
+    /// Threading failed as the maximum number of closures are already pending for the object
+    #[error("Cannot queue function pointer as the queue of the object is full")]
+    QueueFull,
+    /// Blocking failed as it was called from the thread of the object, which would deadlock
+    #[error("Cannot block on the result as the calling thread is the thread of the object")]
+    WouldDeadlock,
+    /// Blocking failed as the closure did not run before the timeout
+    #[error("Cannot block on the result as the closure did not run before the timeout")]
+    TimedOut,
     /// Threading failed with unknown error
     #[error("Cannot queue as an unknown error occurred")]
     Unknown,
@@ -29,11 +49,39 @@ impl From<u8> for ThreadingQueueError {
         match value {
             1 => Self::ObjectDestroyed,
             2 => Self::InvokeMethodFailed,
+            3 => Self::QueueFull,
             _others => Self::Unknown,
         }
     }
 }
 
+/// Options for queueing a closure with [CxxQtThread::queue_with_options]
+///
+/// The default options queue every closure with a normal priority and without a limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThreadingQueueOptions {
+    /// Coalesce closures which are queued with the same key.
+    ///
+    /// If a closure with this key is still pending for the QObject then it is replaced
+    /// by the new closure, rather than queueing another closure onto the event loop.
+    pub coalesce_key: Option<u64>,
+    /// The maximum number of closures which can be pending for the QObject.
+    ///
+    /// If the queue is full then [ThreadingQueueError::QueueFull] is returned.
+    pub max_pending: Option<usize>,
+    /// Post the closure with `Qt::HighEventPriority`, so that it runs before
+    /// closures and events with a normal priority which are already pending.
+    pub high_priority: bool,
+    /// Drop the closure without running it if it has not run before the timeout has elapsed.
+    ///
+    /// A timeout which is too large to be represented, such as [Duration::MAX](core::time::Duration::MAX),
+    /// is the same as no timeout.
+    ///
+    /// This is useful for updates which are only relevant for a while, so that a busy
+    /// event loop does not apply them late.
+    pub timeout: Option<core::time::Duration>,
+}
+
 /// A threading helper which is created from a QObject that implements [Threading].
 ///
 /// This allows for queueing closures onto the Qt event loop from a background thread
@@ -91,6 +139,73 @@ unsafe impl<T> Send for CxxQtThread<T> where T: Threading {}
 // places as it protects the queue call and the closure with mutexes
 unsafe impl<T> Sync for CxxQtThread<T> where T: Threading {}
 
+/// The future of an async invokable, which is polled on the Qt event loop of its QObject
+struct InvokableTask<T, Fut, F>
+where
+    T: Threading,
+{
+    /// The future and the closure which is called with its output, which are None once it has completed
+    state: Mutex<Option<(Pin<Box<Fut>>, F)>>,
+    /// Whether a poll of the future is already in the queue of the event loop
+    queued: AtomicBool,
+    thread: CxxQtThread<T>,
+}
+
+impl<T, Fut, F> InvokableTask<T, Fut, F>
+where
+    T: Threading + 'static,
+    Fut: Future + Send + 'static,
+    F: FnOnce(Pin<&mut T>, Fut::Output) + Send + 'static,
+{
+    fn poll(self: Arc<Self>, qobject: Pin<&mut T>) {
+        self.queued.store(false, Ordering::Release);
+
+        let waker = Waker::from(self.clone());
+        let mut context = Context::from_waker(&waker);
+
+        // If the lock is poisoned then the future or closure has panicked, so there is nothing left to run
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let Some((future, _)) = state.as_mut() else {
+            return;
+        };
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            if let Some((_, finished)) = state.take() {
+                drop(state);
+                // This runs in the same poll as the future completed, so no further closure is queued
+                finished(qobject, output);
+            }
+        }
+    }
+}
+
+impl<T, Fut, F> Wake for InvokableTask<T, Fut, F>
+where
+    T: Threading + 'static,
+    Fut: Future + Send + 'static,
+    F: FnOnce(Pin<&mut T>, Fut::Output) + Send + 'static,
+{
+    fn wake(self: Arc<Self>) {
+        if self.queued.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let task = self.clone();
+        if self
+            .thread
+            .queue(move |qobject| task.poll(qobject))
+            .is_err()
+        {
+            // The QObject has been destroyed, so there is nobody to notify.
+            // The future can wake itself while it is being polled, so the lock is not waited for.
+            if let Ok(mut state) = self.state.try_lock() {
+                *state = None;
+            }
+        }
+    }
+}
+
 impl<T> CxxQtThread<T>
 where
     T: Threading,
@@ -104,7 +219,139 @@ where
         F: FnOnce(Pin<&mut T>),
         F: Send + 'static,
     {
-        T::queue(self, f)
+        T::queue(self, f, ThreadingQueueOptions::default())
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject with the given [ThreadingQueueOptions]
+    ///
+    /// This allows for limiting how many closures a background thread can queue,
+    /// such as only keeping the latest pending update for each key,
+    /// or dropping closures which have been pending for longer than a timeout.
+    ///
+    /// ```rust,ignore
+    /// let options = ThreadingQueueOptions {
+    ///     coalesce_key: Some(SENSOR_UPDATE),
+    ///     ..Default::default()
+    /// };
+    /// thread.queue_with_options(options, move |qobject| qobject.set_reading(reading))?;
+    /// ```
+    pub fn queue_with_options<F>(
+        &self,
+        options: ThreadingQueueOptions,
+        f: F,
+    ) -> Result<(), crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        // A timeout too large to be represented as an Instant never elapses
+        let deadline = options
+            .timeout
+            .and_then(|timeout| std::time::Instant::now().checked_add(timeout));
+        if let Some(deadline) = deadline {
+            // Closures which run too late are dropped, which counts as having run for the queue
+            return T::queue(
+                self,
+                move |qobject| {
+                    if std::time::Instant::now() <= deadline {
+                        f(qobject);
+                    }
+                },
+                options,
+            );
+        }
+
+        T::queue(self, f, options)
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject
+    /// and block the calling thread until it has run, returning the result of the closure.
+    ///
+    /// This allows for reading state of the QObject from a background thread.
+    ///
+    /// If the QObject is destroyed before the closure has run then
+    /// [ThreadingQueueError::ObjectDestroyed] is returned.
+    ///
+    /// Calling this from the thread the QObject lives in would deadlock, as the event loop
+    /// cannot run the closure while the thread is blocked, so instead
+    /// [ThreadingQueueError::WouldDeadlock] is returned.
+    ///
+    /// Note that this blocks until the event loop of the thread the QObject lives in runs the closure,
+    /// so if that thread is not running an event loop or is itself blocked waiting on the calling thread
+    /// then this never returns. Use [queue_with_result_timeout](CxxQtThread::queue_with_result_timeout)
+    /// when the event loop might not be running.
+    ///
+    /// ```rust,ignore
+    /// let thread = qobject.qt_thread();
+    /// std::thread::spawn(move || {
+    ///     let value = thread.queue_with_result(|qobject| *qobject.value())?;
+    ///     // ...
+    /// });
+    /// ```
+    pub fn queue_with_result<F, R>(&self, f: F) -> Result<R, crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        // If the QObject is destroyed before the closure runs, the closure and sender are dropped
+        self.queue_for_result(f)?
+            .recv()
+            .map_err(|_| ThreadingQueueError::ObjectDestroyed)
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject
+    /// and block the calling thread until it has run or the timeout has elapsed.
+    ///
+    /// This behaves the same as [queue_with_result](CxxQtThread::queue_with_result),
+    /// but returns [ThreadingQueueError::TimedOut] if the closure has not run before the timeout,
+    /// for example as the thread of the QObject is not running an event loop.
+    ///
+    /// Note that the closure stays queued after a timeout, so it can still run later.
+    pub fn queue_with_result_timeout<F, R>(
+        &self,
+        timeout: std::time::Duration,
+        f: F,
+    ) -> Result<R, crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        self.queue_for_result(f)?
+            .recv_timeout(timeout)
+            .map_err(|error| match error {
+                std::sync::mpsc::RecvTimeoutError::Timeout => ThreadingQueueError::TimedOut,
+                std::sync::mpsc::RecvTimeoutError::Disconnected => {
+                    ThreadingQueueError::ObjectDestroyed
+                }
+            })
+    }
+
+    /// Queue the given closure and return a receiver for its result
+    fn queue_for_result<F, R>(
+        &self,
+        f: F,
+    ) -> Result<std::sync::mpsc::Receiver<R>, crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        if T::is_current_thread(self) {
+            return Err(ThreadingQueueError::WouldDeadlock);
+        }
+
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        T::queue(
+            self,
+            move |qobject| {
+                // The receiver could have been dropped after a timeout
+                sender.send(f(qobject)).ok();
+            },
+            ThreadingQueueOptions::default(),
+        )?;
+        Ok(receiver)
     }
 
     /// Checks whether the associated `QObject` has been destroyed.
@@ -139,4 +386,27 @@ where
     pub fn is_destroyed(&self) -> bool {
         T::is_destroyed(self)
     }
+
+    /// Poll the future of an async invokable on the Qt event loop for this QObject,
+    /// then call `finished` with the output from the same poll that completed the future.
+    ///
+    /// Once the QObject is destroyed the future is no longer polled,
+    /// and it is dropped without completing when it is next woken.
+    #[doc(hidden)]
+    pub fn spawn_invokable<Fut, F>(self, future: Fut, finished: F)
+    where
+        T: 'static,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+        F: FnOnce(Pin<&mut T>, Fut::Output),
+        F: Send + 'static,
+    {
+        let task = Arc::new(InvokableTask {
+            state: Mutex::new(Some((Box::pin(future), finished))),
+            queued: AtomicBool::new(false),
+            thread: self,
+        });
+        // Queue the first poll, as the QObject is borrowed while the invokable is called
+        task.wake();
+    }
 }
@@ -57,6 +57,7 @@ pub use tool::{
 mod utils;
 
 use std::{
+    collections::BTreeSet,
     env,
     ffi::{OsStr, OsString},
     fs::File,
@@ -406,12 +407,15 @@ impl QtBuild {
         std::fs::create_dir_all(&qml_plugin_dir).expect("Could not create qml_plugin dir");
 
         // Run qmltyperegistrar over the meta types
-        let qmltyperegistrar_path = self.qmltyperegistrar().compile(
-            metatypes_json,
-            &qmltypes_path,
-            uri,
-            Version::new(version_major as u64, version_minor as u64, 0),
-        );
+        let qmltyperegistrar_path = self
+            .qmltyperegistrar()
+            .foreign_types(self.qt_metatypes_json())
+            .compile(
+                metatypes_json,
+                &qmltypes_path,
+                uri,
+                Version::new(version_major as u64, version_minor as u64, 0),
+            );
 
         // Generate QQmlEngineExtensionPlugin
         let qml_plugin_cpp_path = qml_plugin_dir.join(format!("{plugin_class_name}.cpp"));
@@ -470,4 +474,42 @@ impl QtBuild {
     pub fn qtpaths(&self) -> QtToolQtPaths {
         QtToolQtPaths::new(self.qt_installation.as_ref())
     }
+
+    /// Find the metatypes JSON files of the Qt modules that are being linked
+    ///
+    /// These are only installed with Qt 6 and are found in the metatypes folder of either
+    /// the architecture dependent data or the libraries of the Qt installation.
+    pub fn qt_metatypes_json(&self) -> Vec<PathBuf> {
+        if self.qt_installation.version().major < 6
+            || self.qt_installation.try_find_tool(QtTool::QtPaths).is_err()
+        {
+            return vec![];
+        }
+
+        let qtpaths = self.qtpaths();
+        let metatypes_dirs: BTreeSet<PathBuf> = ["QT_INSTALL_ARCHDATA", "QT_INSTALL_LIBS"]
+            .into_iter()
+            .filter_map(|query| qtpaths.query(query))
+            .map(|path| PathBuf::from(path).join("metatypes"))
+            .filter(|path| path.is_dir())
+            .collect();
+
+        let mut metatypes_json = vec![];
+        for qt_module in &self.qt_modules {
+            let prefix = format!("qt6{}_", qt_module.to_lowercase());
+            for dir in &metatypes_dirs {
+                let Ok(entries) = std::fs::read_dir(dir) else {
+                    continue;
+                };
+
+                metatypes_json.extend(entries.filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    let file_name = path.file_name()?.to_str()?;
+                    (file_name.starts_with(&prefix) && file_name.ends_with("metatypes.json"))
+                        .then_some(path)
+                }));
+            }
+        }
+        metatypes_json
+    }
 }
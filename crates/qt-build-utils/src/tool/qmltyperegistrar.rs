@@ -13,6 +13,7 @@ use std::{
 /// A wrapper around the [qmltyperegistrar](https://www.qt.io/blog/qml-type-registration-in-qt-5.15) tool
 pub struct QtToolQmlTypeRegistrar {
     executable: PathBuf,
+    foreign_types: Vec<PathBuf>,
 }
 
 impl QtToolQmlTypeRegistrar {
@@ -25,7 +26,25 @@ impl QtToolQmlTypeRegistrar {
         // Ensure that the executable works
         utils::check_executable_help(&executable).unwrap();
 
-        Self { executable }
+        Self {
+            executable,
+            foreign_types: vec![],
+        }
+    }
+
+    /// Metatypes JSON files of other modules, such as Qt modules, which types in the QML module refer to
+    ///
+    /// These allow for resolving the types used with `QML_FOREIGN` and `QML_EXTENDED`.
+    pub fn foreign_types(
+        mut self,
+        foreign_types: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Self {
+        self.foreign_types.extend(
+            foreign_types
+                .into_iter()
+                .map(|path| path.as_ref().to_path_buf()),
+        );
+        self
     }
 
     /// Run [qmltyperegistrar](https://www.qt.io/blog/qml-type-registration-in-qt-5.15)
@@ -72,6 +91,14 @@ impl QtToolQmlTypeRegistrar {
             "-o".to_owned(),
             qmltyperegistrar_output_path.to_string_lossy().into_owned(),
         ];
+        if !self.foreign_types.is_empty() {
+            let foreign_types: Vec<_> = self
+                .foreign_types
+                .iter()
+                .map(|path| path.to_string_lossy().into_owned())
+                .collect();
+            args.push(format!("--foreign-types={}", foreign_types.join(",")));
+        }
         args.extend(metatypes_json);
         let cmd = Command::new(&self.executable)
             .args(args)
@@ -47,12 +47,10 @@ mod qobject {
     extern "RustQt" {
         #[qobject]
         #[qml_element]
-        #[qproperty(*mut QQuickTextDocument, input, READ, NOTIFY, WRITE=set_input)]
-        #[qproperty(*mut QQuickTextDocument, output)]
+        #[qproperty(QPointer<QQuickTextDocument>, input)]
+        #[qproperty(QPointer<QQuickTextDocument>, output)]
         type SpanInspector = super::SpanInspectorRust;
 
-        unsafe fn set_input(self: Pin<&mut SpanInspector>, input: *mut QQuickTextDocument);
-
         #[qinvokable]
         #[cxx_name = "rebuildOutput"]
         fn rebuild_output(self: Pin<&mut SpanInspector>, cursor_position: i32);
@@ -63,25 +61,14 @@ mod qobject {
 }
 
 use cxx_qt::{CxxQtType, Threading};
-use qobject::{QQuickTextDocument, QString, QTextDocument};
-use std::{pin::Pin, ptr};
+use qobject::{QString, QTextDocument};
+use std::pin::Pin;
 
+#[derive(Default)]
 pub struct SpanInspectorRust {
-    input: *mut QQuickTextDocument,
-    output: *mut QQuickTextDocument,
     thread_count: u32,
 }
 
-impl Default for SpanInspectorRust {
-    fn default() -> Self {
-        Self {
-            input: ptr::null_mut(),
-            output: ptr::null_mut(),
-            thread_count: 0,
-        }
-    }
-}
-
 // This Trait is necessary because `prettyplease` seems to add certain characters in some situations.
 // By simply ignoring these characters, we can work around the problem.
 impl<I> FilterPrettyPlease for I where I: Iterator<Item = TokenTree> + Sized {}
@@ -100,31 +87,37 @@ struct TokenFlag {
 }
 
 impl qobject::SpanInspector {
-    unsafe fn output_document(&self) -> Pin<&mut QTextDocument> {
-        if self.output.is_null() {
-            panic!("Output document must be set!");
-        }
-        let output = unsafe { &*self.output };
-        unsafe { Pin::new_unchecked(&mut *output.text_document()) }
-    }
-
-    fn set_input(mut self: Pin<&mut Self>, input: *mut QQuickTextDocument) {
-        self.as_mut().rust_mut().input = input;
-        self.as_mut().input_changed();
+    fn output_document(self: Pin<&mut Self>) -> Option<Pin<&mut QTextDocument>> {
+        let output = self.output();
+        // SAFETY: the QQuickTextDocument is owned by QML, which does not destroy it while we are running
+        let output = unsafe { output.get() }?;
+        // SAFETY: the text document is owned by the QQuickTextDocument, which is still alive,
+        // and it is only borrowed for as long as the SpanInspector is mutably borrowed
+        unsafe { output.text_document().as_mut() }
+            .map(|document| unsafe { Pin::new_unchecked(document) })
     }
 
     fn rebuild_output(mut self: Pin<&mut Self>, cursor_position: i32) {
-        let input = unsafe { Pin::new_unchecked(&mut *self.input) };
-        let qt_thread = self.qt_thread();
-        unsafe { self.output_document() }.set_html(&QString::from(String::from("expanding...")));
+        let input = self.input();
+        // SAFETY: the QQuickTextDocument is owned by QML, which does not destroy it while we are running,
+        // and the text document is owned by the QQuickTextDocument, which is still alive
+        let Some(text) = unsafe { input.get() }
+            .and_then(|input| unsafe { input.text_document().as_ref() })
+            .map(QTextDocument::to_plain_text)
+        else {
+            return;
+        };
 
-        let text = unsafe { Pin::new_unchecked(&mut *input.text_document()) }.to_plain_text();
+        let qt_thread = self.qt_thread();
+        if let Some(output) = self.as_mut().output_document() {
+            output.set_html(&QString::from(String::from("expanding...")));
+        }
 
         self.as_mut().rust_mut().thread_count += 1;
         let thread_id = self.thread_count;
 
         std::thread::spawn(move || {
-            let output = QString::from(
+            let html = QString::from(
                 match Self::expand(&text.to_string(), cursor_position as usize) {
                     Ok((expanded, token_flags)) => {
                         let Ok(file) = syn::parse_file(expanded.as_str())
@@ -140,7 +133,9 @@ impl qobject::SpanInspector {
             qt_thread
                 .queue(move |this| {
                     if thread_id == this.thread_count {
-                        unsafe { this.output_document() }.set_html(&output)
+                        if let Some(output) = this.output_document() {
+                            output.set_html(&html)
+                        }
                     }
                 })
                 .ok();
@@ -5,6 +5,7 @@
 // SPDX-FileContributor: Gerhard de Clercq <gerhard.declercq@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
+#include <QtCore/QPointer>
 #include <QtCore/QThread>
 #include <QtCore/QTimer>
 #include <QtTest/QSignalSpy>
@@ -12,8 +13,11 @@
 
 #include "basic_cxx_qt/src/data.cxxqt.h"
 #include "basic_cxx_qt/src/empty.cxxqt.h"
+#include "basic_cxx_qt/src/executor.cxxqt.h"
+#include "basic_cxx_qt/src/gadget.cxxqt.h"
 #include "basic_cxx_qt/src/lib.cxxqt.h"
 #include "basic_cxx_qt/src/naming.cxxqt.h"
+#include "basic_cxx_qt/src/pointer.cxxqt.h"
 #include "basic_cxx_qt/src/types.cxxqt.h"
 
 class CxxQtTest : public QObject
@@ -98,6 +102,25 @@ private Q_SLOTS:
              QStringLiteral("{\"number\":16,\"string\":\"Hello\"}"));
   }
 
+  // The auto-generated reset uses the Default implementation of the Rust struct
+  void test_reset_to_struct_default()
+  {
+    cxx_qt::my_data::MyData data;
+    QSignalSpy numberSpy(&data, &cxx_qt::my_data::MyData::numberChanged);
+
+    data.setNumber(16);
+    QCOMPARE(numberSpy.count(), 1);
+
+    // The struct is initialised with 4 rather than the default of the type
+    data.resetNumber();
+    QCOMPARE(data.getNumber(), 4);
+    QCOMPARE(numberSpy.count(), 2);
+
+    // Resetting again does not emit the notify signal
+    data.resetNumber();
+    QCOMPARE(numberSpy.count(), 2);
+  }
+
   // CXX-Qt allows Rust code to queue a request
   void test_queue_request()
   {
@@ -129,6 +152,109 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to block on the result of a queued closure
+  void test_queue_with_result()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(4);
+    obj.queueWithResultTest();
+    QTRY_COMPARE(obj.getNumber(), 8);
+  }
+
+  // Blocking on the thread of the QObject would deadlock, so fails instead
+  void test_queue_with_result_same_thread()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.queueWithResultSameThread(), true);
+  }
+
+  // Blocking times out if the event loop does not run the closure in time
+  void test_queue_with_result_timeout()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.queueWithResultTimeout(), true);
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+
+    // The closure stays queued, so it still runs after the timeout
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // Closures queued with the same coalescing key only run the latest closure
+  void test_queue_coalesced()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueCoalesced();
+    QTRY_COMPARE(obj.getNumber(), 3);
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // High priority closures run before pending closures with a normal priority
+  void test_queue_high_priority()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueHighPriority();
+    QTRY_COMPARE(obj.getNumber(), 1);
+  }
+
+  // Closures which are pending for longer than their timeout are dropped
+  void test_queue_with_timeout()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueWithTimeout();
+    QTRY_COMPARE(obj.getNumber(), 1);
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+  }
+
+  // A timeout which is too large for a deadline is the same as no timeout
+  void test_queue_with_max_timeout()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueWithMaxTimeout();
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // Queueing fails once the maximum number of closures are pending
+  void test_queue_full()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.queueFull(), true);
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+
+    // The closures which were queued still run
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 2);
+  }
+
+  // CXX-Qt allows Rust code to spawn a future onto the Qt event loop
+  void test_spawn_future()
+  {
+    cxx_qt::executor::ExecutorObject obj;
+    obj.spawnYielding(3);
+    QCOMPARE(obj.getYields(), 0);
+    QCOMPARE(obj.getFinished(), false);
+    QTRY_COMPARE(obj.getFinished(), true);
+    QCOMPARE(obj.getYields(), 3);
+  }
+
+  // Spawned futures are dropped when the QObject is destroyed
+  void test_spawn_future_dropped_with_qobject()
+  {
+    const auto dropped = cxx_qt::executor::pendingFutureDropped();
+
+    auto obj = new cxx_qt::executor::ExecutorObject();
+    obj->spawnPending();
+    QTRY_VERIFY(cxx_qt::executor::pendingFuturePolled());
+    QCOMPARE(cxx_qt::executor::pendingFutureDropped(), dropped);
+
+    // The waker of the future is still alive, but the future is dropped
+    delete obj;
+    QCOMPARE(cxx_qt::executor::pendingFutureDropped(), dropped + 1);
+
+    // Waking the future after the QObject has been destroyed does nothing
+    cxx_qt::executor::wakePendingFuture();
+    QCoreApplication::processEvents();
+    QCOMPARE(cxx_qt::executor::pendingFutureDropped(), dropped + 1);
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
@@ -199,6 +325,152 @@ private Q_SLOTS:
     QCOMPARE(types.getUint_32(), 4);
   }
 
+  // CXX-Qt Q_GADGETs can be used as properties of a QObject
+  void test_gadget_property()
+  {
+    cxx_qt::my_gadget::MyGadgetObject obj;
+    QSignalSpy positionSpy(
+      &obj, &cxx_qt::my_gadget::MyGadgetObject::positionChanged);
+
+    cxx_qt::my_gadget::Point point;
+    QCOMPARE(point.getX(), 0);
+    point.setX(1);
+    point.setY(2);
+    QCOMPARE(point.getX(), 1);
+    QCOMPARE(point.getY(), 2);
+
+    obj.setPosition(point);
+    QCOMPARE(positionSpy.count(), 1);
+    QCOMPARE(obj.getPosition().getX(), 1);
+    QCOMPARE(obj.getPosition().getY(), 2);
+
+    // Setting an equal value does not emit the notify signal
+    obj.setPosition(point);
+    QCOMPARE(positionSpy.count(), 1);
+
+    obj.moveRight();
+    QCOMPARE(positionSpy.count(), 2);
+    QCOMPARE(obj.getPosition().getX(), 2);
+
+    // The gadget is exposed through the meta-object system
+    const auto variant = obj.property("position");
+    QVERIFY(variant.canConvert<cxx_qt::my_gadget::Point>());
+    QCOMPARE(variant.value<cxx_qt::my_gadget::Point>().getX(), 2);
+  }
+
+  // CXX-Qt QBox properties own their object
+  void test_qbox_property()
+  {
+    cxx_qt::my_pointer::MyPointerObject obj;
+    QSignalSpy childSpy(&obj,
+                        &cxx_qt::my_pointer::MyPointerObject::childChanged);
+
+    // Setting the object from Rust parents it to the QObject
+    auto* first = new QObject();
+    QPointer<QObject> firstGuard = first;
+    obj.setChildFromRust(first);
+    QCOMPARE(childSpy.count(), 1);
+    QCOMPARE(obj.getChild(), first);
+    QCOMPARE(first->parent(), &obj);
+    QVERIFY(!obj.childIsNull());
+
+    // Replacing the object deletes the previous object
+    auto* second = new QObject();
+    obj.setChildFromRust(second);
+    QCOMPARE(childSpy.count(), 2);
+    QCOMPARE(obj.getChild(), second);
+    QTRY_VERIFY(firstGuard.isNull());
+    QCOMPARE(childSpy.count(), 2);
+
+    // Deleting the object elsewhere makes the property null
+    delete second;
+    QCOMPARE(childSpy.count(), 3);
+    QVERIFY(obj.getChild() == nullptr);
+    QVERIFY(obj.childIsNull());
+
+    // Setting the object from C++ also parents it to the QObject
+    auto* third = new QObject();
+    QPointer<QObject> thirdGuard = third;
+    obj.setChild(third);
+    QCOMPARE(childSpy.count(), 4);
+    QCOMPARE(third->parent(), &obj);
+
+    // Resetting the property deletes the object
+    obj.resetChild();
+    QCOMPARE(childSpy.count(), 5);
+    QVERIFY(obj.getChild() == nullptr);
+    QTRY_VERIFY(thirdGuard.isNull());
+    QCOMPARE(childSpy.count(), 5);
+  }
+
+  // CXX-Qt QBox properties are deleted with the QObject
+  void test_qbox_property_deleted_with_qobject()
+  {
+    auto* child = new QObject();
+    QPointer<QObject> guard = child;
+    {
+      cxx_qt::my_pointer::MyPointerObject obj;
+      obj.setChildFromRust(child);
+    }
+    QVERIFY(guard.isNull());
+  }
+
+  // CXX-Qt QPointer properties become null when their object is destroyed
+  void test_qpointer_property()
+  {
+    cxx_qt::my_pointer::MyPointerObject obj;
+    QSignalSpy targetSpy(&obj,
+                         &cxx_qt::my_pointer::MyPointerObject::targetChanged);
+
+    // The property does not own the object
+    QObject target;
+    obj.setTargetFromRust(&target);
+    QCOMPARE(targetSpy.count(), 1);
+    QCOMPARE(obj.getTarget(), &target);
+    QVERIFY(target.parent() == nullptr);
+    QVERIFY(!obj.targetIsNull());
+
+    // Setting the same object does not emit the notify signal
+    obj.setTarget(&target);
+    QCOMPARE(targetSpy.count(), 1);
+
+    // Destroying the object makes the property null and emits once
+    auto* destroyed = new QObject();
+    obj.setTarget(destroyed);
+    QCOMPARE(targetSpy.count(), 2);
+    delete destroyed;
+    QCOMPARE(targetSpy.count(), 3);
+    QVERIFY(obj.getTarget() == nullptr);
+    QVERIFY(obj.targetIsNull());
+  }
+
+  // CXX-Qt QPointer properties disconnect from the previous object
+  void test_qpointer_property_reset()
+  {
+    cxx_qt::my_pointer::MyPointerObject obj;
+    QSignalSpy targetSpy(&obj,
+                         &cxx_qt::my_pointer::MyPointerObject::targetChanged);
+
+    // Destroying the previous object does not emit the notify signal
+    auto* previous = new QObject();
+    QObject target;
+    obj.setTargetFromRust(previous);
+    obj.setTargetFromRust(&target);
+    QCOMPARE(targetSpy.count(), 2);
+    delete previous;
+    QCOMPARE(targetSpy.count(), 2);
+    QCOMPARE(obj.getTarget(), &target);
+
+    // Resetting the property clears it and emits the notify signal
+    obj.resetTarget();
+    QCOMPARE(targetSpy.count(), 3);
+    QVERIFY(obj.targetIsNull());
+
+    // The object is no longer tracked once the property has been reset
+    obj.resetTarget();
+    QCOMPARE(targetSpy.count(), 3);
+  }
+
   // Tests that we can build an empty QObject end to end
   void testEmpty() { Empty empty; }
 
@@ -9,8 +9,11 @@ fn main() {
     CxxQtBuilder::new()
         .file("src/empty.rs")
         .file("src/data.rs")
+        .file("src/executor.rs")
         .file("src/lib.rs")
         .file("src/types.rs")
         .file("src/naming.rs")
+        .file("src/gadget.rs")
+        .file("src/pointer.rs")
         .build();
 }
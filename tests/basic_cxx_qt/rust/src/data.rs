@@ -32,7 +32,7 @@ mod qobject {
 
     extern "RustQt" {
         #[qobject]
-        #[qproperty(i32, number)]
+        #[qproperty(i32, number, READ, WRITE, NOTIFY, RESET)]
         #[qproperty(QString, string)]
         type MyData = super::MyDataRust;
 
@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(namespace = "cxx_qt::executor")]
+mod qobject {
+    extern "RustQt" {
+        #[qobject]
+        #[qproperty(i32, yields)]
+        #[qproperty(bool, finished)]
+        type ExecutorObject = super::ExecutorObjectRust;
+    }
+
+    // Enabling threading on the qobject
+    impl cxx_qt::Threading for ExecutorObject {}
+
+    // Note that we are only testing with C++ here so we don't need qinvokable
+    extern "RustQt" {
+        #[cxx_name = "spawnYielding"]
+        fn spawn_yielding(self: &ExecutorObject, count: i32);
+
+        #[cxx_name = "spawnPending"]
+        fn spawn_pending(self: &ExecutorObject);
+    }
+
+    extern "Rust" {
+        #[cxx_name = "pendingFutureDropped"]
+        fn pending_future_dropped() -> usize;
+
+        #[cxx_name = "pendingFuturePolled"]
+        fn pending_future_polled() -> bool;
+
+        #[cxx_name = "wakePendingFuture"]
+        fn wake_pending_future();
+    }
+}
+
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+};
+use cxx_qt::Threading;
+use std::sync::Mutex;
+
+/// The number of pending futures which have been dropped
+static PENDING_DROPPED: AtomicUsize = AtomicUsize::new(0);
+/// The waker of the last pending future, which is held outside of the task
+static PENDING_WAKER: Mutex<Option<Waker>> = Mutex::new(None);
+
+#[derive(Default)]
+pub struct ExecutorObjectRust {
+    yields: i32,
+    finished: bool,
+}
+
+/// A future which wakes itself and is pending once before it is ready
+#[derive(Default)]
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Counts when the pending future has been dropped
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        PENDING_DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl qobject::ExecutorObject {
+    fn spawn_yielding(&self, count: i32) {
+        let qt_thread = self.qt_thread();
+        self.qt_thread()
+            .spawn(async move {
+                for _ in 0..count {
+                    YieldNow::default().await;
+                    qt_thread
+                        .queue(|qobject| {
+                            let yields = *qobject.yields();
+                            qobject.set_yields(yields + 1);
+                        })
+                        .unwrap();
+                }
+
+                qt_thread
+                    .queue(|qobject| qobject.set_finished(true))
+                    .unwrap();
+            })
+            .unwrap();
+    }
+
+    fn spawn_pending(&self) {
+        self.qt_thread()
+            .spawn(async move {
+                let _counter = DropCounter;
+                core::future::poll_fn(|cx| {
+                    // Keep the waker alive outside of the task, which should not keep
+                    // the future alive once the QObject has been destroyed
+                    *PENDING_WAKER.lock().unwrap() = Some(cx.waker().clone());
+                    Poll::<()>::Pending
+                })
+                .await;
+            })
+            .unwrap();
+    }
+}
+
+fn pending_future_dropped() -> usize {
+    PENDING_DROPPED.load(Ordering::SeqCst)
+}
+
+fn pending_future_polled() -> bool {
+    PENDING_WAKER.lock().unwrap().is_some()
+}
+
+fn wake_pending_future() {
+    if let Some(waker) = PENDING_WAKER.lock().unwrap().take() {
+        waker.wake();
+    }
+}
@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(namespace = "cxx_qt::my_gadget")]
+mod qobject {
+    extern "RustQt" {
+        #[qgadget]
+        #[derive(PartialEq)]
+        #[qproperty(i32, x)]
+        #[qproperty(i32, y)]
+        type Point = super::PointRust;
+
+        #[qobject]
+        #[qproperty(Point, position)]
+        type MyGadgetObject = super::MyGadgetObjectRust;
+    }
+
+    // Note that we are only testing with C++ here so we don't need qinvokable
+    extern "RustQt" {
+        #[cxx_name = "moveRight"]
+        fn move_right(self: Pin<&mut MyGadgetObject>);
+    }
+}
+
+use core::pin::Pin;
+
+#[derive(Clone, Default, PartialEq)]
+pub struct PointRust {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Default)]
+pub struct MyGadgetObjectRust {
+    position: qobject::Point,
+}
+
+impl qobject::MyGadgetObject {
+    fn move_right(self: Pin<&mut Self>) {
+        let mut position = self.position().clone();
+        position.set_x(position.x() + 1);
+        self.set_position(position);
+    }
+}
@@ -6,7 +6,10 @@
 
 mod data;
 mod empty;
+mod executor;
+mod gadget;
 mod naming;
+mod pointer;
 mod types;
 
 #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
@@ -44,6 +47,30 @@ mod qobject {
         #[cxx_name = "queueTestMultiThread"]
         fn queue_test_multi_thread(self: Pin<&mut MyObject>);
 
+        #[cxx_name = "queueWithResultTest"]
+        fn queue_with_result_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueWithResultSameThread"]
+        fn queue_with_result_same_thread(self: Pin<&mut MyObject>) -> bool;
+
+        #[cxx_name = "queueWithResultTimeout"]
+        fn queue_with_result_timeout(self: Pin<&mut MyObject>) -> bool;
+
+        #[cxx_name = "queueCoalesced"]
+        fn queue_coalesced(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueHighPriority"]
+        fn queue_high_priority(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueWithTimeout"]
+        fn queue_with_timeout(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueWithMaxTimeout"]
+        fn queue_with_max_timeout(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueFull"]
+        fn queue_full(self: Pin<&mut MyObject>) -> bool;
+
         #[cxx_name = "fetchUpdateCallCount"]
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
@@ -54,7 +81,7 @@ mod qobject {
 }
 
 use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
+use cxx_qt::{CxxQtType, Threading, ThreadingQueueError, ThreadingQueueOptions};
 use cxx_qt_lib::QString;
 
 pub struct MyObjectRust {
@@ -126,6 +153,116 @@ impl qobject::MyObject {
         );
     }
 
+    fn queue_with_result_test(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            // Read the number from the QObject thread, then write the result back
+            let number = qt_thread
+                .queue_with_result(|qobject| *qobject.number())
+                .unwrap();
+            qt_thread
+                .queue(move |qobject| qobject.set_number(number * 2))
+                .unwrap();
+        });
+    }
+
+    fn queue_with_result_same_thread(self: Pin<&mut Self>) -> bool {
+        matches!(
+            self.qt_thread().queue_with_result(|_| ()),
+            Err(ThreadingQueueError::WouldDeadlock)
+        )
+    }
+
+    fn queue_with_result_timeout(self: Pin<&mut Self>) -> bool {
+        let qt_thread = self.qt_thread();
+        // The QObject thread is blocked on the join, so the closure cannot run before the timeout
+        std::thread::spawn(move || {
+            qt_thread.queue_with_result_timeout(std::time::Duration::from_millis(10), |qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            })
+        })
+        .join()
+        .map(|result| matches!(result, Err(ThreadingQueueError::TimedOut)))
+        .unwrap()
+    }
+
+    fn queue_coalesced(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        let options = ThreadingQueueOptions {
+            coalesce_key: Some(0),
+            ..Default::default()
+        };
+        // Only the latest closure for the key runs
+        for number in 1..=3 {
+            qt_thread
+                .queue_with_options(options, move |mut qobject| {
+                    qobject.as_mut().rust_mut().update_call_count += 1;
+                    qobject.set_number(number);
+                })
+                .unwrap();
+        }
+    }
+
+    fn queue_high_priority(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        qt_thread.queue(|qobject| qobject.set_number(1)).unwrap();
+        // The high priority closure runs before the pending closure with a normal priority
+        qt_thread
+            .queue_with_options(
+                ThreadingQueueOptions {
+                    high_priority: true,
+                    ..Default::default()
+                },
+                |qobject| qobject.set_number(2),
+            )
+            .unwrap();
+    }
+
+    fn queue_with_timeout(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        let options = ThreadingQueueOptions {
+            timeout: Some(std::time::Duration::from_millis(1)),
+            ..Default::default()
+        };
+        qt_thread
+            .queue_with_options(options, |qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            })
+            .unwrap();
+        // Block the event loop until the timeout has elapsed, so the closure is dropped
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        qt_thread.queue(|qobject| qobject.set_number(1)).unwrap();
+    }
+
+    fn queue_with_max_timeout(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        // A timeout which cannot be represented as a deadline never elapses
+        let options = ThreadingQueueOptions {
+            timeout: Some(std::time::Duration::MAX),
+            ..Default::default()
+        };
+        qt_thread
+            .queue_with_options(options, |qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            })
+            .unwrap();
+    }
+
+    fn queue_full(self: Pin<&mut Self>) -> bool {
+        let qt_thread = self.qt_thread();
+        let options = ThreadingQueueOptions {
+            max_pending: Some(2),
+            ..Default::default()
+        };
+        let queue = || {
+            qt_thread.queue_with_options(options, |qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            })
+        };
+        // The event loop cannot run the closures while this is running, so the third does not fit
+        queue().is_ok() && queue().is_ok() && matches!(queue(), Err(ThreadingQueueError::QueueFull))
+    }
+
     fn fetch_update_call_count(&self) -> i32 {
         self.update_call_count
     }
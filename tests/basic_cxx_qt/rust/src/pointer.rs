@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(namespace = "cxx_qt::my_pointer")]
+mod qobject {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qproperty(QBox<QObject>, child, READ, WRITE, NOTIFY, RESET)]
+        #[qproperty(QPointer<QObject>, target, READ, WRITE, NOTIFY, RESET)]
+        type MyPointerObject = super::MyPointerObjectRust;
+    }
+
+    // Note that we are only testing with C++ here so we don't need qinvokable
+    extern "RustQt" {
+        #[cxx_name = "setChildFromRust"]
+        unsafe fn set_child_from_rust(self: Pin<&mut MyPointerObject>, child: *mut QObject);
+
+        #[cxx_name = "childIsNull"]
+        fn child_is_null(self: &MyPointerObject) -> bool;
+
+        #[cxx_name = "setTargetFromRust"]
+        unsafe fn set_target_from_rust(self: Pin<&mut MyPointerObject>, target: *mut QObject);
+
+        #[cxx_name = "targetIsNull"]
+        fn target_is_null(self: &MyPointerObject) -> bool;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt::{QBox, QPointer};
+
+#[derive(Default)]
+pub struct MyPointerObjectRust;
+
+impl qobject::MyPointerObject {
+    /// # Safety
+    ///
+    /// `child` must be null or a valid pointer to a `QObject`, which is not owned by anything else
+    unsafe fn set_child_from_rust(self: Pin<&mut Self>, child: *mut qobject::QObject) {
+        self.set_child(unsafe { QBox::from_raw(child) });
+    }
+
+    fn child_is_null(&self) -> bool {
+        self.child().is_null()
+    }
+
+    /// # Safety
+    ///
+    /// `target` must be null or a valid pointer to a `QObject`
+    unsafe fn set_target_from_rust(self: Pin<&mut Self>, target: *mut qobject::QObject) {
+        self.set_target(&unsafe { QPointer::from_raw(target) });
+    }
+
+    fn target_is_null(&self) -> bool {
+        self.target().is_null()
+    }
+}
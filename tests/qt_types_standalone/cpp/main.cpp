@@ -26,12 +26,15 @@
 #include "qmap.h"
 #include "qmargins.h"
 #include "qmarginsf.h"
+#include "qmetaobject.h"
 #include "qmetaobjectconnection.h"
 #include "qmodelindex.h"
+#include "qobject.h"
 #include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
 #include "qpointf.h"
+#include "qpointer.h"
 #include "qpolygon.h"
 #include "qpolygonf.h"
 #include "qqmlapplicationengine.h"
@@ -42,6 +45,7 @@
 #include "qset.h"
 #include "qsize.h"
 #include "qsizef.h"
+#include "qsortfilterproxymodel.h"
 #include "qstring.h"
 #include "qstringlist.h"
 #include "qtime.h"
@@ -83,8 +87,10 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QMapTest));
   runTest(QScopedPointer<QObject>(new QMarginsTest));
   runTest(QScopedPointer<QObject>(new QMarginsFTest));
+  runTest(QScopedPointer<QObject>(new QMetaObjectTest));
   runTest(QScopedPointer<QObject>(new QMetaObjectConnectionTest));
   runTest(QScopedPointer<QObject>(new QModelIndexTest));
+  runTest(QScopedPointer<QObject>(new QObjectTest));
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
@@ -95,6 +101,7 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QSetTest));
   runTest(QScopedPointer<QObject>(new QSizeTest));
   runTest(QScopedPointer<QObject>(new QSizeFTest));
+  runTest(QScopedPointer<QObject>(new QSortFilterProxyModelTest));
   runTest(QScopedPointer<QObject>(new QStringTest));
   runTest(QScopedPointer<QObject>(new QStringListTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
@@ -105,6 +112,7 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QVector2DTest));
   runTest(QScopedPointer<QObject>(new QVector3DTest));
   runTest(QScopedPointer<QObject>(new QVector4DTest));
+  runTest(QScopedPointer<QObject>(new QPointerTest));
   runTest(QScopedPointer<QObject>(new QPolygonTest));
   runTest(QScopedPointer<QObject>(new QPenTest));
   runTest(QScopedPointer<QObject>(new QPolygonFTest));
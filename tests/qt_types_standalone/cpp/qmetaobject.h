@@ -0,0 +1,158 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmetaobject.cxx.h"
+
+class MetaObjectTestObject : public QObject
+{
+  Q_OBJECT
+  Q_CLASSINFO("author", "KDAB")
+  Q_PROPERTY(int value READ value WRITE setValue)
+
+public:
+  enum class Colour
+  {
+    Red = 1,
+    Green = 2,
+  };
+  Q_ENUM(Colour)
+
+  int value() const { return m_value; }
+  void setValue(int value) { m_value = value; }
+
+  Q_INVOKABLE int multiply(int factor) const { return m_value * factor; }
+
+  Q_INVOKABLE int add(int amount)
+  {
+    m_value += amount;
+    return m_value;
+  }
+
+public Q_SLOTS:
+  void reset() { m_value = 0; }
+
+private:
+  int m_value = 0;
+};
+
+class QMetaObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void layout()
+  {
+    // The sizes and alignments on the Rust side match the C++ types
+    const auto classInfo = qmetaclassinfo_layout();
+    QCOMPARE(classInfo.size, sizeof(QMetaClassInfo));
+    QCOMPARE(classInfo.align, alignof(QMetaClassInfo));
+
+    const auto metaEnum = qmetaenum_layout();
+    QCOMPARE(metaEnum.size, sizeof(QMetaEnum));
+    QCOMPARE(metaEnum.align, alignof(QMetaEnum));
+
+    const auto method = qmetamethod_layout();
+    QCOMPARE(method.size, sizeof(QMetaMethod));
+    QCOMPARE(method.align, alignof(QMetaMethod));
+
+    const auto property = qmetaproperty_layout();
+    QCOMPARE(property.size, sizeof(QMetaProperty));
+    QCOMPARE(property.align, alignof(QMetaProperty));
+  }
+
+  void className()
+  {
+    MetaObjectTestObject obj;
+    QCOMPARE(qmetaobject_class_name(obj),
+             QStringLiteral("MetaObjectTestObject"));
+    QCOMPARE(qmetaobject_super_class_name(obj), QStringLiteral("QObject"));
+  }
+
+  void classInfo()
+  {
+    MetaObjectTestObject obj;
+    QCOMPARE(qmetaclassinfo_value(obj, "author"), QStringLiteral("KDAB"));
+    QVERIFY(qmetaclassinfo_value(obj, "missing").isEmpty());
+  }
+
+  void enumerator()
+  {
+    MetaObjectTestObject obj;
+    QCOMPARE(qmetaenum_key_to_value(obj, "Colour", "Green"), 2);
+    QCOMPARE(qmetaenum_key_to_value(obj, "Colour", "Blue"), -1);
+    QCOMPARE(qmetaenum_key_to_value(obj, "Missing", "Green"), -1);
+    QCOMPARE(qmetaenum_value_to_key(obj, "Colour", 1), QStringLiteral("Red"));
+  }
+
+  void property()
+  {
+    MetaObjectTestObject obj;
+    QVERIFY(qmetaproperty_write(obj, "value", QVariant::fromValue(4)));
+    QCOMPARE(obj.value(), 4);
+    QCOMPARE(qmetaproperty_read(obj, "value"), QVariant::fromValue(4));
+    QCOMPARE(qmetaproperty_read(obj, "objectName"),
+             QVariant::fromValue(QString()));
+
+    QVERIFY(!qmetaproperty_write(obj, "missing", QVariant::fromValue(4)));
+    QVERIFY(!qmetaproperty_read(obj, "missing").isValid());
+  }
+
+  void invoke()
+  {
+    MetaObjectTestObject obj;
+    obj.setValue(3);
+    QVERIFY(!qmetamethod_is_slot(obj, "multiply(int)"));
+
+    // The argument and the return value are passed as QVariants
+    QVariant returnValue;
+    QVERIFY(qmetamethod_invoke(
+      obj, "multiply(int)", QVariant::fromValue(5), returnValue));
+    QCOMPARE(returnValue, QVariant::fromValue(15));
+
+    // The argument is converted to the type of the parameter
+    QVERIFY(qmetamethod_invoke(
+      obj, "multiply(int)", QVariant::fromValue(QStringLiteral("2")),
+      returnValue));
+    QCOMPARE(returnValue, QVariant::fromValue(6));
+  }
+
+  void invokeQueued()
+  {
+    MetaObjectTestObject obj;
+    obj.setValue(3);
+
+    // A queued invocation has no return value, so it is queued even though
+    // the method returns a value
+    QVERIFY(
+      qmetamethod_invoke_queued(obj, "add(int)", QVariant::fromValue(2)));
+    QCOMPARE(obj.value(), 3);
+    QTRY_COMPARE(obj.value(), 5);
+  }
+
+  void invokeInvalid()
+  {
+    MetaObjectTestObject obj;
+    obj.setValue(3);
+    QVERIFY(qmetamethod_is_slot(obj, "reset()"));
+
+    // The number of arguments does not match the parameters
+    QVariant returnValue;
+    QVERIFY(!qmetamethod_invoke(
+      obj, "reset()", QVariant::fromValue(1), returnValue));
+    QCOMPARE(obj.value(), 3);
+
+    // The method does not exist
+    QVERIFY(!qmetamethod_invoke(
+      obj, "divide(int)", QVariant::fromValue(1), returnValue));
+    QVERIFY(!returnValue.isValid());
+  }
+};
@@ -0,0 +1,161 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QEvent>
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtCore/QThread>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qobject.cxx.h"
+
+class QObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void connectByName()
+  {
+    QObject obj;
+    auto counter = connect_object_name_changed(obj, false);
+    QVERIFY(dynamic_signal_counter_is_connected(*counter));
+
+    obj.setObjectName(QStringLiteral("first"));
+    obj.setObjectName(QStringLiteral("second"));
+    QCOMPARE(dynamic_signal_counter_calls(*counter), 2);
+    QCOMPARE(dynamic_signal_counter_last_name(*counter),
+             QStringLiteral("second"));
+
+    // The receiver of the connection is not a child of the sender
+    QVERIFY(obj.children().isEmpty());
+  }
+
+  void connectByNameDisconnect()
+  {
+    QObject obj;
+    auto counter = connect_object_name_changed(obj, false);
+
+    QVERIFY(dynamic_signal_counter_disconnect(*counter));
+    QVERIFY(!dynamic_signal_counter_is_connected(*counter));
+    obj.setObjectName(QStringLiteral("first"));
+    QCOMPARE(dynamic_signal_counter_calls(*counter), 0);
+
+    // The closure is dropped once the receiver has been deleted
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(dynamic_signal_counter_dropped(*counter));
+  }
+
+  void connectByNameSingleShot()
+  {
+    QObject obj;
+    auto counter = connect_object_name_changed(obj, true);
+
+    obj.setObjectName(QStringLiteral("first"));
+    obj.setObjectName(QStringLiteral("second"));
+    QCOMPARE(dynamic_signal_counter_calls(*counter), 1);
+    QCOMPARE(dynamic_signal_counter_last_name(*counter),
+             QStringLiteral("first"));
+    QVERIFY(!dynamic_signal_counter_is_connected(*counter));
+
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(dynamic_signal_counter_dropped(*counter));
+  }
+
+  void connectByNameSenderDestroyed()
+  {
+    auto* obj = new QObject();
+    auto counter = connect_object_name_changed(*obj, false);
+
+    delete obj;
+    QVERIFY(!dynamic_signal_counter_is_connected(*counter));
+
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(dynamic_signal_counter_dropped(*counter));
+  }
+
+  void connectByNameSenderDisconnect()
+  {
+    auto* obj = new QObject();
+    auto counter = connect_object_name_changed(*obj, false);
+
+    // Disconnecting the signal from the sender stops the closure being called
+    QVERIFY(
+      QObject::disconnect(obj, &QObject::objectNameChanged, nullptr, nullptr));
+    obj->setObjectName(QStringLiteral("first"));
+    QCOMPARE(dynamic_signal_counter_calls(*counter), 0);
+
+    // Qt does not notify the receiver, so the closure is dropped with the
+    // sender
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(!dynamic_signal_counter_dropped(*counter));
+    delete obj;
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(dynamic_signal_counter_dropped(*counter));
+  }
+
+  void dynamicProperty()
+  {
+    QObject obj;
+    QCOMPARE(qobject_dynamic_property(obj, "answer"), -1);
+    QVERIFY(!qobject_has_dynamic_property(obj, "answer"));
+
+    // Properties which are not declared with Q_PROPERTY are added dynamically
+    QVERIFY(!qobject_set_dynamic_property(obj, "answer", 42));
+    QCOMPARE(qobject_dynamic_property(obj, "answer"), 42);
+    QVERIFY(qobject_has_dynamic_property(obj, "answer"));
+    QCOMPARE(obj.property("answer"), QVariant::fromValue(42));
+
+    QVERIFY(!qobject_remove_dynamic_property(obj, "answer"));
+    QCOMPARE(qobject_dynamic_property(obj, "answer"), -1);
+    QVERIFY(!qobject_has_dynamic_property(obj, "answer"));
+    QVERIFY(obj.dynamicPropertyNames().isEmpty());
+  }
+
+  void deleteLater()
+  {
+    auto* parent = new QObject();
+    QPointer<QObject> child = new QObject(parent);
+    QPointer<QObject> obj = parent;
+
+    // The object is only deleted once the deferred delete event is processed
+    qobject_delete_later(*parent);
+    QVERIFY(!obj.isNull());
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(obj.isNull());
+    QVERIFY(child.isNull());
+  }
+
+  void thread()
+  {
+    QObject obj;
+    QCOMPARE(qobject_thread(obj), QThread::currentThread());
+    QVERIFY(qobject_thread_is_current(obj));
+  }
+
+  void moveToThread()
+  {
+    QThread thread;
+    auto* obj = new QObject();
+    auto* child = new QObject(obj);
+
+    // The children of the object are moved with it
+    QVERIFY(qobject_move_to_thread(*obj, &thread));
+    QCOMPARE(qobject_thread(*obj), &thread);
+    QCOMPARE(qobject_thread(*child), &thread);
+    QVERIFY(!qobject_thread_is_current(*obj));
+
+    // An object with a parent cannot be moved on its own
+    QThread other;
+    QVERIFY(!qobject_move_to_thread(*child, &other));
+    QCOMPARE(qobject_thread(*child), &thread);
+
+    // The thread never started, so the object can be deleted from here
+    delete obj;
+  }
+};
@@ -0,0 +1,89 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <utility>
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qpointer.cxx.h"
+
+class QPointerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void nullAfterDelete()
+  {
+    auto* obj = new QObject();
+    auto pointer = construct_qpointer(*obj);
+    QVERIFY(!qpointer_is_null(*pointer));
+    QCOMPARE(qpointer_as_ptr(*pointer), obj);
+
+    delete obj;
+    QVERIFY(qpointer_is_null(*pointer));
+    QVERIFY(qpointer_as_ptr(*pointer) == nullptr);
+  }
+
+  void qboxDrop()
+  {
+    auto* obj = new QObject();
+    QPointer<QObject> guard = obj;
+    {
+      auto qbox = construct_qbox(obj);
+      QCOMPARE(qbox_as_ptr(*qbox), obj);
+    }
+
+    // The unparented object is deleted with the QBox
+    QVERIFY(guard.isNull());
+  }
+
+  void qboxDropParented()
+  {
+    QObject parent;
+    auto* obj = new QObject(&parent);
+    QPointer<QObject> guard = obj;
+    {
+      auto qbox = construct_qbox(obj);
+    }
+
+    // The parent owns the object, so it is not deleted with the QBox
+    QVERIFY(!guard.isNull());
+    QVERIFY(parent.children().contains(obj));
+  }
+
+  void qboxNullAfterParentDelete()
+  {
+    auto* parent = new QObject();
+    auto qbox = construct_qbox(new QObject(parent));
+    auto pointer = qbox_to_qpointer(*qbox);
+    QVERIFY(!qbox_is_null(*qbox));
+
+    // Deleting the parent deletes the object, so the QBox and QPointer are null
+    delete parent;
+    QVERIFY(qbox_is_null(*qbox));
+    QVERIFY(qpointer_is_null(*pointer));
+  }
+
+  void qboxIntoRaw()
+  {
+    auto* obj = new QObject();
+    QPointer<QObject> guard = obj;
+    auto qbox = construct_qbox(obj);
+    auto pointer = qbox_to_qpointer(*qbox);
+
+    // Releasing the object does not delete it
+    QCOMPARE(qbox_into_raw(std::move(qbox)), obj);
+    QVERIFY(!guard.isNull());
+    QVERIFY(!qpointer_is_null(*pointer));
+
+    delete obj;
+    QVERIFY(qpointer_is_null(*pointer));
+  }
+};
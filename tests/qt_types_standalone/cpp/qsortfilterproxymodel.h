@@ -0,0 +1,129 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QAbstractProxyModel>
+#include <QtCore/QStringListModel>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qsortfilterproxymodel.cxx.h"
+
+class QSortFilterProxyModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    QStringListModel source(sourceRows());
+    auto proxy = construct_sortfilterproxymodel(&source);
+    auto* model = sortfilterproxymodel_model(*proxy);
+
+    QVERIFY(qobject_cast<QAbstractProxyModel*>(model) != nullptr);
+    QCOMPARE(qobject_cast<QAbstractProxyModel*>(model)->sourceModel(),
+             static_cast<QAbstractItemModel*>(&source));
+    QCOMPARE(rows(*model), sourceRows());
+  }
+
+  void filter()
+  {
+    QStringListModel source(sourceRows());
+    auto proxy = construct_sortfilterproxymodel(&source);
+    auto* model = sortfilterproxymodel_model(*proxy);
+
+    sortfilterproxymodel_filter_contains(*proxy, QStringLiteral("q"));
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("qt")
+                           << QStringLiteral("qobject"));
+
+    // Proxy rows map back to the rows of the source model
+    const auto proxyModel = qobject_cast<QAbstractProxyModel*>(model);
+    QCOMPARE(proxyModel->mapToSource(model->index(1, 0)).row(), 3);
+    QCOMPARE(proxyModel->mapFromSource(source.index(0, 0)).isValid(), false);
+
+    // Clearing the filter restores all of the rows
+    sortfilterproxymodel_clear_filter(*proxy);
+    QCOMPARE(rows(*model), sourceRows());
+  }
+
+  void sort()
+  {
+    QStringListModel source(sourceRows());
+    auto proxy = construct_sortfilterproxymodel(&source);
+    auto* model = sortfilterproxymodel_model(*proxy);
+
+    model->sort(0, Qt::AscendingOrder);
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("cxx") << QStringLiteral("kdab")
+                           << QStringLiteral("qobject")
+                           << QStringLiteral("qt"));
+
+    // Setting the closure sorts the model again
+    sortfilterproxymodel_sort_by_length(*proxy);
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("qt") << QStringLiteral("cxx")
+                           << QStringLiteral("kdab")
+                           << QStringLiteral("qobject"));
+
+    model->sort(0, Qt::DescendingOrder);
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("qobject")
+                           << QStringLiteral("kdab") << QStringLiteral("cxx")
+                           << QStringLiteral("qt"));
+
+    // Clearing the closure restores the sorting of QSortFilterProxyModel
+    sortfilterproxymodel_clear_sort(*proxy);
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("qt") << QStringLiteral("qobject")
+                           << QStringLiteral("kdab") << QStringLiteral("cxx"));
+  }
+
+  void filterAndSortDynamic()
+  {
+    QStringListModel source(sourceRows());
+    auto proxy = construct_sortfilterproxymodel(&source);
+    auto* model = sortfilterproxymodel_model(*proxy);
+
+    sortfilterproxymodel_filter_contains(*proxy, QStringLiteral("q"));
+    sortfilterproxymodel_sort_by_length(*proxy);
+    model->sort(0, Qt::AscendingOrder);
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("qt")
+                           << QStringLiteral("qobject"));
+
+    // Changes to the source model are filtered and sorted by the closures
+    QVERIFY(source.insertRows(0, 2));
+    QVERIFY(source.setData(source.index(0, 0), QStringLiteral("qml")));
+    QVERIFY(source.setData(source.index(1, 0), QStringLiteral("rust")));
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("qt") << QStringLiteral("qml")
+                           << QStringLiteral("qobject"));
+
+    sortfilterproxymodel_clear_filter(*proxy);
+    sortfilterproxymodel_clear_sort(*proxy);
+    QCOMPARE(rows(*model),
+             QStringList() << QStringLiteral("cxx") << QStringLiteral("kdab")
+                           << QStringLiteral("qml") << QStringLiteral("qobject")
+                           << QStringLiteral("qt") << QStringLiteral("rust"));
+  }
+
+private:
+  static QStringList sourceRows()
+  {
+    return QStringList() << QStringLiteral("kdab") << QStringLiteral("qt")
+                         << QStringLiteral("cxx") << QStringLiteral("qobject");
+  }
+
+  static QStringList rows(const QAbstractItemModel& model)
+  {
+    QStringList rows;
+    for (int row = 0; row < model.rowCount(); ++row) {
+      rows << model.index(row, 0).data().toString();
+    }
+    return rows;
+  }
+};
@@ -25,12 +25,15 @@ fn main() {
         .file("src/qmap.rs")
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
+        .file("src/qmetaobject.rs")
         .file("src/qmetaobjectconnection.rs")
         .file("src/qmodelindex.rs")
+        .file("src/qobject.rs")
         .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
         .file("src/qpointf.rs")
+        .file("src/qpointer.rs")
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
         .file("src/qqmlapplicationengine.rs")
@@ -41,6 +44,7 @@ fn main() {
         .file("src/qset.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
+        .file("src/qsortfilterproxymodel.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
         .file("src/qtime.rs")
@@ -22,12 +22,15 @@ mod qlist;
 mod qmap;
 mod qmargins;
 mod qmarginsf;
+mod qmetaobject;
 mod qmetaobjectconnection;
 mod qmodelindex;
+mod qobject;
 mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
 mod qpointf;
+mod qpointer;
 mod qpolygon;
 mod qpolygonf;
 mod qqmlapplicationengine;
@@ -38,6 +41,7 @@ mod qregion;
 mod qset;
 mod qsize;
 mod qsizef;
+mod qsortfilterproxymodel;
 mod qstring;
 mod qstringlist;
 mod qtime;
@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    ConnectionType, QMetaClassInfo, QMetaEnum, QMetaMethod, QMetaMethodType, QMetaObject,
+    QMetaProperty, QObjectExt, QString, QVariant,
+};
+use std::mem::{align_of, size_of};
+
+#[cxx::bridge]
+mod qmetaobject_cxx {
+    struct QMetaLayout {
+        size: usize,
+        align: usize,
+    }
+
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    extern "Rust" {
+        fn qmetaclassinfo_layout() -> QMetaLayout;
+        fn qmetaenum_layout() -> QMetaLayout;
+        fn qmetamethod_layout() -> QMetaLayout;
+        fn qmetaproperty_layout() -> QMetaLayout;
+
+        fn qmetaobject_class_name(obj: &QObject) -> QString;
+        fn qmetaobject_super_class_name(obj: &QObject) -> QString;
+        fn qmetaclassinfo_value(obj: &QObject, name: &str) -> QString;
+        fn qmetaenum_key_to_value(obj: &QObject, enumerator: &str, key: &str) -> i32;
+        fn qmetaenum_value_to_key(obj: &QObject, enumerator: &str, value: i32) -> QString;
+        fn qmetaproperty_read(obj: &QObject, name: &str) -> QVariant;
+        fn qmetaproperty_write(obj: Pin<&mut QObject>, name: &str, value: &QVariant) -> bool;
+        fn qmetamethod_is_slot(obj: &QObject, signature: &str) -> bool;
+        fn qmetamethod_invoke(
+            obj: Pin<&mut QObject>,
+            signature: &str,
+            argument: &QVariant,
+            return_value: &mut QVariant,
+        ) -> bool;
+        fn qmetamethod_invoke_queued(
+            obj: Pin<&mut QObject>,
+            signature: &str,
+            argument: &QVariant,
+        ) -> bool;
+    }
+}
+
+use qmetaobject_cxx::QMetaLayout;
+
+fn layout_of<T>() -> QMetaLayout {
+    QMetaLayout {
+        size: size_of::<T>(),
+        align: align_of::<T>(),
+    }
+}
+
+fn qmetaclassinfo_layout() -> QMetaLayout {
+    layout_of::<QMetaClassInfo>()
+}
+
+fn qmetaenum_layout() -> QMetaLayout {
+    layout_of::<QMetaEnum>()
+}
+
+fn qmetamethod_layout() -> QMetaLayout {
+    layout_of::<QMetaMethod>()
+}
+
+fn qmetaproperty_layout() -> QMetaLayout {
+    layout_of::<QMetaProperty>()
+}
+
+fn meta_object(obj: &qmetaobject_cxx::QObject) -> &QMetaObject {
+    obj.meta_object()
+}
+
+fn find_enumerator(obj: &qmetaobject_cxx::QObject, enumerator: &str) -> Option<QMetaEnum> {
+    let meta_object = meta_object(obj);
+    meta_object
+        .index_of_enumerator(enumerator)
+        .map(|index| meta_object.enumerator(index))
+}
+
+fn find_method(obj: &qmetaobject_cxx::QObject, signature: &str) -> Option<QMetaMethod> {
+    let meta_object = meta_object(obj);
+    meta_object
+        .index_of_method(signature)
+        .map(|index| meta_object.method(index))
+}
+
+fn find_property(obj: &qmetaobject_cxx::QObject, name: &str) -> Option<QMetaProperty> {
+    let meta_object = meta_object(obj);
+    meta_object
+        .index_of_property(name)
+        .map(|index| meta_object.property(index))
+}
+
+fn qmetaobject_class_name(obj: &qmetaobject_cxx::QObject) -> QString {
+    meta_object(obj).class_name()
+}
+
+fn qmetaobject_super_class_name(obj: &qmetaobject_cxx::QObject) -> QString {
+    let meta_object = meta_object(obj);
+    let super_class = meta_object.super_class().unwrap();
+    // Every class inherits itself and its superclass
+    assert!(meta_object.inherits(meta_object));
+    assert!(meta_object.inherits(super_class));
+    assert!(!super_class.inherits(meta_object));
+    super_class.class_name()
+}
+
+fn qmetaclassinfo_value(obj: &qmetaobject_cxx::QObject, name: &str) -> QString {
+    let meta_object = meta_object(obj);
+    meta_object
+        .index_of_class_info(name)
+        .map(|index| {
+            let class_info = meta_object.class_info(index);
+            assert_eq!(class_info.name().to_string(), name);
+            class_info.value()
+        })
+        .unwrap_or_default()
+}
+
+fn qmetaenum_key_to_value(obj: &qmetaobject_cxx::QObject, enumerator: &str, key: &str) -> i32 {
+    find_enumerator(obj, enumerator)
+        .and_then(|meta_enum| meta_enum.key_to_value(key))
+        .unwrap_or(-1)
+}
+
+fn qmetaenum_value_to_key(obj: &qmetaobject_cxx::QObject, enumerator: &str, value: i32) -> QString {
+    find_enumerator(obj, enumerator)
+        .map(|meta_enum| meta_enum.value_to_key(value))
+        .unwrap_or_default()
+}
+
+fn qmetaproperty_read(obj: &qmetaobject_cxx::QObject, name: &str) -> QVariant {
+    find_property(obj, name)
+        .map(|property| property.read(obj))
+        .unwrap_or_default()
+}
+
+fn qmetaproperty_write(
+    obj: Pin<&mut qmetaobject_cxx::QObject>,
+    name: &str,
+    value: &QVariant,
+) -> bool {
+    find_property(&obj, name).is_some_and(|property| property.write(obj, value))
+}
+
+fn qmetamethod_is_slot(obj: &qmetaobject_cxx::QObject, signature: &str) -> bool {
+    find_method(obj, signature).is_some_and(|method| method.method_type() == QMetaMethodType::Slot)
+}
+
+fn qmetamethod_invoke(
+    obj: Pin<&mut qmetaobject_cxx::QObject>,
+    signature: &str,
+    argument: &QVariant,
+    return_value: &mut QVariant,
+) -> bool {
+    let Some(method) = find_method(&obj, signature) else {
+        return false;
+    };
+    match method.invoke(obj, &[argument.clone()], ConnectionType::DirectConnection) {
+        Some(value) => {
+            *return_value = value;
+            true
+        }
+        None => false,
+    }
+}
+
+fn qmetamethod_invoke_queued(
+    obj: Pin<&mut qmetaobject_cxx::QObject>,
+    signature: &str,
+    argument: &QVariant,
+) -> bool {
+    find_method(&obj, signature)
+        .and_then(|method| {
+            method.invoke(obj, &[argument.clone()], ConnectionType::QueuedConnection)
+        })
+        // The invocation is queued, so there is no return value
+        .is_some_and(|return_value| !return_value.is_valid())
+}
@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{ConnectionType, QMetaObjectConnection, QObjectExt, QString, QThread, QVariant};
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+#[cxx::bridge]
+mod qobject_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = cxx_qt_lib::QThread;
+    }
+
+    extern "Rust" {
+        type DynamicSignalCounter;
+
+        fn connect_object_name_changed(
+            obj: Pin<&mut QObject>,
+            single_shot: bool,
+        ) -> Box<DynamicSignalCounter>;
+        fn dynamic_signal_counter_calls(counter: &DynamicSignalCounter) -> usize;
+        fn dynamic_signal_counter_disconnect(counter: &DynamicSignalCounter) -> bool;
+        fn dynamic_signal_counter_dropped(counter: &DynamicSignalCounter) -> bool;
+        fn dynamic_signal_counter_is_connected(counter: &DynamicSignalCounter) -> bool;
+        fn dynamic_signal_counter_last_name(counter: &DynamicSignalCounter) -> QString;
+
+        fn qobject_dynamic_property(obj: &QObject, name: &str) -> i32;
+        fn qobject_has_dynamic_property(obj: &QObject, name: &str) -> bool;
+        fn qobject_remove_dynamic_property(obj: Pin<&mut QObject>, name: &str) -> bool;
+        fn qobject_set_dynamic_property(obj: Pin<&mut QObject>, name: &str, value: i32) -> bool;
+
+        fn qobject_delete_later(obj: Pin<&mut QObject>);
+
+        fn qobject_thread(obj: &QObject) -> *mut QThread;
+        fn qobject_thread_is_current(obj: &QObject) -> bool;
+        unsafe fn qobject_move_to_thread(obj: Pin<&mut QObject>, thread: *mut QThread) -> bool;
+    }
+}
+
+#[derive(Default)]
+struct DynamicSignalState {
+    calls: AtomicUsize,
+    dropped: AtomicBool,
+    last_name: Mutex<String>,
+}
+
+// Marks the state as dropped once the closure owning it is dropped
+struct DropFlag(Arc<DynamicSignalState>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+struct DynamicSignalCounter {
+    connection: QMetaObjectConnection,
+    state: Arc<DynamicSignalState>,
+}
+
+fn connect_object_name_changed(
+    obj: Pin<&mut qobject_cxx::QObject>,
+    single_shot: bool,
+) -> Box<DynamicSignalCounter> {
+    let state = Arc::new(DynamicSignalState::default());
+    let flag = DropFlag(state.clone());
+    let conn_type = if single_shot {
+        ConnectionType::DirectConnection | ConnectionType::SingleShotConnection
+    } else {
+        ConnectionType::DirectConnection
+    };
+    let connection = obj.connect_by_name(
+        "objectNameChanged",
+        move |arguments| {
+            let state = &flag.0;
+            state.calls.fetch_add(1, Ordering::SeqCst);
+            if let Some(name) = arguments.first().and_then(|name| name.value::<QString>()) {
+                *state.last_name.lock().unwrap() = name.to_string();
+            }
+        },
+        conn_type,
+    );
+    Box::new(DynamicSignalCounter { connection, state })
+}
+
+fn dynamic_signal_counter_calls(counter: &DynamicSignalCounter) -> usize {
+    counter.state.calls.load(Ordering::SeqCst)
+}
+
+fn dynamic_signal_counter_disconnect(counter: &DynamicSignalCounter) -> bool {
+    counter.connection.disconnect()
+}
+
+fn dynamic_signal_counter_dropped(counter: &DynamicSignalCounter) -> bool {
+    counter.state.dropped.load(Ordering::SeqCst)
+}
+
+fn dynamic_signal_counter_is_connected(counter: &DynamicSignalCounter) -> bool {
+    counter.connection.is_connected()
+}
+
+fn dynamic_signal_counter_last_name(counter: &DynamicSignalCounter) -> QString {
+    QString::from(counter.state.last_name.lock().unwrap().as_str())
+}
+
+fn qobject_dynamic_property(obj: &qobject_cxx::QObject, name: &str) -> i32 {
+    obj.property(name).value::<i32>().unwrap_or(-1)
+}
+
+fn qobject_has_dynamic_property(obj: &qobject_cxx::QObject, name: &str) -> bool {
+    obj.dynamic_property_names()
+        .iter()
+        .any(|property_name| property_name.as_slice() == name.as_bytes())
+}
+
+fn qobject_remove_dynamic_property(obj: Pin<&mut qobject_cxx::QObject>, name: &str) -> bool {
+    // Setting an invalid variant removes the dynamic property
+    obj.set_property(name, &QVariant::default())
+}
+
+fn qobject_set_dynamic_property(
+    obj: Pin<&mut qobject_cxx::QObject>,
+    name: &str,
+    value: i32,
+) -> bool {
+    obj.set_property(name, &QVariant::from(&value))
+}
+
+fn qobject_delete_later(obj: Pin<&mut qobject_cxx::QObject>) {
+    // SAFETY: the object is owned by C++ and is not used once it has been deleted
+    unsafe { obj.delete_later() }
+}
+
+fn qobject_thread(obj: &qobject_cxx::QObject) -> *mut QThread {
+    obj.thread()
+}
+
+fn qobject_thread_is_current(obj: &qobject_cxx::QObject) -> bool {
+    obj.thread() == QThread::current_thread()
+}
+
+unsafe fn qobject_move_to_thread(
+    obj: Pin<&mut qobject_cxx::QObject>,
+    thread: *mut QThread,
+) -> bool {
+    // SAFETY: the thread is a valid pointer from C++ and the object has no Rust data
+    unsafe { obj.move_to_thread(thread) }
+}
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt::{QBox, QObject, QPointer};
+
+#[cxx::bridge]
+mod qpointer_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+    }
+
+    extern "Rust" {
+        type QObjectQPointer;
+        type QObjectQBox;
+
+        fn construct_qpointer(obj: Pin<&mut QObject>) -> Box<QObjectQPointer>;
+        fn qpointer_as_ptr(pointer: &QObjectQPointer) -> *mut QObject;
+        fn qpointer_is_null(pointer: &QObjectQPointer) -> bool;
+
+        unsafe fn construct_qbox(obj: *mut QObject) -> Box<QObjectQBox>;
+        fn qbox_as_ptr(qbox: &QObjectQBox) -> *mut QObject;
+        fn qbox_into_raw(qbox: Box<QObjectQBox>) -> *mut QObject;
+        fn qbox_is_null(qbox: &QObjectQBox) -> bool;
+        fn qbox_to_qpointer(qbox: &QObjectQBox) -> Box<QObjectQPointer>;
+    }
+}
+
+struct QObjectQPointer(QPointer<QObject>);
+
+struct QObjectQBox(QBox<QObject>);
+
+fn construct_qpointer(obj: Pin<&mut QObject>) -> Box<QObjectQPointer> {
+    Box::new(QObjectQPointer(QPointer::new(obj)))
+}
+
+fn qpointer_as_ptr(pointer: &QObjectQPointer) -> *mut QObject {
+    pointer.0.as_ptr()
+}
+
+fn qpointer_is_null(pointer: &QObjectQPointer) -> bool {
+    pointer.0.is_null()
+}
+
+unsafe fn construct_qbox(obj: *mut QObject) -> Box<QObjectQBox> {
+    // SAFETY: the object is owned by the QBox once it has been passed from C++
+    Box::new(QObjectQBox(unsafe { QBox::from_raw(obj) }))
+}
+
+fn qbox_as_ptr(qbox: &QObjectQBox) -> *mut QObject {
+    qbox.0.as_ptr()
+}
+
+fn qbox_into_raw(qbox: Box<QObjectQBox>) -> *mut QObject {
+    qbox.0.into_raw()
+}
+
+fn qbox_is_null(qbox: &QObjectQBox) -> bool {
+    qbox.0.is_null()
+}
+
+fn qbox_to_qpointer(qbox: &QObjectQBox) -> Box<QObjectQPointer> {
+    Box::new(QObjectQPointer(qbox.0.to_qpointer()))
+}
@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{
+    CaseSensitivity, QAbstractItemModel, QModelIndex, QSortFilterProxyModel, QString,
+    SortFilterProxyModel,
+};
+
+#[cxx::bridge]
+mod qsortfilterproxymodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = cxx_qt_lib::QAbstractItemModel;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        type SortFilterProxyModelWrapper;
+
+        unsafe fn construct_sortfilterproxymodel(
+            source_model: *mut QAbstractItemModel,
+        ) -> Box<SortFilterProxyModelWrapper>;
+
+        fn sortfilterproxymodel_model(
+            wrapper: &mut SortFilterProxyModelWrapper,
+        ) -> *mut QAbstractItemModel;
+        fn sortfilterproxymodel_filter_contains(
+            wrapper: &mut SortFilterProxyModelWrapper,
+            pattern: &QString,
+        );
+        fn sortfilterproxymodel_clear_filter(wrapper: &mut SortFilterProxyModelWrapper);
+        fn sortfilterproxymodel_sort_by_length(wrapper: &mut SortFilterProxyModelWrapper);
+        fn sortfilterproxymodel_clear_sort(wrapper: &mut SortFilterProxyModelWrapper);
+    }
+}
+
+// The proxy model is owned by Rust so that the closures can be changed by the tests
+struct SortFilterProxyModelWrapper {
+    proxy: cxx::UniquePtr<SortFilterProxyModel>,
+}
+
+/// The display text of the item in the source model for the given index
+fn display_text(index: &QModelIndex) -> QString {
+    index.data(0).value::<QString>().unwrap_or_default()
+}
+
+/// # Safety
+///
+/// `source_model` must be a valid pointer to a model which outlives the proxy model.
+unsafe fn construct_sortfilterproxymodel(
+    source_model: *mut QAbstractItemModel,
+) -> Box<SortFilterProxyModelWrapper> {
+    let mut proxy = SortFilterProxyModel::new();
+    Upcast::<QSortFilterProxyModel>::upcast_pin(proxy.pin_mut())
+        .set_source_model(Pin::new_unchecked(&mut *source_model));
+    Box::new(SortFilterProxyModelWrapper { proxy })
+}
+
+fn sortfilterproxymodel_model(
+    wrapper: &mut SortFilterProxyModelWrapper,
+) -> *mut QAbstractItemModel {
+    let model = Upcast::<QAbstractItemModel>::upcast_pin(wrapper.proxy.pin_mut());
+    // SAFETY: the model is only used by C++ while the wrapper is alive
+    unsafe { model.get_unchecked_mut() }
+}
+
+fn sortfilterproxymodel_filter_contains(
+    wrapper: &mut SortFilterProxyModelWrapper,
+    pattern: &QString,
+) {
+    let pattern = pattern.clone();
+    wrapper
+        .proxy
+        .pin_mut()
+        .set_filter_accepts_row(move |proxy, source_row, source_parent| {
+            let source_model = Upcast::<QSortFilterProxyModel>::upcast(proxy).source_model();
+            // SAFETY: the source model is valid while the proxy is filtering its rows
+            let index = unsafe { &*source_model }.index(source_row, 0, source_parent);
+            display_text(&index).contains(&pattern, CaseSensitivity::CaseSensitive)
+        });
+}
+
+fn sortfilterproxymodel_clear_filter(wrapper: &mut SortFilterProxyModelWrapper) {
+    wrapper.proxy.pin_mut().clear_filter_accepts_row();
+}
+
+fn sortfilterproxymodel_sort_by_length(wrapper: &mut SortFilterProxyModelWrapper) {
+    wrapper
+        .proxy
+        .pin_mut()
+        .set_less_than(|_proxy, source_left, source_right| {
+            display_text(source_left).len() < display_text(source_right).len()
+        });
+}
+
+fn sortfilterproxymodel_clear_sort(wrapper: &mut SortFilterProxyModelWrapper) {
+    wrapper.proxy.pin_mut().clear_less_than();
+}